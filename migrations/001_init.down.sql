@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_guild_configs_updated;
+DROP TABLE IF EXISTS guild_configs;
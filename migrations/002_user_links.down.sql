@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_user_links_summoner;
+DROP TABLE IF EXISTS user_links;
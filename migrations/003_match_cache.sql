@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS match_cache (
+    match_id TEXT PRIMARY KEY NOT NULL,
+    payload TEXT NOT NULL,
+    game_creation INTEGER NOT NULL,
+    created_at INTEGER NOT NULL
+);
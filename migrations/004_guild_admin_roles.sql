@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS guild_admin_roles (
+    guild_id TEXT PRIMARY KEY NOT NULL,
+    role_id TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS guild_cooldowns (
+    guild_id TEXT NOT NULL,
+    command TEXT NOT NULL,
+    scope TEXT NOT NULL,
+    window_secs INTEGER NOT NULL,
+    burst INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, command, scope)
+);
@@ -0,0 +1,22 @@
+CREATE TABLE user_links_old (
+    discord_user_id TEXT PRIMARY KEY NOT NULL,
+    summoner_name TEXT NOT NULL,
+    summoner_tag TEXT NOT NULL,
+    region TEXT NOT NULL,
+    riot_puuid TEXT,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL
+);
+
+INSERT INTO user_links_old (discord_user_id, summoner_name, summoner_tag, region, riot_puuid, created_at, updated_at)
+SELECT discord_user_id, summoner_name, summoner_tag, region, riot_puuid, created_at, updated_at
+FROM user_links
+WHERE is_primary = 1;
+
+DROP INDEX IF EXISTS idx_user_links_summoner;
+DROP INDEX IF EXISTS idx_user_links_primary;
+DROP TABLE user_links;
+ALTER TABLE user_links_old RENAME TO user_links;
+
+CREATE INDEX idx_user_links_summoner
+ON user_links(summoner_name, summoner_tag, region);
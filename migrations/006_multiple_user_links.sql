@@ -0,0 +1,26 @@
+CREATE TABLE user_links_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    discord_user_id TEXT NOT NULL,
+    summoner_name TEXT NOT NULL,
+    summoner_tag TEXT NOT NULL,
+    region TEXT NOT NULL,
+    riot_puuid TEXT,
+    is_primary INTEGER NOT NULL DEFAULT 0,
+    created_at INTEGER NOT NULL,
+    updated_at INTEGER NOT NULL,
+    UNIQUE (discord_user_id, summoner_name, summoner_tag)
+);
+
+INSERT INTO user_links_new (discord_user_id, summoner_name, summoner_tag, region, riot_puuid, is_primary, created_at, updated_at)
+SELECT discord_user_id, summoner_name, summoner_tag, region, riot_puuid, 1, created_at, updated_at
+FROM user_links;
+
+DROP INDEX IF EXISTS idx_user_links_summoner;
+DROP TABLE user_links;
+ALTER TABLE user_links_new RENAME TO user_links;
+
+CREATE INDEX idx_user_links_summoner
+ON user_links(summoner_name, summoner_tag, region);
+
+CREATE UNIQUE INDEX idx_user_links_primary
+ON user_links(discord_user_id) WHERE is_primary = 1;
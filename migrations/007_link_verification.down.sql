@@ -0,0 +1,5 @@
+DROP TABLE link_challenges;
+
+ALTER TABLE guild_configs DROP COLUMN require_verified;
+
+ALTER TABLE user_links DROP COLUMN verified;
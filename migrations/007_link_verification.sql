@@ -0,0 +1,10 @@
+ALTER TABLE user_links ADD COLUMN verified INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE guild_configs ADD COLUMN require_verified INTEGER NOT NULL DEFAULT 0;
+
+CREATE TABLE link_challenges (
+    discord_user_id TEXT PRIMARY KEY NOT NULL,
+    riot_puuid TEXT NOT NULL,
+    icon_id INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL
+);
@@ -0,0 +1,7 @@
+CREATE TABLE guild_rank_roles (
+    guild_id TEXT NOT NULL,
+    tier TEXT NOT NULL,
+    role_id TEXT NOT NULL,
+    updated_at INTEGER NOT NULL,
+    PRIMARY KEY (guild_id, tier)
+);
@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS idx_rank_snapshots_puuid;
+DROP TABLE rank_snapshots;
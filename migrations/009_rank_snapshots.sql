@@ -0,0 +1,14 @@
+CREATE TABLE rank_snapshots (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    riot_puuid TEXT NOT NULL,
+    queue_type TEXT NOT NULL,
+    tier TEXT NOT NULL,
+    rank TEXT NOT NULL,
+    league_points INTEGER NOT NULL,
+    wins INTEGER NOT NULL,
+    losses INTEGER NOT NULL,
+    captured_at INTEGER NOT NULL
+);
+
+CREATE INDEX idx_rank_snapshots_puuid
+ON rank_snapshots(riot_puuid, queue_type, captured_at);
@@ -0,0 +1,3 @@
+DROP TABLE match_feed_state;
+
+ALTER TABLE guild_configs DROP COLUMN feed_channel_id;
@@ -0,0 +1,7 @@
+ALTER TABLE guild_configs ADD COLUMN feed_channel_id TEXT;
+
+CREATE TABLE match_feed_state (
+    riot_puuid TEXT PRIMARY KEY NOT NULL,
+    last_match_id TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
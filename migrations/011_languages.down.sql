@@ -0,0 +1,3 @@
+DROP TABLE user_languages;
+
+ALTER TABLE guild_configs DROP COLUMN language;
@@ -0,0 +1,7 @@
+ALTER TABLE guild_configs ADD COLUMN language TEXT;
+
+CREATE TABLE user_languages (
+    discord_user_id TEXT PRIMARY KEY NOT NULL,
+    language TEXT NOT NULL,
+    updated_at INTEGER NOT NULL
+);
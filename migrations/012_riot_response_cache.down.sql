@@ -0,0 +1 @@
+DROP TABLE riot_response_cache;
@@ -0,0 +1,8 @@
+CREATE TABLE riot_response_cache (
+    cache_key TEXT PRIMARY KEY NOT NULL,
+    body TEXT NOT NULL,
+    fetched_at INTEGER NOT NULL,
+    expires_at INTEGER NOT NULL
+);
+
+CREATE INDEX idx_riot_response_cache_expires ON riot_response_cache (expires_at);
@@ -0,0 +1,14 @@
+-- Riot IDs are looked up case-insensitively, so a user can't link the same
+-- one twice in different case. Keeps the primary, then the oldest, copy.
+DELETE FROM user_links
+WHERE EXISTS (
+    SELECT 1 FROM user_links other
+    WHERE other.discord_user_id = user_links.discord_user_id
+      AND other.summoner_name = user_links.summoner_name COLLATE NOCASE
+      AND other.summoner_tag = user_links.summoner_tag COLLATE NOCASE
+      AND (other.is_primary > user_links.is_primary
+           OR (other.is_primary = user_links.is_primary AND other.id < user_links.id))
+);
+
+CREATE UNIQUE INDEX idx_user_links_riot_id
+ON user_links(discord_user_id, summoner_name COLLATE NOCASE, summoner_tag COLLATE NOCASE);
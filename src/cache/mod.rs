@@ -1,5 +1,14 @@
+mod ttl_cache;
+
+pub use ttl_cache::TtlCache;
+
 use std::time::{Duration, SystemTime};
 
+// How often caches drop expired entries nobody asked for again.
+pub const SWEEP_INTERVAL: Duration = Duration::from_secs(600);
+
+// A cached value together with when it was fetched, for "updated N minutes
+// ago" notes. Expiry is up to the `TtlCache` holding it.
 #[derive(Clone, Debug)]
 pub struct CachedData<T> {
     pub data: T,
@@ -14,29 +23,27 @@ impl<T> CachedData<T> {
         }
     }
 
-    pub fn is_stale(&self, ttl: Duration) -> bool {
-        SystemTime::now()
-            .duration_since(self.cached_at)
-            .map(|age| age > ttl)
-            .unwrap_or(true)
-    }
-
     pub fn age(&self) -> Option<Duration> {
         SystemTime::now().duration_since(self.cached_at).ok()
     }
-
-    pub fn update(&mut self, data: T) {
-        self.data = data;
-        self.cached_at = SystemTime::now();
-    }
 }
 
 pub mod ttl {
     use std::time::Duration;
 
     pub const USER_LINK: Duration = Duration::from_secs(86400);
+    pub const NO_USER_LINK: Duration = Duration::from_secs(600);
     pub const SUMMONER_PROFILE: Duration = Duration::from_secs(3600); 
     pub const MATCH_HISTORY: Duration = Duration::from_secs(3600); 
+    pub const LEADERBOARD: Duration = Duration::from_secs(1800);
+    // Stale boards are still shown while a new one builds.
+    pub const LEADERBOARD_KEPT: Duration = Duration::from_secs(86400);
+
+    // Riot API responses without a cache of their own. Accounts are kept
+    // briefly, since a Riot ID can be renamed and then taken by another
+    // account, and `link` would save the old PUUID.
+    pub const ACCOUNT: Duration = Duration::from_secs(600);
+    pub const CHAMPION_MASTERY: Duration = Duration::from_secs(1800);
 }
 
 
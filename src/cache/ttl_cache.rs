@@ -0,0 +1,393 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::future::Future;
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+struct Entry<V> {
+    value: V,
+    expires_at: Instant,
+    last_used: u64,
+}
+
+struct Entries<K, V> {
+    map: HashMap<K, Entry<V>>,
+    // Keyed by `last_used`, so the first entry is the least recently used.
+    recency: BTreeMap<u64, K>,
+    clock: u64,
+}
+
+impl<K: Eq + Hash + Clone, V> Entries<K, V> {
+    fn touch(&mut self, key: &K) -> Option<&V> {
+        self.clock += 1;
+        let entry = self.map.get_mut(key)?;
+        self.recency.remove(&entry.last_used);
+        self.recency.insert(self.clock, key.clone());
+        entry.last_used = self.clock;
+        Some(&entry.value)
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let entry = self.map.remove(key)?;
+        self.recency.remove(&entry.last_used);
+        Some(entry.value)
+    }
+}
+
+#[derive(Debug, Default)]
+struct Counters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+    expirations: AtomicU64,
+    loads: AtomicU64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CacheMetrics {
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    pub expirations: u64,
+    pub loads: u64,
+}
+
+impl fmt::Display for CacheMetrics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses, {} evicted, {} expired, {} loads",
+            self.hits, self.misses, self.evictions, self.expirations, self.loads
+        )
+    }
+}
+
+// A bounded map whose entries expire `ttl` after they were written. When
+// full, inserting drops the least recently used entry.
+pub struct TtlCache<K, V> {
+    name: &'static str,
+    capacity: usize,
+    ttl: Duration,
+    ttl_for: Option<fn(&V) -> Duration>,
+    entries: Mutex<Entries<K, V>>,
+    // One lock per key being loaded, so concurrent misses share a load.
+    loads: Mutex<HashMap<K, Arc<tokio::sync::Mutex<()>>>>,
+    counters: Counters,
+}
+
+impl<K: Eq + Hash + Clone, V: Clone> TtlCache<K, V> {
+    pub fn new(name: &'static str, capacity: usize, ttl: Duration) -> Self {
+        TtlCache {
+            name,
+            capacity,
+            ttl,
+            ttl_for: None,
+            entries: Mutex::new(Entries { map: HashMap::new(), recency: BTreeMap::new(), clock: 0 }),
+            loads: Mutex::new(HashMap::new()),
+            counters: Counters::default(),
+        }
+    }
+
+    // Picks each entry's TTL from its value, e.g. a shorter one for
+    // "nothing found" so negative results are retried sooner.
+    pub fn with_ttl_for(mut self, ttl_for: fn(&V) -> Duration) -> Self {
+        self.ttl_for = Some(ttl_for);
+        self
+    }
+
+    pub fn get(&self, key: &K) -> Option<V> {
+        self.get_at(key, Instant::now())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        self.insert_at(key, value, Instant::now());
+    }
+
+    pub fn remove(&self, key: &K) -> Option<V> {
+        self.entries.lock().unwrap().remove(key)
+    }
+
+    pub fn keys(&self) -> Vec<K> {
+        self.entries.lock().unwrap().map.keys().cloned().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.lock().unwrap().map.len()
+    }
+
+    pub fn metrics(&self) -> CacheMetrics {
+        CacheMetrics {
+            hits: self.counters.hits.load(Ordering::Relaxed),
+            misses: self.counters.misses.load(Ordering::Relaxed),
+            evictions: self.counters.evictions.load(Ordering::Relaxed),
+            expirations: self.counters.expirations.load(Ordering::Relaxed),
+            loads: self.counters.loads.load(Ordering::Relaxed),
+        }
+    }
+
+    // Returns the cached value or runs `load`. Callers that miss on the same
+    // key while a load is running wait for it instead of loading again;
+    // failed loads are not cached, so the next waiter retries.
+    pub async fn get_or_load<F, Fut, E>(&self, key: K, load: F) -> Result<V, E>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V, E>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+
+        self.locked(key.clone(), || async {
+            if let Some(value) = self.lookup(&key, Instant::now()) {
+                return Ok(value);
+            }
+
+            self.counters.loads.fetch_add(1, Ordering::Relaxed);
+            let result = load().await;
+            if let Ok(value) = &result {
+                self.insert(key.clone(), value.clone());
+            }
+            result
+        }).await
+    }
+
+    // Runs `f` holding the key's load lock. Writers that update the source
+    // and then the cache go through this, so a load that read the source
+    // before the write can't finish afterwards and put the old value back.
+    pub async fn locked<F, Fut, T>(&self, key: K, f: F) -> T
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let lock = self.loads.lock().unwrap().entry(key.clone()).or_default().clone();
+        let guard = lock.lock().await;
+
+        let result = f().await;
+
+        drop(guard);
+        // Left in place while others wait on it, so a newcomer can't take a
+        // fresh lock and run alongside them.
+        let mut loads = self.loads.lock().unwrap();
+        if loads.get(&key).is_some_and(|current| Arc::ptr_eq(current, &lock) && Arc::strong_count(current) == 2) {
+            loads.remove(&key);
+        }
+
+        result
+    }
+
+    // Drops expired entries and returns how many there were.
+    pub fn sweep(&self, now: Instant) -> usize {
+        let mut entries = self.entries.lock().unwrap();
+        let expired: Vec<K> = entries
+            .map
+            .iter()
+            .filter(|(_, entry)| entry.expires_at <= now)
+            .map(|(key, _)| key.clone())
+            .collect();
+
+        for key in &expired {
+            entries.remove(key);
+        }
+        self.counters.expirations.fetch_add(expired.len() as u64, Ordering::Relaxed);
+
+        expired.len()
+    }
+
+    fn get_at(&self, key: &K, now: Instant) -> Option<V> {
+        let value = self.lookup(key, now);
+        let counter = if value.is_some() { &self.counters.hits } else { &self.counters.misses };
+        counter.fetch_add(1, Ordering::Relaxed);
+        value
+    }
+
+    // Like `get_at` without counting a hit or miss.
+    fn lookup(&self, key: &K, now: Instant) -> Option<V> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.map.get(key)?.expires_at <= now {
+            entries.remove(key);
+            self.counters.expirations.fetch_add(1, Ordering::Relaxed);
+            return None;
+        }
+        entries.touch(key).cloned()
+    }
+
+    fn insert_at(&self, key: K, value: V, now: Instant) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut entries = self.entries.lock().unwrap();
+        entries.remove(&key);
+        while entries.map.len() >= self.capacity {
+            let Some((_, oldest)) = entries.recency.pop_first() else {
+                break;
+            };
+            entries.map.remove(&oldest);
+            self.counters.evictions.fetch_add(1, Ordering::Relaxed);
+        }
+
+        entries.clock += 1;
+        let last_used = entries.clock;
+        let ttl = self.ttl_for.map_or(self.ttl, |ttl_for| ttl_for(&value));
+        entries.recency.insert(last_used, key.clone());
+        entries.map.insert(key, Entry { value, expires_at: now + ttl, last_used });
+    }
+}
+
+impl<K, V> TtlCache<K, V>
+where
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+    V: Clone + Send + Sync + 'static,
+{
+    // Reads only drop the entry they hit, so without this, entries nobody
+    // asks for again stay until they are evicted. Stops once the cache is
+    // dropped.
+    pub fn spawn_sweeper(self: &Arc<Self>, every: Duration) {
+        let cache = Arc::downgrade(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+            interval.tick().await;
+
+            loop {
+                interval.tick().await;
+                let Some(cache) = cache.upgrade() else {
+                    break;
+                };
+
+                let swept = cache.sweep(Instant::now());
+                if swept > 0 {
+                    println!(
+                        "{} cache: dropped {} expired entries, {} left ({})",
+                        cache.name, swept, cache.len(), cache.metrics()
+                    );
+                }
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    const TTL: Duration = Duration::from_secs(60);
+
+    #[test]
+    fn test_entries_expire() {
+        let cache = TtlCache::new("test", 10, TTL);
+        let start = Instant::now();
+
+        cache.insert_at("a", 1, start);
+        assert_eq!(cache.get_at(&"a", start + Duration::from_secs(59)), Some(1));
+        assert_eq!(cache.get_at(&"a", start + TTL), None);
+        assert_eq!(cache.len(), 0);
+
+        let metrics = cache.metrics();
+        assert_eq!((metrics.hits, metrics.misses, metrics.expirations), (1, 1, 1));
+    }
+
+    #[test]
+    fn test_least_recently_used_is_evicted() {
+        let cache = TtlCache::new("test", 2, TTL);
+        let now = Instant::now();
+
+        cache.insert_at("a", 1, now);
+        cache.insert_at("b", 2, now);
+        // Reading `a` makes `b` the least recently used.
+        assert_eq!(cache.get_at(&"a", now), Some(1));
+        cache.insert_at("c", 3, now);
+
+        assert_eq!(cache.get_at(&"b", now), None);
+        assert_eq!(cache.get_at(&"a", now), Some(1));
+        assert_eq!(cache.get_at(&"c", now), Some(3));
+        assert_eq!(cache.metrics().evictions, 1);
+
+        // Overwriting an existing key doesn't evict anything.
+        cache.insert_at("c", 4, now);
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.metrics().evictions, 1);
+    }
+
+    #[test]
+    fn test_sweep_drops_only_expired() {
+        let cache = TtlCache::new("test", 10, TTL);
+        let start = Instant::now();
+
+        cache.insert_at("old", 1, start);
+        cache.insert_at("new", 2, start + Duration::from_secs(30));
+
+        assert_eq!(cache.sweep(start + TTL), 1);
+        assert_eq!(cache.len(), 1);
+        assert_eq!(cache.get_at(&"new", start + TTL), Some(2));
+        assert_eq!(cache.remove(&"new"), Some(2));
+        assert_eq!(cache.len(), 0);
+    }
+
+    #[test]
+    fn test_ttl_depends_on_value() {
+        let cache = TtlCache::new("test", 10, TTL).with_ttl_for(|value: &i32| {
+            if *value == 0 { Duration::from_secs(5) } else { TTL }
+        });
+        let start = Instant::now();
+
+        cache.insert_at("empty", 0, start);
+        cache.insert_at("full", 1, start);
+        assert_eq!(cache.get_at(&"empty", start + Duration::from_secs(5)), None);
+        assert_eq!(cache.get_at(&"full", start + Duration::from_secs(5)), Some(1));
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_loads_are_shared() {
+        let cache = TtlCache::new("test", 10, TTL);
+        let calls = AtomicUsize::new(0);
+        let load = || async {
+            calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            Ok::<_, ()>(42)
+        };
+
+        let (a, b, c) = tokio::join!(
+            cache.get_or_load("key", load),
+            cache.get_or_load("key", load),
+            cache.get_or_load("key", load),
+        );
+
+        assert_eq!((a, b, c), (Ok(42), Ok(42), Ok(42)));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.metrics().loads, 1);
+        assert_eq!(cache.get(&"key"), Some(42));
+    }
+
+    #[tokio::test]
+    async fn test_failed_loads_are_not_cached() {
+        let cache: TtlCache<&str, i32> = TtlCache::new("test", 10, TTL);
+
+        assert_eq!(cache.get_or_load("key", || async { Err("down") }).await, Err("down"));
+        assert_eq!(cache.len(), 0);
+        assert_eq!(cache.get_or_load("key", || async { Ok::<_, &str>(7) }).await, Ok(7));
+        assert_eq!(cache.metrics().loads, 2);
+    }
+
+    #[tokio::test]
+    async fn test_write_waits_for_running_load() {
+        let cache = TtlCache::new("test", 10, TTL);
+
+        // The load reads "old" before the write but would finish after it.
+        let load = cache.get_or_load("key", || async {
+            tokio::time::sleep(Duration::from_millis(30)).await;
+            Ok::<_, ()>("old")
+        });
+        let write = async {
+            tokio::time::sleep(Duration::from_millis(5)).await;
+            cache.locked("key", || async { cache.insert("key", "new") }).await;
+        };
+
+        let (loaded, ()) = tokio::join!(load, write);
+        assert_eq!(loaded, Ok("old"));
+        assert_eq!(cache.get(&"key"), Some("new"));
+    }
+}
+
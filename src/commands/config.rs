@@ -1,18 +1,35 @@
-use serenity::prelude::*;
-use serenity::model::channel::Message;
+use super::Invocation;
+use super::registry::{self, COMMANDS};
 use crate::config::{ConfigMap, DatabaseContainer};
-use crate::database::models::ServerConfig;
+use crate::database::{Database, ServerConfig};
+use dashmap::DashMap;
+use serenity::model::id::{GuildId, RoleId};
+use crate::cooldown::{Cooldown, CooldownLimit, CooldownOverride, CooldownScope, MAX_WINDOW};
+use crate::i18n::{self, Locale, t};
+use crate::rank_roles::{self as sync, SyncReport};
+use crate::riot::Tier;
+use crate::utils::{parse_channel_mention, parse_role_mention};
 
-pub async fn prefix(ctx: &Context, msg: &Message, args: Vec<String>) {
-    let guild_id = match msg.guild_id {
+const REPORT_LINES: usize = 20;
+
+// Memory only takes the new config once the database has it, so a failed
+// save leaves both on the old one.
+async fn save(db: &Database, config_map: &DashMap<GuildId, ServerConfig>, guild_id: GuildId, config: &ServerConfig) -> Result<(), sqlx::Error> {
+    db.save_config(guild_id, config).await?;
+    config_map.insert(guild_id, config.clone());
+    Ok(())
+}
+
+pub async fn prefix(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
         Some(id) => id,
         None => {
-            let _ = msg.channel_id.say(&ctx.http, "This command only works in servers!").await;
+            inv.reply(t!(inv.locale, "server-only")).await;
             return;
         }
     };
 
-    let data = ctx.data.read().await;
+    let data = inv.ctx.data.read().await;
     let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
     let db = data.get::<DatabaseContainer>().expect("Database not found");
 
@@ -21,22 +38,491 @@ pub async fn prefix(ctx: &Context, msg: &Message, args: Vec<String>) {
             .map(|entry| entry.prefix.clone())
             .unwrap_or_else(|| "!".to_string());
         
-        let response = format!("Current prefix: `{}`", current_prefix);
-        let _ = msg.channel_id.say(&ctx.http, response).await;
+        let response = t!(inv.locale, "prefix-current", prefix = current_prefix);
+        inv.reply(response).await;
+        return;
+    }
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
+    new_config.prefix = args[0].clone();
+
+    if let Err(e) = save(db, config_map, guild_id, &new_config).await {
+        eprintln!("Failed to save config to database: {}", e);
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
+        return;
+    }
+
+    let response = t!(inv.locale, "prefix-changed", prefix = args[0]);
+    inv.reply(response).await;
+}
+
+pub async fn admin_role(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
+    };
+
+    let data = inv.ctx.data.read().await;
+    let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
+
+    let arg = match args.first() {
+        Some(arg) => arg,
+        None => {
+            let response = match new_config.admin_role_id {
+                Some(role_id) => t!(inv.locale, "admin-role-current", role = role_id),
+                None => t!(inv.locale, "admin-role-none"),
+            };
+            inv.reply(response).await;
+            return;
+        }
+    };
+
+    new_config.admin_role_id = if arg.eq_ignore_ascii_case("off") {
+        None
+    } else {
+        match parse_role_mention(arg).or_else(|| arg.parse::<u64>().ok().filter(|id| *id != 0).map(RoleId::new)) {
+            Some(role_id) => Some(role_id),
+            None => {
+                inv.reply(t!(inv.locale, "admin-role-invalid", value = arg)).await;
+                return;
+            }
+        }
+    };
+
+    if let Err(e) = save(db, config_map, guild_id, &new_config).await {
+        eprintln!("Failed to save config to database: {}", e);
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
+        return;
+    }
+
+    let response = match new_config.admin_role_id {
+        Some(role_id) => t!(inv.locale, "admin-role-set", role = role_id),
+        None => t!(inv.locale, "admin-role-removed"),
+    };
+    inv.reply(response).await;
+}
+
+pub async fn verification(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
+    };
+
+    let data = inv.ctx.data.read().await;
+    let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
+
+    let require_verified = match args.first().map(|arg| arg.to_lowercase()) {
+        None => {
+            let response = if new_config.require_verified {
+                t!(inv.locale, "verification-required-status")
+            } else {
+                t!(inv.locale, "verification-optional-status")
+            };
+            inv.reply(response).await;
+            return;
+        }
+        Some(mode) if mode == "required" || mode == "on" => true,
+        Some(mode) if mode == "optional" || mode == "off" => false,
+        Some(mode) => {
+            inv.reply(t!(inv.locale, "verification-invalid", value = mode)).await;
+            return;
+        }
+    };
+    new_config.require_verified = require_verified;
+
+    if let Err(e) = save(db, config_map, guild_id, &new_config).await {
+        eprintln!("Failed to save config to database: {}", e);
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
         return;
     }
 
-    let new_config = ServerConfig {
-        prefix: args[0].clone(),
+    let response = if require_verified {
+        t!(inv.locale, "verification-required-set")
+    } else {
+        t!(inv.locale, "verification-optional-set")
+    };
+    inv.reply(response).await;
+}
+
+pub async fn feed(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
     };
 
-    config_map.insert(guild_id, new_config.clone());
+    let data = inv.ctx.data.read().await;
+    let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
 
-    if let Err(e) = db.save_config(guild_id, &new_config).await {
+    let usage = t!(inv.locale, "feed-usage", prefix = inv.prefix);
+    new_config.feed_channel = match args.first().map(|arg| arg.to_lowercase()).as_deref() {
+        None => {
+            let response = match new_config.feed_channel {
+                Some(channel_id) => t!(inv.locale, "feed-status", channel = channel_id),
+                None => format!("{}\n{}", t!(inv.locale, "feed-off-status"), usage),
+            };
+            inv.reply(response).await;
+            return;
+        }
+        Some("off") => None,
+        Some("set") => match args.get(1).and_then(|arg| parse_channel_mention(arg)) {
+            Some(channel_id) => Some(channel_id),
+            None => {
+                inv.reply(usage).await;
+                return;
+            }
+        },
+        Some(_) => {
+            inv.reply(usage).await;
+            return;
+        }
+    };
+
+    // Only checkable when the guild is cached; otherwise trust the mention.
+    if let Some(channel_id) = new_config.feed_channel {
+        let foreign = inv.ctx.cache.guild(guild_id).is_some_and(|guild| !guild.channels.contains_key(&channel_id));
+        if foreign {
+            inv.reply(t!(inv.locale, "feed-foreign-channel")).await;
+            return;
+        }
+    }
+
+    if let Err(e) = save(db, config_map, guild_id, &new_config).await {
         eprintln!("Failed to save config to database: {}", e);
-        let _ = msg.channel_id.say(&ctx.http, "Warning: Config saved to memory but failed to save to database!").await;
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
+        return;
     }
 
-    let response = format!("Prefix changed to: `{}`", args[0]);
-    let _ = msg.channel_id.say(&ctx.http, response).await;
+    let response = match new_config.feed_channel {
+        Some(channel_id) => t!(inv.locale, "feed-set", channel = channel_id),
+        None => t!(inv.locale, "feed-disabled"),
+    };
+    inv.reply(response).await;
+}
+
+pub async fn language(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
+    };
+
+    let data = inv.ctx.data.read().await;
+    let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
+
+    new_config.language = match args.first().map(|arg| arg.to_lowercase()) {
+        None => {
+            let response = match new_config.language {
+                Some(locale) => t!(inv.locale, "language-current", language = locale.name()),
+                None => t!(inv.locale, "language-unset"),
+            };
+            inv.reply(response).await;
+            return;
+        }
+        Some(value) if value == "reset" => None,
+        Some(value) => match Locale::parse(&value) {
+            Some(locale) => Some(locale),
+            None => {
+                inv.reply(t!(inv.locale, "language-invalid", value = value, languages = Locale::codes().join(", "))).await;
+                return;
+            }
+        },
+    };
+
+    let saved = save(db, config_map, guild_id, &new_config).await;
+
+    // Reply in the new language unless the author has one of their own.
+    drop(data);
+    inv.resolve_locale().await;
+
+    if let Err(e) = saved {
+        eprintln!("Failed to save config to database: {}", e);
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
+        return;
+    }
+
+    let response = match new_config.language {
+        Some(locale) => t!(inv.locale, "language-set", language = locale.name()),
+        None => t!(inv.locale, "language-reset"),
+    };
+    inv.reply(response).await;
+}
+
+pub async fn rank_roles(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
+    };
+
+    let action = args.first().map(|arg| arg.to_lowercase());
+    if let Some(action) = action.as_deref().filter(|action| *action == "sync" || *action == "dryrun") {
+        let dry_run = action == "dryrun";
+        inv.progress(t!(inv.locale, "rank-roles-checking")).await;
+
+        let response = match sync::sync_guild(inv.ctx, guild_id, dry_run).await {
+            Ok(report) => format_sync_report(inv, guild_id, &report, dry_run),
+            Err(e) => {
+                eprintln!("Failed to sync rank roles: {}", e);
+                t!(inv.locale, "rank-roles-sync-failed")
+            }
+        };
+        inv.reply(response).await;
+        return;
+    }
+
+    let data = inv.ctx.data.read().await;
+    let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
+
+    let usage = t!(inv.locale, "rank-roles-usage", prefix = inv.prefix, tiers = Tier::NAMES.join(", "));
+
+    let action = match action {
+        Some(action) => action,
+        None => {
+            let response = if new_config.rank_roles.is_empty() {
+                format!("{}\n{}", t!(inv.locale, "rank-roles-none"), usage)
+            } else {
+                let lines: Vec<String> = new_config.rank_roles
+                    .iter()
+                    .map(|(tier, role_id)| format!("**{}** → <@&{}>", tier.title(), role_id))
+                    .collect();
+                format!("**{}:**\n{}", t!(inv.locale, "rank-roles-title"), lines.join("\n"))
+            };
+            inv.reply(response).await;
+            return;
+        }
+    };
+
+    let tier = match args.get(1).and_then(|tier| Tier::parse(tier)) {
+        Some(tier) => tier,
+        None => {
+            inv.reply(usage).await;
+            return;
+        }
+    };
+
+    let response = match action.as_str() {
+        "set" => {
+            let role_id = match args.get(2).and_then(|arg| {
+                parse_role_mention(arg).or_else(|| arg.parse::<u64>().ok().filter(|id| *id != 0).map(RoleId::new))
+            }) {
+                Some(role_id) => role_id,
+                None => {
+                    inv.reply(usage).await;
+                    return;
+                }
+            };
+            new_config.rank_roles.insert(tier, role_id);
+            t!(inv.locale, "rank-roles-set", tier = tier.title(), role = role_id, prefix = inv.prefix)
+        }
+        "remove" => {
+            if new_config.rank_roles.remove(&tier).is_none() {
+                inv.reply(t!(inv.locale, "rank-roles-not-mapped", tier = tier.title())).await;
+                return;
+            }
+            t!(inv.locale, "rank-roles-removed", tier = tier.title())
+        }
+        _ => {
+            inv.reply(usage).await;
+            return;
+        }
+    };
+
+    if let Err(e) = save(db, config_map, guild_id, &new_config).await {
+        eprintln!("Failed to save config to database: {}", e);
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
+        return;
+    }
+
+    inv.reply(response).await;
+}
+
+// Role names instead of mentions, so a report doesn't ping anyone.
+fn format_sync_report(inv: &Invocation<'_>, guild_id: GuildId, report: &SyncReport, dry_run: bool) -> String {
+    let role_name = |role_id: &RoleId| {
+        inv.ctx.cache
+            .guild(guild_id)
+            .and_then(|guild| guild.roles.get(role_id).map(|role| role.name.clone()))
+            .unwrap_or_else(|| role_id.to_string())
+    };
+
+    let key = if dry_run { "rank-roles-report-dry-run" } else { "rank-roles-report" };
+    let mut response = t!(
+        inv.locale, key,
+        checked = i18n::format_number(inv.locale, report.checked as i64),
+        changes = i18n::format_number(inv.locale, report.changes.len() as i64)
+    );
+
+    for change in report.changes.iter().take(REPORT_LINES) {
+        let added = change.changes.add.iter().map(|role| format!("+{}", role_name(role)));
+        let removed = change.changes.remove.iter().map(|role| format!("-{}", role_name(role)));
+        let roles: Vec<String> = removed.chain(added).collect();
+        response.push_str(&format!("\n• {}: {}", change.name, roles.join(" ")));
+    }
+    if report.changes.len() > REPORT_LINES {
+        let more = i18n::format_number(inv.locale, (report.changes.len() - REPORT_LINES) as i64);
+        response.push_str(&format!("\n{}", t!(inv.locale, "rank-roles-report-more", count = more)));
+    }
+    if report.errors > 0 {
+        let errors = i18n::format_number(inv.locale, report.errors as i64);
+        response.push_str(&format!("\n{}", t!(inv.locale, "rank-roles-report-errors", count = errors)));
+    }
+
+    response
+}
+
+pub async fn cooldown(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
+    };
+
+    let data = inv.ctx.data.read().await;
+    let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+
+    let mut new_config = config_map.get(&guild_id)
+        .map(|entry| entry.clone())
+        .unwrap_or_default();
+
+    let spec = match args.first() {
+        Some(name) => match registry::find(name) {
+            Some(spec) => spec,
+            None => {
+                inv.reply(t!(inv.locale, "cooldown-unknown-command", command = name)).await;
+                return;
+            }
+        },
+        None => {
+            let lines: Vec<String> = COMMANDS
+                .iter()
+                .map(|spec| (spec.name, spec.cooldown.with_overrides(spec.name, &new_config.cooldown_overrides)))
+                .filter(|(_, cooldown)| *cooldown != Cooldown::NONE)
+                .map(|(name, cooldown)| format!("`{}` - {}", name, describe_cooldown(inv.locale, &cooldown)))
+                .collect();
+
+            let response = if lines.is_empty() {
+                t!(inv.locale, "cooldown-none")
+            } else {
+                format!("**{}:**\n{}", t!(inv.locale, "cooldown-title"), lines.join("\n"))
+            };
+            inv.reply(response).await;
+            return;
+        }
+    };
+
+    let action = match args.get(1) {
+        Some(action) => action.to_lowercase(),
+        None => {
+            let cooldown = spec.cooldown.with_overrides(spec.name, &new_config.cooldown_overrides);
+            inv.reply(format!("`{}` - {}", spec.name, describe_cooldown(inv.locale, &cooldown))).await;
+            return;
+        }
+    };
+
+    if action == "reset" {
+        new_config.cooldown_overrides.retain(|o| o.command != spec.name);
+    } else {
+        let scope = match CooldownScope::parse(&action) {
+            Some(scope) => scope,
+            None => {
+                inv.reply(t!(inv.locale, "cooldown-invalid-scope", value = action)).await;
+                return;
+            }
+        };
+
+        let seconds = match args.get(2).map(|value| value.parse::<u64>()) {
+            Some(Ok(seconds)) if seconds <= MAX_WINDOW.as_secs() => seconds,
+            _ => {
+                inv.reply(t!(inv.locale, "cooldown-usage", prefix = inv.prefix, max = MAX_WINDOW.as_secs())).await;
+                return;
+            }
+        };
+
+        let burst = match args.get(3).map(|value| value.parse::<u32>()) {
+            None => 1,
+            Some(Ok(burst)) if burst > 0 => burst,
+            Some(_) => {
+                inv.reply(t!(inv.locale, "cooldown-burst-invalid")).await;
+                return;
+            }
+        };
+
+        let limit = if seconds == 0 { None } else { Some(CooldownLimit::new(seconds, burst)) };
+
+        new_config.cooldown_overrides.retain(|o| o.command != spec.name || o.scope != scope);
+        new_config.cooldown_overrides.push(CooldownOverride {
+            command: spec.name.to_string(),
+            scope,
+            limit,
+        });
+    }
+
+    if let Err(e) = save(db, config_map, guild_id, &new_config).await {
+        eprintln!("Failed to save config to database: {}", e);
+        inv.reply(t!(inv.locale, "config-save-failed")).await;
+        return;
+    }
+
+    let cooldown = spec.cooldown.with_overrides(spec.name, &new_config.cooldown_overrides);
+    inv.reply(format!("✅ `{}` - {}", spec.name, describe_cooldown(inv.locale, &cooldown))).await;
+}
+
+fn describe_cooldown(locale: Locale, cooldown: &Cooldown) -> String {
+    let describe = |scope: CooldownScope| {
+        let scope_name = t!(locale, &format!("cooldown-scope-{}", scope.as_str()));
+        match cooldown.get(scope) {
+            Some(limit) => t!(
+                locale, "cooldown-limit",
+                burst = limit.burst,
+                window = i18n::format_duration(locale, limit.window),
+                scope = scope_name
+            ),
+            None => t!(locale, "cooldown-no-limit", scope = scope_name),
+        }
+    };
+
+    format!("{}, {}", describe(CooldownScope::User), describe(CooldownScope::Guild))
 }
@@ -1,27 +1,96 @@
-use serenity::prelude::*;
-use serenity::model::channel::Message;
+use super::Invocation;
+use super::registry::{self, Category, COMMANDS};
+use crate::i18n::{Locale, t};
+use crate::riot::Platform;
 
-pub async fn ping(ctx: &Context, msg: &Message) {
-    if let Err(why) = msg.channel_id.say(&ctx.http, "Pong!").await {
-        println!("Error sending message: {:?}", why);
+pub async fn ping(inv: &mut Invocation<'_>, _args: Vec<String>) {
+    inv.reply(t!(inv.locale, "pong")).await;
+}
+
+pub async fn help(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let response = match args.first() {
+        Some(name) => match registry::find(name) {
+            Some(spec) => command_help(inv.locale, spec, &inv.prefix),
+            None => t!(inv.locale, "help-unknown", command = name, prefix = inv.prefix),
+        },
+        None => overview(inv.locale, &inv.prefix),
+    };
+
+    inv.reply(response).await;
+}
+
+fn description(locale: Locale, spec: &registry::CommandSpec) -> String {
+    t!(locale, &format!("command-{}", spec.name))
+}
+
+fn arg_description(locale: Locale, spec: &registry::CommandSpec, arg: &registry::ArgSpec) -> String {
+    t!(locale, &format!("arg-{}-{}", spec.name, arg.name.replace('_', "-")))
+}
+
+fn overview(locale: Locale, prefix: &str) -> String {
+    let mut text = format!("{}\n", t!(locale, "help-header"));
+
+    for category in Category::ALL {
+        text.push_str(&format!("\n**{}**\n", t!(locale, category.key())));
+        for spec in COMMANDS.iter().filter(|spec| spec.category == category) {
+            text.push_str(&format!("`{}` - {}\n", spec.usage(prefix), description(locale, spec)));
+        }
+    }
+
+    text.push('\n');
+    text.push_str(&t!(locale, "help-footer", regions = Platform::game_regions().join(", "), prefix = prefix));
+
+    text
+}
+
+fn command_help(locale: Locale, spec: &registry::CommandSpec, prefix: &str) -> String {
+    let mut text = format!(
+        "**{}{}** - {}\n{}",
+        prefix, spec.name, description(locale, spec), t!(locale, "help-usage", usage = spec.usage(prefix))
+    );
+
+    if !spec.aliases.is_empty() {
+        let aliases: Vec<String> = spec.aliases.iter().map(|alias| format!("`{}`", alias)).collect();
+        text.push_str(&format!("\n{}", t!(locale, "help-aliases", aliases = aliases.join(", "))));
+    }
+
+    if !spec.permissions.is_empty() {
+        let permissions = spec.permissions.get_permission_names().join(", ");
+        text.push_str(&format!("\n{}", t!(locale, "help-requires", permissions = permissions)));
     }
+
+    if !spec.args.is_empty() {
+        text.push_str(&format!("\n{}", t!(locale, "help-arguments")));
+        for arg in spec.args {
+            let optional = if arg.required { String::new() } else { format!(" {}", t!(locale, "help-optional")) };
+            text.push_str(&format!("\n  `{}`{} - {}", arg.name, optional, arg_description(locale, spec, arg)));
+        }
+    }
+
+    if !spec.examples.is_empty() {
+        let examples: Vec<String> = spec.examples.iter().map(|example| format!("`{}{}`", prefix, example)).collect();
+        text.push_str(&format!("\n{}", t!(locale, "help-examples", examples = examples.join(", "))));
+    }
+
+    text
 }
 
-pub async fn help(ctx: &Context, msg: &Message) {
-    let help_text = "Available commands:\n\
-        ping - Responds with Pong!\n\
-        help - Shows this message\n\
-        prefix [new_prefix] - View or set command prefix for this server\n\
-        link <Name#TAG> <region> - Link your Discord account to your LoL account\n\
-        unlink - Remove your linked LoL account\n\
-        me - Show your linked LoL account\n\
-        \n\
-        Regions: na, euw, eune, kr, br, lan, las, oce, ru, tr, jp, ph, sg, th, tw, vn\n\
-        Example: !link Faker#KR1 kr\n\
-        \n\
-        Tip: Use quotes for multi-word arguments: `!command \"multi word arg\"`";
-    
-    if let Err(why) = msg.channel_id.say(&ctx.http, help_text).await {
-        println!("Error sending message: {:?}", why);
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n;
+
+    #[test]
+    fn test_every_command_and_argument_is_described() {
+        for spec in COMMANDS {
+            assert!(i18n::has_message(&format!("command-{}", spec.name)), "no description for {}", spec.name);
+            for arg in spec.args {
+                let key = format!("arg-{}-{}", spec.name, arg.name.replace('_', "-"));
+                assert!(i18n::has_message(&key), "no description for {}", key);
+            }
+        }
+        for category in Category::ALL {
+            assert!(i18n::has_message(category.key()));
+        }
     }
 }
@@ -0,0 +1,88 @@
+use super::Invocation;
+use chrono::Utc;
+use serenity::builder::CreateAttachment;
+use crate::config::{DatabaseContainer, RiotClientContainer};
+use crate::graph::{self, LpChart};
+use crate::i18n::t;
+use crate::leaderboard::RankedQueue;
+use crate::rank_history::{self, format_rank};
+use crate::utils::parse_user_mention;
+use super::lookup::{LinkedAccount, linked_account};
+
+const DEFAULT_DAYS: i64 = 30;
+const MAX_DAYS: i64 = 365;
+
+pub async fn graph(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let mut user_id = inv.author.id;
+    let mut days = DEFAULT_DAYS;
+
+    for arg in &args {
+        if let Some(mentioned) = parse_user_mention(arg) {
+            user_id = mentioned;
+        } else if let Some(parsed) = arg.parse::<i64>().ok().filter(|d| (1..=MAX_DAYS).contains(d)) {
+            days = parsed;
+        } else {
+            inv.reply(t!(inv.locale, "graph-invalid-argument", value = arg, prefix = inv.prefix, max = MAX_DAYS)).await;
+            return;
+        }
+    }
+
+    let Some(LinkedAccount { link, puuid, platform }) = linked_account(inv, user_id).await else {
+        return;
+    };
+
+    let data = inv.ctx.data.read().await;
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+
+    // Same as `progress`: end the chart at the current rank.
+    if let Err(e) = rank_history::capture(db, riot_client, &puuid, platform).await {
+        eprintln!("Failed to capture rank snapshot: {}", e);
+    }
+
+    let queue = RankedQueue::Solo;
+    let now = Utc::now().timestamp();
+    let since = now - days * 24 * 60 * 60;
+    let mut snapshots = match db.get_snapshots_since(&puuid, queue.queue_type(), since).await {
+        Ok(snapshots) => snapshots,
+        Err(e) => {
+            eprintln!("Failed to load rank snapshots: {}", e);
+            inv.reply(t!(inv.locale, "graph-history-failed")).await;
+            return;
+        }
+    };
+
+    if rank_history::has_history(&snapshots, since) {
+        rank_history::extend_to(&mut snapshots, now);
+    }
+    let points = rank_history::lp_points(&snapshots);
+    if points.len() < 2 {
+        inv.reply(t!(inv.locale, "graph-no-history", queue = t!(inv.locale, queue.key()), riot_id = link.riot_id())).await;
+        return;
+    }
+
+    // The chart font is ASCII only, so the Riot ID stays in the message.
+    let title = format!("{} - {} days", queue.title(), days);
+    let png = match graph::render_png(&LpChart { title: &title, points: &points }) {
+        Ok(png) => png,
+        Err(e) => {
+            eprintln!("Failed to encode LP graph: {}", e);
+            inv.reply(t!(inv.locale, "graph-draw-failed")).await;
+            return;
+        }
+    };
+
+    let summary = match (snapshots.first(), snapshots.last()) {
+        (Some(first), Some(last)) => format!("{} → {}", format_rank(first), format_rank(last)),
+        _ => String::new(),
+    };
+    let content = t!(
+        inv.locale,
+        "graph-summary",
+        riot_id = link.riot_id(),
+        queue = t!(inv.locale, queue.key()),
+        days = days,
+        summary = summary,
+    );
+    inv.reply_with_attachment(content, CreateAttachment::bytes(png, "lp-graph.png")).await;
+}
@@ -0,0 +1,130 @@
+use super::Invocation;
+use crate::config::{DatabaseContainer, RiotClientContainer};
+use crate::match_cache::{MatchHistoryCache, MatchHistoryKey};
+use crate::cache::CachedData;
+use crate::i18n::t;
+use crate::presentation;
+use crate::riot::{queue_id_from_name, QUEUE_ALIASES};
+use super::lookup::{LinkedAccount, linked_account, riot_error_message};
+
+const DEFAULT_COUNT: u32 = 5;
+const MAX_COUNT: u32 = 10;
+
+pub async fn history(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let (count, queue) = match parse_filters(&args) {
+        Ok(filters) => filters,
+        Err(arg) => {
+            inv.reply(t!(
+                inv.locale,
+                "history-invalid-argument",
+                value = arg,
+                prefix = inv.prefix,
+                max = MAX_COUNT,
+                queues = QUEUE_ALIASES.join(", "),
+            )).await;
+            return;
+        }
+    };
+
+    let Some(LinkedAccount { link, puuid, platform }) = linked_account(inv, inv.author.id).await else {
+        return;
+    };
+
+    let data = inv.ctx.data.read().await;
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+    let history_cache = data.get::<MatchHistoryCache>().expect("MatchHistoryCache not found");
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+
+    let key = MatchHistoryKey { puuid: puuid.clone(), count, queue };
+    let loaded = history_cache
+        .get_or_load(key, || async {
+            riot_client.get_match_ids_by_puuid(&puuid, platform, count, queue).await.map(CachedData::new)
+        })
+        .await;
+
+    let (match_ids, age) = match loaded {
+        Ok(cached) => {
+            let age = cached.age();
+            (cached.data, age)
+        }
+        Err(e) => {
+            inv.reply_card(presentation::error(riot_error_message(inv.locale, &e))).await;
+            return;
+        }
+    };
+
+    if match_ids.is_empty() {
+        inv.reply(t!(inv.locale, "history-empty")).await;
+        return;
+    }
+
+    let mut lines = Vec::with_capacity(match_ids.len());
+    for match_id in &match_ids {
+        let match_dto = match db.get_cached_match(match_id).await {
+            Ok(Some(match_dto)) => match_dto,
+            Ok(None) => match riot_client.get_match(match_id, platform).await {
+                Ok(match_dto) => {
+                    if let Err(e) = db.save_cached_match(&match_dto).await {
+                        eprintln!("Failed to cache match {}: {}", match_id, e);
+                    }
+                    match_dto
+                }
+                Err(e) => {
+                    inv.reply_card(presentation::error(riot_error_message(inv.locale, &e))).await;
+                    return;
+                }
+            },
+            Err(e) => {
+                eprintln!("Failed to read cached match {}: {}", match_id, e);
+                inv.reply(t!(inv.locale, "history-load-failed")).await;
+                return;
+            }
+        };
+
+        if let Some(line) = presentation::match_line(&match_dto, &puuid) {
+            lines.push(line);
+        }
+    }
+
+    inv.reply_card(presentation::match_history(inv.locale, &link.riot_id(), &lines, age)).await;
+}
+
+// Numbers are counts unless they name a known queue; anything else is
+// returned as the offending argument.
+fn parse_filters(args: &[String]) -> Result<(u32, Option<i32>), &str> {
+    let mut count = DEFAULT_COUNT;
+    let mut queue = None;
+
+    for arg in args {
+        match (arg.parse::<u32>(), queue_id_from_name(arg)) {
+            (_, Some(id)) => queue = Some(id),
+            (Ok(n), None) if (1..=MAX_COUNT).contains(&n) => count = n,
+            _ => return Err(arg),
+        }
+    }
+
+    Ok((count, queue))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<(u32, Option<i32>), String> {
+        let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+        parse_filters(&args).map_err(str::to_string)
+    }
+
+    #[test]
+    fn test_parse_filters() {
+        assert_eq!(parse(&[]), Ok((DEFAULT_COUNT, None)));
+        assert_eq!(parse(&["3", "aram"]), Ok((3, Some(450))));
+        assert_eq!(parse(&["420"]), Ok((DEFAULT_COUNT, Some(420))));
+    }
+
+    #[test]
+    fn test_count_out_of_range_is_rejected() {
+        assert_eq!(parse(&["20"]), Err("20".to_string()));
+        assert_eq!(parse(&["0"]), Err("0".to_string()));
+    }
+}
@@ -0,0 +1,251 @@
+use serenity::prelude::*;
+use serenity::builder::{
+    CreateActionRow, CreateAttachment, CreateEmbed, CreateInteractionResponse, CreateInteractionResponseMessage,
+    CreateMessage, EditInteractionResponse, EditMessage,
+};
+use serenity::model::application::CommandInteraction;
+use serenity::model::channel::Message;
+use serenity::model::id::{GuildId, UserId};
+use serenity::model::guild::Member;
+use serenity::model::permissions::Permissions;
+use serenity::model::user::User;
+use crate::config::{ConfigMap, UserLocales};
+use crate::i18n::Locale;
+use crate::presentation::Card;
+
+enum Source<'a> {
+    Message(&'a Message),
+    Interaction(&'a CommandInteraction),
+}
+
+// A single command call, whether it came from a prefixed message or a slash
+// command. Commands only talk to Discord through this so both paths behave
+// the same.
+pub struct Invocation<'a> {
+    pub ctx: &'a Context,
+    pub author: &'a User,
+    pub guild_id: Option<GuildId>,
+    pub prefix: String,
+    pub locale: Locale,
+    source: Source<'a>,
+    pending: Option<Message>,
+    responded: bool,
+}
+
+impl<'a> Invocation<'a> {
+    pub fn from_message(ctx: &'a Context, msg: &'a Message, prefix: &str) -> Self {
+        Invocation {
+            ctx,
+            author: &msg.author,
+            guild_id: msg.guild_id,
+            prefix: prefix.to_string(),
+            locale: Locale::default(),
+            source: Source::Message(msg),
+            pending: None,
+            responded: false,
+        }
+    }
+
+    pub fn from_interaction(ctx: &'a Context, interaction: &'a CommandInteraction) -> Self {
+        Invocation {
+            ctx,
+            author: &interaction.user,
+            guild_id: interaction.guild_id,
+            prefix: "/".to_string(),
+            locale: Locale::default(),
+            source: Source::Interaction(interaction),
+            pending: None,
+            responded: false,
+        }
+    }
+
+    pub async fn resolve_locale(&mut self) {
+        let client = match self.source {
+            Source::Interaction(interaction) => Some(interaction.locale.as_str()),
+            Source::Message(_) => None,
+        };
+        self.locale = resolve_locale(self.ctx, self.author.id, self.guild_id, client).await;
+    }
+
+    pub async fn member(&self) -> Option<Member> {
+        let guild_id = self.guild_id?;
+        match self.source {
+            Source::Interaction(interaction) => interaction.member.as_deref().cloned(),
+            Source::Message(_) => guild_id.member(self.ctx, self.author.id).await.ok(),
+        }
+    }
+
+    // Guild-wide permissions of the author. Interactions carry them already;
+    // for messages they are computed from the cached guild roles.
+    pub async fn permissions(&self, member: &Member) -> Option<Permissions> {
+        if let Source::Interaction(_) = self.source {
+            return member.permissions;
+        }
+
+        let guild = self.ctx.cache.guild(self.guild_id?)?;
+        if guild.owner_id == member.user.id {
+            return Some(Permissions::all());
+        }
+
+        let permissions = guild
+            .roles
+            .values()
+            .filter(|role| role.id.get() == guild.id.get() || member.roles.contains(&role.id))
+            .fold(Permissions::empty(), |permissions, role| permissions | role.permissions);
+
+        if permissions.administrator() {
+            Some(Permissions::all())
+        } else {
+            Some(permissions)
+        }
+    }
+
+    // Embeds need Embed Links in guild channels. Interaction responses
+    // always allow them, and when the cache can't tell we assume they work.
+    pub fn can_embed(&self) -> bool {
+        let Source::Message(msg) = self.source else {
+            return true;
+        };
+        let Some(guild) = msg.guild_id.and_then(|guild_id| self.ctx.cache.guild(guild_id)) else {
+            return true;
+        };
+        let bot_id = self.ctx.cache.current_user().id;
+
+        match (guild.channels.get(&msg.channel_id), guild.members.get(&bot_id)) {
+            (Some(channel), Some(member)) => guild.user_permissions_in(channel, member).embed_links(),
+            _ => true,
+        }
+    }
+
+    // Acknowledges a slash command up front; Discord drops interactions that
+    // get no response within three seconds, which Riot lookups can exceed.
+    pub async fn defer(&mut self) {
+        let Source::Interaction(interaction) = self.source else {
+            return;
+        };
+
+        match interaction.defer(&self.ctx.http).await {
+            Ok(()) => self.responded = true,
+            Err(why) => println!("Error deferring interaction: {:?}", why),
+        }
+    }
+
+    // Shows a placeholder that the next `reply` replaces.
+    pub async fn progress(&mut self, content: impl Into<String>) {
+        let content = content.into();
+        match self.source {
+            Source::Message(msg) => {
+                if let Some(pending) = self.pending.as_mut() {
+                    let _ = pending.edit(&self.ctx.http, EditMessage::new().content(content)).await;
+                } else if let Ok(sent) = msg.channel_id.say(&self.ctx.http, content).await {
+                    self.pending = Some(sent);
+                }
+            }
+            Source::Interaction(_) => self.send_interaction(content, Vec::new(), Vec::new(), Vec::new()).await,
+        }
+    }
+
+    pub async fn reply(&mut self, content: impl Into<String>) {
+        self.reply_with_components(content, Vec::new()).await;
+    }
+
+    pub async fn reply_with_components(&mut self, content: impl Into<String>, components: Vec<CreateActionRow>) {
+        self.send(content.into(), Vec::new(), components, Vec::new()).await;
+    }
+
+    pub async fn reply_with_attachment(&mut self, content: impl Into<String>, attachment: CreateAttachment) {
+        self.send(content.into(), Vec::new(), Vec::new(), vec![attachment]).await;
+    }
+
+    // Falls back to the card's plain text where embeds would be suppressed.
+    pub async fn reply_card(&mut self, card: Card) {
+        if self.can_embed() {
+            self.send(String::new(), vec![card.embed()], Vec::new(), Vec::new()).await;
+        } else {
+            self.reply(card.plain_text()).await;
+        }
+    }
+
+    async fn send(
+        &mut self,
+        content: String,
+        embeds: Vec<CreateEmbed>,
+        components: Vec<CreateActionRow>,
+        files: Vec<CreateAttachment>,
+    ) {
+        match self.source {
+            Source::Message(msg) => {
+                if let Some(mut pending) = self.pending.take() {
+                    let mut edit = EditMessage::new().content(content).embeds(embeds).components(components);
+                    for file in files {
+                        edit = edit.new_attachment(file);
+                    }
+                    let _ = pending.edit(&self.ctx.http, edit).await;
+                } else if let Err(why) = msg
+                    .channel_id
+                    .send_message(
+                        &self.ctx.http,
+                        CreateMessage::new().content(content).embeds(embeds).components(components).files(files),
+                    )
+                    .await
+                {
+                    println!("Error sending message: {:?}", why);
+                }
+            }
+            Source::Interaction(_) => self.send_interaction(content, embeds, components, files).await,
+        }
+    }
+
+    async fn send_interaction(
+        &mut self,
+        content: String,
+        embeds: Vec<CreateEmbed>,
+        components: Vec<CreateActionRow>,
+        files: Vec<CreateAttachment>,
+    ) {
+        let Source::Interaction(interaction) = self.source else {
+            return;
+        };
+
+        let result = if self.responded {
+            let mut edit = EditInteractionResponse::new().content(content).embeds(embeds).components(components);
+            for file in files {
+                edit = edit.new_attachment(file);
+            }
+            interaction.edit_response(&self.ctx.http, edit).await.map(|_| ())
+        } else {
+            let message = CreateInteractionResponseMessage::new()
+                .content(content)
+                .embeds(embeds)
+                .components(components)
+                .files(files);
+            interaction
+                .create_response(&self.ctx.http, CreateInteractionResponse::Message(message))
+                .await
+        };
+
+        match result {
+            Ok(()) => self.responded = true,
+            Err(why) => println!("Error responding to interaction: {:?}", why),
+        }
+    }
+}
+
+// The user's own language, then the server's, then (for interactions) the
+// language of their Discord client.
+pub async fn resolve_locale(ctx: &Context, user_id: UserId, guild_id: Option<GuildId>, client: Option<&str>) -> Locale {
+    let data = ctx.data.read().await;
+    let user = data
+        .get::<UserLocales>()
+        .expect("UserLocales not found")
+        .get(&user_id)
+        .map(|locale| *locale);
+    let guild = guild_id.and_then(|guild_id| {
+        data.get::<ConfigMap>()
+            .expect("ConfigMap not found")
+            .get(&guild_id)
+            .and_then(|config| config.language)
+    });
+
+    user.or(guild).or_else(|| client.and_then(Locale::parse)).unwrap_or_default()
+}
@@ -0,0 +1,180 @@
+use super::Invocation;
+use super::invocation::resolve_locale;
+use serenity::builder::{CreateActionRow, CreateButton, CreateInteractionResponse, CreateInteractionResponseMessage};
+use serenity::model::application::ComponentInteraction;
+use serenity::model::id::UserId;
+use serenity::prelude::*;
+use std::time::Duration;
+use crate::i18n::{Locale, t};
+use crate::leaderboard::{self, Leaderboard, RankedQueue};
+
+const PAGE_SIZE: usize = 10;
+
+// Buttons carry the queue and page they lead to, so paging works for any
+// message and survives restarts.
+pub const BUTTON_PREFIX: &str = "leaderboard:";
+
+pub async fn leaderboard(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => {
+            inv.reply(t!(inv.locale, "server-only")).await;
+            return;
+        }
+    };
+
+    let queue = match args.first() {
+        Some(arg) => match RankedQueue::parse(arg) {
+            Some(queue) => queue,
+            None => {
+                inv.reply(t!(inv.locale, "leaderboard-queue-invalid", value = arg)).await;
+                return;
+            }
+        },
+        None => RankedQueue::Solo,
+    };
+
+    match leaderboard::get(inv.ctx, guild_id).await {
+        Some((board, age)) => {
+            let (content, components) = render(inv.locale, board.queue(queue), queue, 0, age, inv.author.id);
+            inv.reply_with_components(content, components).await;
+        }
+        None => inv.reply(t!(inv.locale, "leaderboard-building")).await,
+    }
+}
+
+pub async fn handle_button(ctx: &Context, component: &ComponentInteraction) {
+    let (queue, page) = match parse_button(&component.data.custom_id) {
+        Some(parsed) => parsed,
+        None => return,
+    };
+    let guild_id = match component.guild_id {
+        Some(id) => id,
+        None => return,
+    };
+
+    let locale = resolve_locale(ctx, component.user.id, Some(guild_id), Some(&component.locale)).await;
+
+    // The board may have been dropped since the message was sent; the
+    // message is left as it is and only the clicker hears about it.
+    let response = match leaderboard::get(ctx, guild_id).await {
+        Some((board, age)) => {
+            let (content, components) = render(locale, board.queue(queue), queue, page, age, component.user.id);
+            CreateInteractionResponse::UpdateMessage(
+                CreateInteractionResponseMessage::new().content(content).components(components),
+            )
+        }
+        None => CreateInteractionResponse::Message(
+            CreateInteractionResponseMessage::new().content(t!(locale, "leaderboard-building")).ephemeral(true),
+        ),
+    };
+
+    if let Err(why) = component.create_response(&ctx.http, response).await {
+        println!("Error updating leaderboard: {:?}", why);
+    }
+}
+
+fn render(
+    locale: Locale,
+    board: &Leaderboard,
+    queue: RankedQueue,
+    page: usize,
+    age: Option<Duration>,
+    viewer: UserId,
+) -> (String, Vec<CreateActionRow>) {
+    let pages = board.entries.len().div_ceil(PAGE_SIZE).max(1);
+    let page = page.min(pages - 1);
+
+    let content = format_page(locale, board, queue, page, pages, age, viewer);
+    if pages == 1 {
+        return (content, Vec::new());
+    }
+
+    let buttons = vec![
+        CreateButton::new(button_id(queue, page.saturating_sub(1)))
+            .label("◀")
+            .disabled(page == 0),
+        CreateButton::new(button_id(queue, page + 1))
+            .label("▶")
+            .disabled(page + 1 >= pages),
+    ];
+    (content, vec![CreateActionRow::Buttons(buttons)])
+}
+
+fn format_page(
+    locale: Locale,
+    board: &Leaderboard,
+    queue: RankedQueue,
+    page: usize,
+    pages: usize,
+    age: Option<Duration>,
+    viewer: UserId,
+) -> String {
+    let mut response = t!(locale, "leaderboard-title", queue = t!(locale, queue.key()), page = page + 1, pages = pages);
+
+    if board.entries.is_empty() {
+        response.push_str(&format!("\n{}", t!(locale, "leaderboard-empty")));
+    }
+
+    for (i, entry) in board.entries.iter().enumerate().skip(page * PAGE_SIZE).take(PAGE_SIZE) {
+        let tier = match entry.entry.ranked_tier() {
+            Some(tier) => tier.title().to_string(),
+            None => t!(locale, "unranked"),
+        };
+        let division = match entry.entry.ranked_tier() {
+            Some(tier) if tier.is_apex() => String::new(),
+            _ => format!(" {}", entry.entry.rank),
+        };
+        response.push_str(&format!(
+            "\n`#{}` **{}** — {}{} {} LP · {}",
+            i + 1, entry.name, tier, division, entry.entry.league_points, entry.riot_id
+        ));
+        if entry.user_id == viewer {
+            response.push_str(" ⬅️");
+        }
+    }
+
+    if board.unranked > 0 {
+        response.push_str(&format!("\n{}", t!(locale, "leaderboard-unranked", count = board.unranked)));
+    }
+    if let Some(age) = age.filter(|age| age.as_secs() >= 60) {
+        response.push_str(&format!("\n{}", t!(locale, "leaderboard-updated", minutes = age.as_secs() / 60)));
+    }
+
+    response
+}
+
+fn button_id(queue: RankedQueue, page: usize) -> String {
+    format!("{}{}:{}", BUTTON_PREFIX, queue.as_str(), page)
+}
+
+fn parse_button(custom_id: &str) -> Option<(RankedQueue, usize)> {
+    let (queue, page) = custom_id.strip_prefix(BUTTON_PREFIX)?.split_once(':')?;
+    Some((RankedQueue::parse(queue)?, page.parse().ok()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_button_round_trip() {
+        assert_eq!(parse_button(&button_id(RankedQueue::Flex, 3)), Some((RankedQueue::Flex, 3)));
+        assert_eq!(parse_button("leaderboard:aram:1"), None);
+        assert_eq!(parse_button("other:solo:1"), None);
+    }
+
+    #[test]
+    fn test_single_page_has_no_buttons() {
+        let (content, components) = render(Locale::En, &Leaderboard::default(), RankedQueue::Solo, 5, None, UserId::new(1));
+        assert!(content.contains("page 1/1"));
+        assert!(components.is_empty());
+    }
+
+    #[test]
+    fn test_page_follows_locale() {
+        let (content, _) = render(Locale::Es, &Leaderboard::default(), RankedQueue::Flex, 0, None, UserId::new(1));
+        assert!(content.starts_with("**🏆 Tabla de Clasificatoria Flexible** (página 1/1)"));
+        assert!(content.contains("¡Usa `link` para unirte!"));
+    }
+}
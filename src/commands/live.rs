@@ -0,0 +1,178 @@
+use super::Invocation;
+use chrono::Utc;
+use crate::cache::{CachedData, TtlCache};
+use crate::config::{RiotClientContainer, StaticDataContainer};
+use crate::i18n::{Locale, t};
+use crate::profile_cache::ProfileCache;
+use crate::riot::static_data::StaticData;
+use crate::riot::{CurrentGameInfo, CurrentGameParticipant, LeagueEntry, Platform, RankedProfile, RiotApiError, RiotClient, queue_name};
+use crate::utils::parse_user_mention;
+use super::lookup::{LinkedAccount, linked_account, riot_error_message};
+
+pub async fn live(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let user_id = match args.first().map(|arg| parse_user_mention(arg)) {
+        None => inv.author.id,
+        Some(Some(user_id)) => user_id,
+        Some(None) => {
+            inv.reply(t!(inv.locale, "live-usage", prefix = inv.prefix)).await;
+            return;
+        }
+    };
+
+    let Some(LinkedAccount { link, puuid, platform }) = linked_account(inv, user_id).await else {
+        return;
+    };
+
+    let data = inv.ctx.data.read().await;
+    let profile_cache = data.get::<ProfileCache>().expect("ProfileCache not found");
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+    let static_data = data.get::<StaticDataContainer>().expect("StaticDataContainer not found").current();
+
+    let game = match riot_client.get_active_game(&puuid, platform).await {
+        Ok(Some(game)) => game,
+        Ok(None) => {
+            inv.reply(t!(inv.locale, "live-not-in-game", riot_id = link.riot_id())).await;
+            return;
+        }
+        Err(e) => {
+            inv.reply(riot_error_message(inv.locale, &e)).await;
+            return;
+        }
+    };
+
+    // One league lookup per player can take a moment.
+    inv.progress(t!(inv.locale, "live-looking-up", riot_id = link.riot_id())).await;
+
+    let ranked_queue = match game.game_queue_config_id {
+        Some(440) => LeagueEntry::RANKED_FLEX,
+        _ => LeagueEntry::RANKED_SOLO,
+    };
+
+    let mut lines = Vec::with_capacity(game.participants.len());
+    for participant in &game.participants {
+        let rank = match &participant.puuid {
+            Some(puuid) => participant_rank(profile_cache, riot_client, puuid, platform, ranked_queue).await,
+            None => None,
+        };
+        lines.push(format_participant(inv.locale, &static_data, participant, rank.as_ref(), participant.puuid.as_deref() == Some(&puuid)));
+    }
+
+    let response = format_game(inv.locale, &link.riot_id(), &game, &lines, Utc::now().timestamp_millis());
+    inv.reply(response).await;
+}
+
+// Prefers a fresh cached profile over another league request.
+async fn participant_rank(
+    profile_cache: &TtlCache<String, CachedData<RankedProfile>>,
+    riot_client: &RiotClient,
+    puuid: &str,
+    platform: Platform,
+    queue_type: &str,
+) -> Option<LeagueEntry> {
+    let cached = profile_cache
+        .get(&puuid.to_string())
+        .map(|cached| cached.data.solo.iter().chain(&cached.data.flex).cloned().collect::<Vec<_>>());
+
+    let entries = match cached {
+        Some(entries) => entries,
+        None => match riot_client.get_league_entries_by_puuid(puuid, platform).await {
+            Ok(entries) => entries,
+            Err(RiotApiError::NotFound) => Vec::new(),
+            Err(e) => {
+                eprintln!("Failed to fetch league entries for {}: {}", puuid, e);
+                return None;
+            }
+        },
+    };
+
+    entries.into_iter().find(|entry| entry.queue_type == queue_type && entry.standing().is_some())
+}
+
+fn format_game(locale: Locale, riot_id: &str, game: &CurrentGameInfo, lines: &[String], now_millis: i64) -> String {
+    let elapsed = game.elapsed_secs(now_millis);
+    let queue = match game.game_queue_config_id {
+        Some(queue_id) => queue_name(queue_id).to_string(),
+        None => t!(locale, "live-custom-game"),
+    };
+
+    let mut response = t!(
+        locale,
+        "live-header",
+        riot_id = riot_id,
+        queue = queue,
+        time = format!("{}:{:02}", elapsed / 60, elapsed % 60),
+    );
+
+    for (team_id, title) in [(CurrentGameInfo::BLUE_TEAM, "live-blue-team"), (CurrentGameInfo::RED_TEAM, "live-red-team")] {
+        response.push_str(&format!("\n\n{}", t!(locale, title)));
+        for (participant, line) in game.participants.iter().zip(lines) {
+            if participant.team_id == team_id {
+                response.push_str(&format!("\n{}", line));
+            }
+        }
+    }
+
+    response
+}
+
+fn format_participant(
+    locale: Locale,
+    static_data: &StaticData,
+    participant: &CurrentGameParticipant,
+    rank: Option<&LeagueEntry>,
+    highlight: bool,
+) -> String {
+    let name = match (&participant.riot_id, participant.bot) {
+        (_, true) => t!(locale, "live-bot"),
+        (Some(riot_id), _) => riot_id.clone(),
+        (None, _) => t!(locale, "live-unknown-player"),
+    };
+
+    let rank = match rank.and_then(|entry| entry.ranked_tier().map(|tier| (tier, entry))) {
+        Some((tier, entry)) if tier.is_apex() => format!("{} {} LP", tier.title(), entry.league_points),
+        Some((tier, entry)) => format!("{} {} {} LP", tier.title(), entry.rank, entry.league_points),
+        None => t!(locale, "unranked"),
+    };
+
+    let name = if highlight { format!("**{}**", name) } else { name };
+    let mut line = format!(
+        "`{}` {} · {}/{}",
+        static_data.champions.name(participant.champion_id),
+        name,
+        static_data.spells.name(participant.spell1_id),
+        static_data.spells.name(participant.spell2_id),
+    );
+    if let Some(keystone) = participant.keystone() {
+        line.push_str(&format!(" · {}", static_data.runes.name(keystone)));
+    }
+    format!("{} — {}", line, rank)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::riot::static_data;
+
+    const ACTIVE_GAME: &str = include_str!("../../tests/fixtures/riot/active_game.json");
+
+    #[test]
+    fn test_format_game_groups_teams() {
+        let game: CurrentGameInfo = serde_json::from_str(ACTIVE_GAME).unwrap();
+        let static_data = static_data::fixture();
+        let lines: Vec<String> = game
+            .participants
+            .iter()
+            .enumerate()
+            .map(|(i, participant)| format_participant(Locale::En, &static_data, participant, None, i == 0))
+            .collect();
+
+        let response = format_game(Locale::En, "Hide on bush#KR1", &game, &lines, game.game_start_time + 754_000);
+
+        assert!(response.starts_with("🔴 **Hide on bush#KR1** is in game — Ranked Solo/Duo · 12:34"));
+        assert!(response.contains("`LeBlanc` **Hide on bush#KR1** · Flash/Ignite · Electrocute — Unranked"));
+
+        let (blue, red) = response.split_once("Red team").unwrap();
+        assert_eq!(blue.matches('`').count(), 10);
+        assert_eq!(red.matches('`').count(), 10);
+    }
+}
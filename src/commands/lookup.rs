@@ -0,0 +1,102 @@
+use dashmap::DashMap;
+use serenity::model::id::{GuildId, UserId};
+use super::Invocation;
+use crate::config::ConfigMap;
+use crate::database::{ServerConfig, UserLink};
+use crate::i18n::{Locale, t};
+use crate::riot::{Platform, RiotApiError};
+use crate::user_links::UserLinkRepository;
+
+// A member's main link, checked for use by the ranked commands.
+pub(super) struct LinkedAccount {
+    pub link: UserLink,
+    pub puuid: String,
+    pub platform: Platform,
+}
+
+// Loads `user_id`'s main link and checks it can be used in this server. On
+// failure the reason has been sent and `None` is returned.
+pub(super) async fn linked_account(inv: &mut Invocation<'_>, user_id: UserId) -> Option<LinkedAccount> {
+    let is_self = user_id == inv.author.id;
+    let (user_links, config_map) = {
+        let data = inv.ctx.data.read().await;
+        (
+            data.get::<UserLinkRepository>().expect("UserLinkRepository not found").clone(),
+            data.get::<ConfigMap>().expect("ConfigMap not found").clone(),
+        )
+    };
+
+    let link = match user_links.get_primary(user_id).await {
+        Ok(Some(link)) => link,
+        Ok(None) => {
+            let response = if is_self {
+                t!(inv.locale, "no-linked-account-hint", prefix = inv.prefix)
+            } else {
+                t!(inv.locale, "member-no-linked-account")
+            };
+            inv.reply(response).await;
+            return None;
+        }
+        Err(e) => {
+            eprintln!("Failed to get user link: {}", e);
+            inv.reply(t!(inv.locale, "link-load-failed")).await;
+            return None;
+        }
+    };
+
+    if !link.verified && verification_required(&config_map, inv.guild_id) {
+        let response = if is_self {
+            t!(inv.locale, "verified-account-required", prefix = inv.prefix, riot_id = link.riot_id())
+        } else {
+            t!(inv.locale, "member-link-unverified")
+        };
+        inv.reply(response).await;
+        return None;
+    }
+
+    match (link.riot_puuid.clone(), Platform::from_game_region(&link.region)) {
+        (Some(puuid), Some(platform)) => Some(LinkedAccount { link, puuid, platform }),
+        _ => {
+            inv.reply(t!(inv.locale, "link-outdated", prefix = inv.prefix)).await;
+            None
+        }
+    }
+}
+
+pub(super) fn verification_required(config_map: &DashMap<GuildId, ServerConfig>, guild_id: Option<GuildId>) -> bool {
+    guild_id
+        .and_then(|guild_id| config_map.get(&guild_id).map(|config| config.require_verified))
+        .unwrap_or(false)
+}
+
+pub(super) fn riot_error_message(locale: Locale, e: &RiotApiError) -> String {
+    match e {
+        RiotApiError::NotFound => t!(locale, "riot-not-found"),
+        RiotApiError::RateLimited => t!(locale, "rate-limited"),
+        RiotApiError::Unauthorized => t!(locale, "api-unauthorized"),
+        _ => t!(locale, "riot-request-failed", error = e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verification_required_follows_server_config() {
+        let config_map = DashMap::new();
+        let guild_id = GuildId::new(1);
+        config_map.insert(guild_id, ServerConfig { require_verified: true, ..ServerConfig::default() });
+
+        assert!(verification_required(&config_map, Some(guild_id)));
+        assert!(!verification_required(&config_map, Some(GuildId::new(2))));
+        assert!(!verification_required(&config_map, None));
+    }
+
+    #[test]
+    fn test_riot_error_message_is_shared_per_error() {
+        assert_eq!(riot_error_message(Locale::En, &RiotApiError::NotFound), t!(Locale::En, "riot-not-found"));
+        assert_eq!(riot_error_message(Locale::En, &RiotApiError::RateLimited), t!(Locale::En, "rate-limited"));
+        assert_eq!(riot_error_message(Locale::En, &RiotApiError::Unauthorized), t!(Locale::En, "api-unauthorized"));
+    }
+}
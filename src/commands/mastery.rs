@@ -0,0 +1,114 @@
+use super::Invocation;
+use crate::config::{RiotClientContainer, StaticDataContainer};
+use crate::i18n::{self, Locale, t};
+use crate::riot::static_data::{Champion, NameIndex};
+use crate::riot::ChampionMastery;
+use crate::utils::parse_user_mention;
+use serenity::model::id::UserId;
+use super::lookup::{LinkedAccount, linked_account, riot_error_message};
+
+const TOP_COUNT: u32 = 5;
+
+pub async fn mastery(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let (user_id, champion_query) = split_target(&args);
+    let user_id = user_id.unwrap_or(inv.author.id);
+
+    let static_data = {
+        let data = inv.ctx.data.read().await;
+        data.get::<StaticDataContainer>().expect("StaticDataContainer not found").current()
+    };
+    let champions = &static_data.champions;
+
+    let champion = if champion_query.is_empty() {
+        None
+    } else {
+        match champions.find(&champion_query) {
+            Some(champion) => Some(champion.clone()),
+            None => {
+                inv.reply(t!(inv.locale, "mastery-unknown-champion", champion = champion_query)).await;
+                return;
+            }
+        }
+    };
+
+    let Some(LinkedAccount { link, puuid, platform }) = linked_account(inv, user_id).await else {
+        return;
+    };
+
+    let data = inv.ctx.data.read().await;
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+
+    let response = match champion {
+        Some(champion) => match riot_client.get_champion_mastery(&puuid, platform, champion.id).await {
+            Ok(Some(mastery)) => format!(
+                "{}\n{}",
+                t!(inv.locale, "mastery-champion-title", champion = champion.name, riot_id = link.riot_id()),
+                format_mastery(inv.locale, champions, &mastery)
+            ),
+            Ok(None) => t!(inv.locale, "mastery-never-played", riot_id = link.riot_id(), champion = champion.name),
+            Err(e) => riot_error_message(inv.locale, &e),
+        },
+        None => match riot_client.get_top_champion_masteries(&puuid, platform, TOP_COUNT).await {
+            Ok(masteries) if masteries.is_empty() => t!(inv.locale, "mastery-none", riot_id = link.riot_id()),
+            Ok(masteries) => format_top(inv.locale, champions, &link.riot_id(), &masteries),
+            Err(e) => riot_error_message(inv.locale, &e),
+        },
+    };
+
+    inv.reply(response).await;
+}
+
+fn format_top(locale: Locale, champions: &NameIndex<Champion>, riot_id: &str, masteries: &[ChampionMastery]) -> String {
+    let mut response = t!(locale, "mastery-top-title", riot_id = riot_id);
+    for (i, mastery) in masteries.iter().enumerate() {
+        response.push_str(&format!("\n`{}.` {}", i + 1, format_mastery(locale, champions, mastery)));
+    }
+    response
+}
+
+// `<t:..:R>` lets Discord render "3 days ago" in the reader's locale.
+fn format_mastery(locale: Locale, champions: &NameIndex<Champion>, mastery: &ChampionMastery) -> String {
+    t!(
+        locale,
+        "mastery-line",
+        champion = champions.name(mastery.champion_id),
+        level = mastery.champion_level,
+        points = i18n::format_number(locale, mastery.champion_points),
+        last_played = mastery.last_play_time / 1000,
+    )
+}
+
+// An optional leading mention, then the champion. Unquoted names like
+// `lee sin` arrive as several arguments.
+pub(super) fn split_target(args: &[String]) -> (Option<UserId>, String) {
+    match args.split_first() {
+        Some((first, rest)) => match parse_user_mention(first) {
+            Some(user_id) => (Some(user_id), rest.join(" ")),
+            None => (None, args.join(" ")),
+        },
+        None => (None, String::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::riot::static_data;
+
+    const MASTERIES: &str = include_str!("../../tests/fixtures/riot/champion_masteries.json");
+
+    #[test]
+    fn test_format_top_resolves_names() {
+        let static_data = static_data::fixture();
+        let masteries: Vec<ChampionMastery> = serde_json::from_str(MASTERIES).unwrap();
+
+        let response = format_top(Locale::En, &static_data.champions, "Hide on bush#KR1", &masteries);
+        assert!(response.contains("`1.` **LeBlanc** — Level 62 · 1,043,952 pts · last played <t:1760390000:R>"));
+        // Not in the fixture's champion list.
+        assert!(response.contains("`2.` **Champion 112**"));
+
+        let response = format_top(Locale::Es, &static_data.champions, "Hide on bush#KR1", &masteries);
+        assert!(response.starts_with("**Mejores campeones de Hide on bush#KR1**"));
+        assert!(response.contains("**LeBlanc** — Nivel 62 · 1.043.952 pts"));
+    }
+}
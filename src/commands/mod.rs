@@ -1,18 +1,132 @@
 mod general;
 mod config;
 mod user;
+mod rank;
+mod history;
+mod live;
+mod mastery;
+mod verify;
+mod leaderboard;
+mod progress;
+mod graph;
+mod lookup;
+mod invocation;
+mod registry;
+mod permissions;
+pub mod slash;
 
 use serenity::prelude::*;
 use serenity::model::channel::Message;
+use std::time::Instant;
+use crate::config::{BotOwners, ConfigMap};
+use crate::cooldown::{CooldownScope, Cooldowns};
+use crate::i18n::{Locale, t};
+use crate::utils::closest_match;
 
-pub async fn handle_command(ctx: &Context, msg: &Message, command: &str, args: Vec<String>) {
-    match command {
-        "ping" => general::ping(ctx, msg).await,
-        "help" => general::help(ctx, msg).await,
-        "prefix" => config::prefix(ctx, msg, args).await,
-        "link" => user::link(ctx, msg, args).await,
-        "unlink" => user::unlink(ctx, msg).await,
-        "me" => user::me(ctx, msg).await,
-        _ => {}
+pub use invocation::Invocation;
+
+const SUGGESTION_DISTANCE: usize = 2;
+
+pub async fn handle_command(ctx: &Context, msg: &Message, prefix: &str, command: &str, args: Vec<String>) {
+    let mut inv = Invocation::from_message(ctx, msg, prefix);
+    dispatch(&mut inv, command, args).await;
+}
+
+async fn dispatch(inv: &mut Invocation<'_>, command: &str, args: Vec<String>) {
+    inv.resolve_locale().await;
+
+    let spec = match registry::find(command) {
+        Some(spec) => spec,
+        None => {
+            // Stay quiet for unrelated messages that just share our prefix.
+            if let Some(suggestion) = closest_match(command, registry::names(), SUGGESTION_DISTANCE) {
+                let suggestion = format!("{}{}", inv.prefix, suggestion);
+                inv.reply(t!(inv.locale, "unknown-command-suggestion", command = command, suggestion = suggestion)).await;
+            }
+            return;
+        }
+    };
+
+    if let Err(usage) = check_arity(inv.locale, &inv.prefix, spec, &args) {
+        inv.reply(usage).await;
+        return;
+    }
+
+    if let Err(denial) = permissions::check(inv, spec).await {
+        inv.reply(denial).await;
+        return;
+    }
+
+    if let Err(message) = check_cooldown(inv, spec).await {
+        inv.reply(message).await;
+        return;
+    }
+
+    (spec.handler)(inv, args).await;
+}
+
+// The usage message when `args` can't be the command's arguments.
+fn check_arity(locale: Locale, prefix: &str, spec: &registry::CommandSpec, args: &[String]) -> Result<(), String> {
+    if spec.accepts(args.len()) {
+        return Ok(());
+    }
+
+    let mut response = t!(locale, "help-usage", usage = spec.usage(prefix));
+    if let Some(example) = spec.examples.first() {
+        let example = format!("{}{}", prefix, example);
+        response.push_str(&format!("\n{}", t!(locale, "usage-example", example = example)));
+    }
+    Err(response)
+}
+
+async fn check_cooldown(inv: &Invocation<'_>, spec: &registry::CommandSpec) -> Result<(), String> {
+    let data = inv.ctx.data.read().await;
+    let owners = data.get::<BotOwners>().expect("BotOwners not found");
+    if owners.contains(&inv.author.id) {
+        return Ok(());
+    }
+
+    let cooldown = match inv.guild_id {
+        Some(guild_id) => data
+            .get::<ConfigMap>()
+            .expect("ConfigMap not found")
+            .get(&guild_id)
+            .map(|config| spec.cooldown.with_overrides(spec.name, &config.cooldown_overrides))
+            .unwrap_or(spec.cooldown),
+        None => spec.cooldown,
+    };
+
+    let tracker = data.get::<Cooldowns>().expect("Cooldowns not found");
+    tracker
+        .try_use(spec.name, inv.author.id, inv.guild_id, &cooldown, Instant::now())
+        .map_err(|hit| {
+            let seconds = hit.remaining.as_secs().max(1);
+            match hit.scope {
+                CooldownScope::User => t!(inv.locale, "cooldown-user-wait", seconds = seconds, command = spec.name),
+                CooldownScope::Guild => t!(inv.locale, "cooldown-guild-wait", seconds = seconds, command = spec.name),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::parse_args;
+    use serenity::model::id::UserId;
+
+    #[test]
+    fn test_mastery_takes_a_mention_and_an_unquoted_champion() {
+        let args = parse_args("<@42> lee sin");
+        let spec = registry::find("mastery").unwrap();
+
+        assert!(check_arity(Locale::En, "!", spec, &args).is_ok());
+        assert_eq!(mastery::split_target(&args), (Some(UserId::new(42)), "lee sin".to_string()));
+    }
+
+    #[test]
+    fn test_too_many_arguments_get_the_usage() {
+        let args = parse_args("Faker#KR1 kr extra");
+        let usage = check_arity(Locale::En, "!", registry::find("link").unwrap(), &args).unwrap_err();
+        assert!(usage.starts_with("Usage: `!link"));
     }
 }
@@ -0,0 +1,57 @@
+use serenity::model::id::RoleId;
+use serenity::model::permissions::Permissions;
+use crate::config::{BotOwners, ConfigMap};
+use crate::i18n::{Locale, t};
+use super::Invocation;
+use super::registry::CommandSpec;
+
+pub async fn check(inv: &Invocation<'_>, spec: &CommandSpec) -> Result<(), String> {
+    if spec.permissions.is_empty() {
+        return Ok(());
+    }
+
+    let data = inv.ctx.data.read().await;
+    let owners = data.get::<BotOwners>().expect("BotOwners not found");
+    if owners.contains(&inv.author.id) {
+        return Ok(());
+    }
+
+    let guild_id = match inv.guild_id {
+        Some(id) => id,
+        None => return Err(t!(inv.locale, "server-only")),
+    };
+
+    let admin_role_id = data
+        .get::<ConfigMap>()
+        .expect("ConfigMap not found")
+        .get(&guild_id)
+        .and_then(|config| config.admin_role_id);
+
+    let member = match inv.member().await {
+        Some(member) => member,
+        None => return Err(denial(inv.locale, spec, admin_role_id)),
+    };
+
+    if let Some(role_id) = admin_role_id
+        && member.roles.contains(&role_id)
+    {
+        return Ok(());
+    }
+
+    match inv.permissions(&member).await {
+        Some(permissions) if permissions.contains(spec.permissions) => Ok(()),
+        _ => Err(denial(inv.locale, spec, admin_role_id)),
+    }
+}
+
+fn denial(locale: Locale, spec: &CommandSpec, admin_role_id: Option<RoleId>) -> String {
+    let names = permission_names(spec.permissions);
+    match admin_role_id {
+        Some(role_id) => t!(locale, "permission-denied-role", permissions = names, role = role_id, command = spec.name),
+        None => t!(locale, "permission-denied", permissions = names, command = spec.name),
+    }
+}
+
+fn permission_names(permissions: Permissions) -> String {
+    permissions.get_permission_names().join(", ")
+}
@@ -0,0 +1,77 @@
+use super::Invocation;
+use chrono::Utc;
+use crate::config::{DatabaseContainer, RiotClientContainer};
+use crate::i18n::t;
+use crate::leaderboard::RankedQueue;
+use crate::rank_history::{self, Window, format_rank};
+use super::lookup::{LinkedAccount, linked_account};
+
+pub async fn progress(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let mut window = Window::Week;
+    let mut queue = RankedQueue::Solo;
+
+    for arg in &args {
+        if let Some(parsed) = Window::parse(arg) {
+            window = parsed;
+        } else if let Some(parsed) = RankedQueue::parse(arg) {
+            queue = parsed;
+        } else {
+            inv.reply(t!(inv.locale, "progress-invalid-argument", value = arg, prefix = inv.prefix)).await;
+            return;
+        }
+    }
+
+    let Some(LinkedAccount { link, puuid, platform }) = linked_account(inv, inv.author.id).await else {
+        return;
+    };
+
+    let data = inv.ctx.data.read().await;
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+
+    // A fresh snapshot makes the report end at the current rank rather than
+    // at the last background run.
+    if let Err(e) = rank_history::capture(db, riot_client, &puuid, platform).await {
+        eprintln!("Failed to capture rank snapshot: {}", e);
+    }
+
+    let since = window.since(Utc::now());
+    let snapshots = match db.get_snapshots_since(&puuid, queue.queue_type(), since).await {
+        Ok(snapshots) => snapshots,
+        Err(e) => {
+            eprintln!("Failed to load rank snapshots: {}", e);
+            inv.reply(t!(inv.locale, "progress-history-failed")).await;
+            return;
+        }
+    };
+
+    let progress = match rank_history::summarize(&snapshots) {
+        Some(progress) if rank_history::has_history(&snapshots, since) => progress,
+        _ => {
+            inv.reply(t!(inv.locale, "progress-no-history", queue = t!(inv.locale, queue.key()), riot_id = link.riot_id())).await;
+            return;
+        }
+    };
+
+    let games = progress.wins + progress.losses;
+    let trend = if progress.lp_change > 0 { "📈" } else if progress.lp_change < 0 { "📉" } else { "➖" };
+
+    let response = t!(
+        inv.locale,
+        "progress-summary",
+        trend = trend,
+        riot_id = link.riot_id(),
+        queue = t!(inv.locale, queue.key()),
+        window = t!(inv.locale, window.key()),
+        start = format_rank(&progress.start),
+        end = format_rank(&progress.end),
+        lp_change = format!("{:+}", progress.lp_change),
+        promotions = progress.promotions,
+        demotions = progress.demotions,
+        games = games,
+        wins = progress.wins,
+        losses = progress.losses,
+        win_rate = format!("{:.1}", progress.win_rate()),
+    );
+    inv.reply(response).await;
+}
@@ -0,0 +1,95 @@
+use super::Invocation;
+use crate::config::{RiotClientContainer, StaticDataContainer};
+use crate::i18n::t;
+use crate::profile_cache::{ProfileCache, get_ranked_profile};
+use crate::presentation;
+use crate::riot::{Platform, RiotApiError};
+use crate::utils::{parse_riot_id, parse_user_mention};
+use super::lookup::{LinkedAccount, linked_account, riot_error_message};
+
+struct RankTarget {
+    display_name: String,
+    puuid: String,
+    platform: Platform,
+}
+
+pub async fn rank(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let target = if args.len() >= 2 {
+        let (name, tag) = match parse_riot_id(&args[0]) {
+            Some(riot_id) => riot_id,
+            None => {
+                inv.reply(t!(inv.locale, "riot-id-invalid")).await;
+                return;
+            }
+        };
+
+        let platform = match Platform::from_game_region(&args[1]) {
+            Some(platform) => platform,
+            None => {
+                inv.reply(t!(inv.locale, "region-invalid", region = args[1], regions = Platform::game_regions().join(", "))).await;
+                return;
+            }
+        };
+
+        let riot_client = {
+            let data = inv.ctx.data.read().await;
+            data.get::<RiotClientContainer>().expect("RiotClient not found").clone()
+        };
+        match riot_client.get_account_by_riot_id(name, tag, platform).await {
+            Ok(account) => RankTarget {
+                display_name: format!("{}#{}", account.game_name, account.tag_line),
+                puuid: account.puuid,
+                platform,
+            },
+            Err(RiotApiError::NotFound) => {
+                inv.reply_card(presentation::error(t!(
+                    inv.locale,
+                    "link-not-found",
+                    riot_id = format!("{}#{}", name, tag),
+                    region = platform.game_region().to_uppercase(),
+                ))).await;
+                return;
+            }
+            Err(e) => {
+                inv.reply_card(presentation::error(riot_error_message(inv.locale, &e))).await;
+                return;
+            }
+        }
+    } else {
+        let user_id = match args.first().map(|arg| parse_user_mention(arg)) {
+            None => inv.author.id,
+            Some(Some(user_id)) => user_id,
+            Some(None) => {
+                inv.reply(t!(inv.locale, "rank-usage", prefix = inv.prefix)).await;
+                return;
+            }
+        };
+
+        let Some(LinkedAccount { link, puuid, platform }) = linked_account(inv, user_id).await else {
+            return;
+        };
+
+        RankTarget {
+            display_name: format!("{}#{}", link.summoner_name, link.summoner_tag),
+            puuid,
+            platform,
+        }
+    };
+
+    let data = inv.ctx.data.read().await;
+    let profile_cache = data.get::<ProfileCache>().expect("ProfileCache not found");
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+
+    let (profile, age) = match get_ranked_profile(profile_cache, riot_client, &target.puuid, target.platform).await {
+        Ok(result) => result,
+        Err(e) => {
+            inv.reply_card(presentation::error(riot_error_message(inv.locale, &e))).await;
+            return;
+        }
+    };
+
+    let static_data = data.get::<StaticDataContainer>().expect("StaticDataContainer not found").current();
+    let icon_url = presentation::profile_icon_url(static_data.version(), profile.summoner.profile_icon_id);
+    let card = presentation::profile(inv.locale, &target.display_name, target.platform, &profile, icon_url, age);
+    inv.reply_card(card).await;
+}
@@ -0,0 +1,505 @@
+use serenity::model::permissions::Permissions;
+use std::future::Future;
+use crate::cooldown::{Cooldown, CooldownLimit};
+use crate::riot::Tier;
+use std::pin::Pin;
+use super::{Invocation, config, general, graph, history, leaderboard, live, mastery, progress, rank, user, verify};
+
+pub type CommandFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+pub type Handler = for<'a, 'b> fn(&'a mut Invocation<'b>, Vec<String>) -> CommandFuture<'a>;
+
+macro_rules! handler {
+    ($command:path) => {{
+        fn handler<'a, 'b>(inv: &'a mut Invocation<'b>, args: Vec<String>) -> CommandFuture<'a> {
+            Box::pin($command(inv, args))
+        }
+        handler as Handler
+    }};
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Category {
+    General,
+    Config,
+    Account,
+    Stats,
+}
+
+impl Category {
+    pub const ALL: [Category; 4] = [Category::General, Category::Config, Category::Account, Category::Stats];
+
+    // Message key of the category heading in `help`.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Category::General => "category-general",
+            Category::Config => "category-config",
+            Category::Account => "category-account",
+            Category::Stats => "category-stats",
+        }
+    }
+}
+
+pub enum ArgKind {
+    Text,
+    Command,
+    RiotId,
+    Region,
+    Queue,
+    User,
+    Role,
+    Channel,
+    Choice(&'static [&'static str]),
+    Integer { min: u64, max: u64 },
+}
+
+pub struct ArgSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub kind: ArgKind,
+    pub required: bool,
+    // Takes every remaining word, for names like `lee sin` typed unquoted.
+    // Only the last argument can be a rest argument.
+    pub rest: bool,
+}
+
+// Arguments are listed in prefix-command order; the slash path relies on
+// this to rebuild the same positional `args`.
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub description: &'static str,
+    pub usage: &'static str,
+    pub examples: &'static [&'static str],
+    pub category: Category,
+    pub args: &'static [ArgSpec],
+    // The option sets the prefix form can express, when not every
+    // combination of `args` reads back the same; empty means any. Slash
+    // commands with other combinations are refused.
+    pub combinations: &'static [&'static [&'static str]],
+    pub permissions: Permissions,
+    pub cooldown: Cooldown,
+    pub handler: Handler,
+}
+
+impl CommandSpec {
+    pub fn usage(&self, prefix: &str) -> String {
+        if self.usage.is_empty() {
+            format!("{}{}", prefix, self.name)
+        } else {
+            format!("{}{} {}", prefix, self.name, self.usage)
+        }
+    }
+
+    pub fn accepts(&self, arg_count: usize) -> bool {
+        let required = self.args.iter().filter(|arg| arg.required).count();
+        let rest = self.args.last().is_some_and(|arg| arg.rest);
+        arg_count >= required && (rest || arg_count <= self.args.len())
+    }
+}
+
+pub fn find(name: &str) -> Option<&'static CommandSpec> {
+    let name = name.to_lowercase();
+    COMMANDS
+        .iter()
+        .find(|spec| spec.name == name || spec.aliases.contains(&name.as_str()))
+}
+
+pub fn names() -> impl Iterator<Item = &'static str> {
+    COMMANDS
+        .iter()
+        .flat_map(|spec| std::iter::once(spec.name).chain(spec.aliases.iter().copied()))
+}
+
+pub static COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "ping",
+        aliases: &[],
+        description: "Responds with Pong!",
+        usage: "",
+        examples: &[],
+        category: Category::General,
+        args: &[],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown::NONE,
+        handler: handler!(general::ping),
+    },
+    CommandSpec {
+        name: "help",
+        aliases: &["commands"],
+        description: "Shows the list of commands, or details about one command",
+        usage: "[command]",
+        examples: &["help link"],
+        category: Category::General,
+        args: &[
+            ArgSpec { name: "command", description: "A command to explain", kind: ArgKind::Command, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown::NONE,
+        handler: handler!(general::help),
+    },
+    CommandSpec {
+        name: "prefix",
+        aliases: &[],
+        description: "View or set the command prefix for this server",
+        usage: "[new_prefix]",
+        examples: &["prefix ?"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "new_prefix", description: "The new prefix", kind: ArgKind::Text, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::prefix),
+    },
+    CommandSpec {
+        name: "adminrole",
+        aliases: &[],
+        description: "View or set the role allowed to change bot settings",
+        usage: "[@role | off]",
+        examples: &["adminrole @Moderators", "adminrole off"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "role", description: "The bot admin role, or off", kind: ArgKind::Role, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::admin_role),
+    },
+    CommandSpec {
+        name: "cooldown",
+        aliases: &["cooldowns"],
+        description: "View or override command cooldowns for this server",
+        usage: "[command] [user | guild | reset] [seconds] [burst]",
+        examples: &["cooldown", "cooldown rank user 60 2", "cooldown link guild 0", "cooldown rank reset"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "command", description: "The command to configure", kind: ArgKind::Command, required: false, rest: false },
+            ArgSpec { name: "scope", description: "Which limit to change", kind: ArgKind::Choice(&["user", "guild", "reset"]), required: false, rest: false },
+            ArgSpec { name: "seconds", description: "Window length, 0 disables the limit", kind: ArgKind::Integer { min: 0, max: 3600 }, required: false, rest: false },
+            ArgSpec { name: "burst", description: "Uses allowed per window (default 1)", kind: ArgKind::Integer { min: 1, max: 100 }, required: false, rest: false },
+        ],
+        combinations: &[
+            &[],
+            &["command"],
+            &["command", "scope"],
+            &["command", "scope", "seconds"],
+            &["command", "scope", "seconds", "burst"],
+        ],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::cooldown),
+    },
+    CommandSpec {
+        name: "verification",
+        aliases: &[],
+        description: "View or set whether ranked commands need verified accounts",
+        usage: "[required | optional]",
+        examples: &["verification required", "verification optional"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "mode", description: "Whether linked accounts must be verified", kind: ArgKind::Choice(&["required", "optional"]), required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::verification),
+    },
+    CommandSpec {
+        name: "feed",
+        aliases: &[],
+        description: "Post linked members' finished games in a channel",
+        usage: "[set #channel | off]",
+        examples: &["feed set #lol-games", "feed off"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "action", description: "Turn the feed on or off", kind: ArgKind::Choice(&["set", "off"]), required: false, rest: false },
+            ArgSpec { name: "channel", description: "Where to post games", kind: ArgKind::Channel, required: false, rest: false },
+        ],
+        combinations: &[&[], &["action"], &["action", "channel"]],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::feed),
+    },
+    CommandSpec {
+        name: "rankroles",
+        aliases: &["rankrole"],
+        description: "Map Solo/Duo tiers to roles that the bot keeps in sync",
+        usage: "[set <tier> <@role> | remove <tier> | sync | dryrun]",
+        examples: &["rankroles", "rankroles set gold @Gold", "rankroles remove gold", "rankroles dryrun"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "action", description: "What to do", kind: ArgKind::Choice(&["set", "remove", "sync", "dryrun"]), required: false, rest: false },
+            ArgSpec { name: "tier", description: "The ranked tier", kind: ArgKind::Choice(&Tier::NAMES), required: false, rest: false },
+            ArgSpec { name: "role", description: "The role for that tier", kind: ArgKind::Role, required: false, rest: false },
+        ],
+        combinations: &[&[], &["action"], &["action", "tier"], &["action", "tier", "role"]],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::rank_roles),
+    },
+    CommandSpec {
+        name: "language",
+        aliases: &["lang"],
+        description: "View or set the language the bot replies in on this server",
+        usage: "[en | es | pt | ko | reset]",
+        examples: &["language es", "language reset"],
+        category: Category::Config,
+        args: &[
+            ArgSpec { name: "language", description: "The server language, or reset", kind: ArgKind::Choice(&["en", "es", "pt", "ko", "reset"]), required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::MANAGE_GUILD,
+        cooldown: Cooldown::NONE,
+        handler: handler!(config::language),
+    },
+    CommandSpec {
+        name: "link",
+        aliases: &[],
+        description: "Link a LoL account to your Discord account",
+        usage: "<Name#TAG> <region>",
+        examples: &["link Faker#KR1 kr", "link \"Hide on bush#KR1\" kr"],
+        category: Category::Account,
+        args: &[
+            ArgSpec { name: "riot_id", description: "Your Riot ID, e.g. Faker#KR1", kind: ArgKind::RiotId, required: true, rest: false },
+            ArgSpec { name: "region", description: "Your server", kind: ArgKind::Region, required: true, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(60, 2)), guild: Some(CooldownLimit::new(60, 10)) },
+        handler: handler!(user::link),
+    },
+    CommandSpec {
+        name: "unlink",
+        aliases: &[],
+        description: "Remove one of your linked LoL accounts",
+        usage: "[Name#TAG]",
+        examples: &["unlink", "unlink Faker#KR1"],
+        category: Category::Account,
+        args: &[
+            ArgSpec { name: "riot_id", description: "The account to remove", kind: ArgKind::RiotId, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown::NONE,
+        handler: handler!(user::unlink),
+    },
+    CommandSpec {
+        name: "main",
+        aliases: &["primary"],
+        description: "Choose which linked account other commands use",
+        usage: "<Name#TAG>",
+        examples: &["main Faker#KR1"],
+        category: Category::Account,
+        args: &[
+            ArgSpec { name: "riot_id", description: "One of your linked accounts", kind: ArgKind::RiotId, required: true, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown::NONE,
+        handler: handler!(user::main),
+    },
+    CommandSpec {
+        name: "verify",
+        aliases: &[],
+        description: "Prove you own a linked account by changing its profile icon",
+        usage: "[Name#TAG]",
+        examples: &["verify", "verify Faker#KR1"],
+        category: Category::Account,
+        args: &[
+            ArgSpec { name: "riot_id", description: "The account to verify (default: your main)", kind: ArgKind::RiotId, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 3)), guild: None },
+        handler: handler!(verify::verify),
+    },
+    CommandSpec {
+        name: "me",
+        aliases: &["whoami"],
+        description: "Show your linked LoL accounts",
+        usage: "",
+        examples: &[],
+        category: Category::Account,
+        args: &[],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown::NONE,
+        handler: handler!(user::me),
+    },
+    CommandSpec {
+        name: "mylanguage",
+        aliases: &["mylang"],
+        description: "View or set the language the bot replies to you in",
+        usage: "[en | es | pt | ko | reset]",
+        examples: &["mylanguage ko", "mylanguage reset"],
+        category: Category::Account,
+        args: &[
+            ArgSpec { name: "language", description: "Your language, or reset", kind: ArgKind::Choice(&["en", "es", "pt", "ko", "reset"]), required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown::NONE,
+        handler: handler!(user::my_language),
+    },
+    CommandSpec {
+        name: "rank",
+        aliases: &["elo"],
+        description: "Show Solo/Duo and Flex ranks",
+        usage: "[@user | Name#TAG region]",
+        examples: &["rank", "rank @friend", "rank Faker#KR1 kr"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "user", description: "A member with a linked account", kind: ArgKind::User, required: false, rest: false },
+            ArgSpec { name: "riot_id", description: "A Riot ID, e.g. Faker#KR1", kind: ArgKind::RiotId, required: false, rest: false },
+            ArgSpec { name: "region", description: "Server of the Riot ID", kind: ArgKind::Region, required: false, rest: false },
+        ],
+        combinations: &[&[], &["user"], &["riot_id", "region"]],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 3)), guild: Some(CooldownLimit::new(60, 20)) },
+        handler: handler!(rank::rank),
+    },
+    CommandSpec {
+        name: "history",
+        aliases: &["matches"],
+        description: "Show your recent matches",
+        usage: "[count] [queue]",
+        examples: &["history", "history 10 solo"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "count", description: "Number of matches", kind: ArgKind::Integer { min: 1, max: 10 }, required: false, rest: false },
+            ArgSpec { name: "queue", description: "Only show this queue", kind: ArgKind::Queue, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 2)), guild: Some(CooldownLimit::new(60, 10)) },
+        handler: handler!(history::history),
+    },
+    CommandSpec {
+        name: "live",
+        aliases: &["ingame", "spectate"],
+        description: "Show a linked player's current game",
+        usage: "[@user]",
+        examples: &["live", "live @friend"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "user", description: "A member with a linked account", kind: ArgKind::User, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 2)), guild: Some(CooldownLimit::new(60, 10)) },
+        handler: handler!(live::live),
+    },
+    CommandSpec {
+        name: "mastery",
+        aliases: &["champs"],
+        description: "Show champion mastery, or one champion's",
+        usage: "[@user] [champion]",
+        examples: &["mastery", "mastery lee sin", "mastery @friend ahri"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "user", description: "A member with a linked account", kind: ArgKind::User, required: false, rest: false },
+            ArgSpec { name: "champion", description: "Only show this champion", kind: ArgKind::Text, required: false, rest: true },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 3)), guild: Some(CooldownLimit::new(60, 20)) },
+        handler: handler!(mastery::mastery),
+    },
+    CommandSpec {
+        name: "progress",
+        aliases: &["lp"],
+        description: "Show LP gained or lost, promotions and win rate over time",
+        usage: "[day | week | season] [solo | flex]",
+        examples: &["progress", "progress day", "progress season flex"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "window", description: "Time range (default: week)", kind: ArgKind::Choice(&["day", "week", "season"]), required: false, rest: false },
+            ArgSpec { name: "queue", description: "Which ranked queue", kind: ArgKind::Choice(&["solo", "flex"]), required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 3)), guild: Some(CooldownLimit::new(60, 20)) },
+        handler: handler!(progress::progress),
+    },
+    CommandSpec {
+        name: "graph",
+        aliases: &["chart"],
+        description: "Draw a Solo/Duo LP graph from recorded ranks",
+        usage: "[@user] [days]",
+        examples: &["graph", "graph 7", "graph @friend 90"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "user", description: "A member with a linked account", kind: ArgKind::User, required: false, rest: false },
+            ArgSpec { name: "days", description: "How far back to go (default: 30)", kind: ArgKind::Integer { min: 1, max: 365 }, required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 2)), guild: Some(CooldownLimit::new(60, 10)) },
+        handler: handler!(graph::graph),
+    },
+    CommandSpec {
+        name: "leaderboard",
+        aliases: &["lb", "top"],
+        description: "Rank this server's linked members",
+        usage: "[solo | flex]",
+        examples: &["leaderboard", "leaderboard flex"],
+        category: Category::Stats,
+        args: &[
+            ArgSpec { name: "queue", description: "Which ranked queue", kind: ArgKind::Choice(&["solo", "flex"]), required: false, rest: false },
+        ],
+        combinations: &[],
+        permissions: Permissions::empty(),
+        cooldown: Cooldown { user: Some(CooldownLimit::new(30, 2)), guild: Some(CooldownLimit::new(60, 10)) },
+        handler: handler!(leaderboard::leaderboard),
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_names_and_aliases_are_unique() {
+        let mut seen = HashSet::new();
+        for name in names() {
+            assert!(seen.insert(name), "duplicate command name or alias: {}", name);
+        }
+    }
+
+    #[test]
+    fn test_find_by_alias() {
+        assert_eq!(find("elo").map(|spec| spec.name), Some("rank"));
+        assert_eq!(find("RANK").map(|spec| spec.name), Some("rank"));
+        assert!(find("nope").is_none());
+    }
+
+    #[test]
+    fn test_arity() {
+        let link = find("link").unwrap();
+        assert!(!link.accepts(1));
+        assert!(link.accepts(2));
+        assert!(!link.accepts(3));
+        assert!(find("me").unwrap().accepts(0));
+    }
+
+    #[test]
+    fn test_rest_argument_takes_extra_words() {
+        let mastery = find("mastery").unwrap();
+        assert!(mastery.accepts(0));
+        assert!(mastery.accepts(4));
+    }
+
+    #[test]
+    fn test_only_the_last_argument_is_rest() {
+        for spec in COMMANDS {
+            if let Some((_, init)) = spec.args.split_last() {
+                assert!(init.iter().all(|arg| !arg.rest), "{} has a rest argument before the last one", spec.name);
+            }
+        }
+    }
+}
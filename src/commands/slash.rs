@@ -0,0 +1,239 @@
+use serenity::prelude::*;
+use serenity::builder::{CreateAutocompleteResponse, CreateCommand, CreateCommandOption, CreateInteractionResponse};
+use serenity::model::application::{Command, CommandDataOptionValue, CommandInteraction, CommandOptionType, Interaction};
+use serenity::model::channel::ChannelType;
+use serenity::model::id::GuildId;
+use std::env;
+use crate::config::DatabaseContainer;
+use crate::i18n::t;
+use crate::user_links::UserLinkRepository;
+use crate::riot::{Platform, QUEUE_ALIASES};
+use super::{Invocation, leaderboard};
+use super::registry::{self, ArgKind, ArgSpec, CommandSpec, COMMANDS};
+
+const AUTOCOMPLETE_LIMIT: u32 = 25;
+
+// Commands that act on the caller's own links, so only those are suggested.
+const OWN_LINK_COMMANDS: &[&str] = &["link", "unlink", "main", "verify"];
+
+pub async fn register(ctx: &Context) {
+    let commands: Vec<CreateCommand> = COMMANDS.iter().map(build_command).collect();
+
+    // Global commands can take a while to show up, so a development guild
+    // can be targeted instead.
+    let guild_id = env::var("DISCORD_GUILD_ID")
+        .ok()
+        .and_then(|id| id.parse::<u64>().ok())
+        .map(GuildId::new);
+
+    let result = match guild_id {
+        Some(guild_id) => guild_id.set_commands(&ctx.http, commands).await,
+        None => Command::set_global_commands(&ctx.http, commands).await,
+    };
+
+    match result {
+        Ok(commands) => println!("Registered {} slash commands", commands.len()),
+        Err(e) => eprintln!("Failed to register slash commands: {}", e),
+    }
+}
+
+pub async fn handle_interaction(ctx: &Context, interaction: Interaction) {
+    match interaction {
+        Interaction::Command(command) => run(ctx, &command).await,
+        Interaction::Autocomplete(command) => autocomplete(ctx, &command).await,
+        Interaction::Component(component) if component.data.custom_id.starts_with(leaderboard::BUTTON_PREFIX) => {
+            leaderboard::handle_button(ctx, &component).await
+        }
+        _ => {}
+    }
+}
+
+async fn run(ctx: &Context, command: &CommandInteraction) {
+    let spec = match registry::find(&command.data.name) {
+        Some(spec) => spec,
+        None => return,
+    };
+
+    let given: Vec<(&str, String)> = command
+        .data
+        .options
+        .iter()
+        .filter_map(|option| {
+            let value = match &option.value {
+                CommandDataOptionValue::String(value) => value.clone(),
+                CommandDataOptionValue::Integer(value) => value.to_string(),
+                CommandDataOptionValue::User(user_id) => format!("<@{}>", user_id),
+                CommandDataOptionValue::Role(role_id) => format!("<@&{}>", role_id),
+                CommandDataOptionValue::Channel(channel_id) => format!("<#{}>", channel_id),
+                _ => return None,
+            };
+            Some((option.name.as_str(), value))
+        })
+        .collect();
+
+    let mut inv = Invocation::from_interaction(ctx, command);
+    inv.defer().await;
+
+    match positional_args(spec, &given) {
+        Some(args) => super::dispatch(&mut inv, spec.name, args).await,
+        None => {
+            inv.resolve_locale().await;
+            inv.reply(t!(inv.locale, "help-usage", usage = spec.usage(&inv.prefix))).await;
+        }
+    }
+}
+
+// The arguments a prefix command would have been given, in `spec.args`
+// order, or `None` when the prefix form can't express this combination.
+fn positional_args(spec: &CommandSpec, given: &[(&str, String)]) -> Option<Vec<String>> {
+    let args: Vec<(&str, String)> = spec
+        .args
+        .iter()
+        .filter_map(|arg| given.iter().find(|(name, _)| *name == arg.name).cloned())
+        .collect();
+
+    let names: Vec<&str> = args.iter().map(|(name, _)| *name).collect();
+    if !spec.combinations.is_empty() && !spec.combinations.contains(&names.as_slice()) {
+        return None;
+    }
+
+    Some(args.into_iter().map(|(_, value)| value).collect())
+}
+
+async fn autocomplete(ctx: &Context, command: &CommandInteraction) {
+    let focused = match command.data.autocomplete() {
+        Some(focused) if focused.name == "riot_id" => focused,
+        _ => return,
+    };
+
+    let data = ctx.data.read().await;
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+    let user_links = data.get::<UserLinkRepository>().expect("UserLinkRepository not found");
+
+    // Other commands suggest accounts linked by members of this server, as
+    // far as the member cache knows them, so nothing leaks across servers.
+    let members = command.guild_id
+        .filter(|_| !OWN_LINK_COMMANDS.contains(&command.data.name.as_str()))
+        .and_then(|guild_id| ctx.cache.guild(guild_id).map(|guild| guild.members.keys().copied().collect::<Vec<_>>()));
+
+    let result = match members {
+        Some(members) => db.search_riot_ids(focused.value, &members, AUTOCOMPLETE_LIMIT).await,
+        None => user_links.get(command.user.id).await.map(|links| {
+            let prefix = focused.value.to_lowercase();
+            links
+                .iter()
+                .map(|link| link.riot_id())
+                .filter(|riot_id| riot_id.to_lowercase().starts_with(&prefix))
+                .collect()
+        }),
+    };
+
+    let suggestions = match result {
+        Ok(suggestions) => suggestions,
+        Err(e) => {
+            eprintln!("Failed to search Riot IDs: {}", e);
+            Vec::new()
+        }
+    };
+
+    let response = suggestions
+        .into_iter()
+        .fold(CreateAutocompleteResponse::new(), |response, riot_id| {
+            response.add_string_choice(riot_id.clone(), riot_id)
+        });
+
+    if let Err(why) = command.create_response(&ctx.http, CreateInteractionResponse::Autocomplete(response)).await {
+        println!("Error responding to autocomplete: {:?}", why);
+    }
+}
+
+fn build_command(spec: &CommandSpec) -> CreateCommand {
+    let mut command = CreateCommand::new(spec.name).description(spec.description);
+
+    // Only hides the command from members who lack the permission; the
+    // actual check still happens in `dispatch` so admin roles and owners work.
+    if !spec.permissions.is_empty() {
+        command = command.default_member_permissions(spec.permissions);
+    }
+
+    spec.args
+        .iter()
+        .fold(command, |command, arg| command.add_option(build_option(arg)))
+}
+
+fn build_option(arg: &ArgSpec) -> CreateCommandOption {
+    let option = match arg.kind {
+        ArgKind::Text | ArgKind::Command => CreateCommandOption::new(CommandOptionType::String, arg.name, arg.description),
+        ArgKind::RiotId => CreateCommandOption::new(CommandOptionType::String, arg.name, arg.description)
+            .set_autocomplete(true),
+        ArgKind::Region => Platform::game_regions()
+            .into_iter()
+            .fold(CreateCommandOption::new(CommandOptionType::String, arg.name, arg.description), |option, region| {
+                option.add_string_choice(region.to_uppercase(), region)
+            }),
+        ArgKind::Queue => QUEUE_ALIASES
+            .iter()
+            .fold(CreateCommandOption::new(CommandOptionType::String, arg.name, arg.description), |option, queue| {
+                option.add_string_choice(*queue, *queue)
+            }),
+        ArgKind::User => CreateCommandOption::new(CommandOptionType::User, arg.name, arg.description),
+        ArgKind::Role => CreateCommandOption::new(CommandOptionType::Role, arg.name, arg.description),
+        ArgKind::Channel => CreateCommandOption::new(CommandOptionType::Channel, arg.name, arg.description)
+            .channel_types(vec![ChannelType::Text]),
+        ArgKind::Choice(choices) => choices
+            .iter()
+            .fold(CreateCommandOption::new(CommandOptionType::String, arg.name, arg.description), |option, choice| {
+                option.add_string_choice(*choice, *choice)
+            }),
+        ArgKind::Integer { min, max } => CreateCommandOption::new(CommandOptionType::Integer, arg.name, arg.description)
+            .min_int_value(min)
+            .max_int_value(max),
+    };
+
+    option.required(arg.required)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rank_args(given: &[(&'static str, &str)]) -> Option<Vec<String>> {
+        let given: Vec<(&str, String)> = given.iter().map(|(name, value)| (*name, value.to_string())).collect();
+        positional_args(registry::find("rank").unwrap(), &given)
+    }
+
+    #[test]
+    fn test_rank_options_follow_the_prefix_form() {
+        assert_eq!(rank_args(&[]), Some(vec![]));
+        assert_eq!(rank_args(&[("user", "<@1>")]), Some(vec!["<@1>".to_string()]));
+        assert_eq!(
+            rank_args(&[("region", "kr"), ("riot_id", "Faker#KR1")]),
+            Some(vec!["Faker#KR1".to_string(), "kr".to_string()])
+        );
+    }
+
+    #[test]
+    fn test_rank_with_user_and_riot_id_is_refused() {
+        assert_eq!(rank_args(&[("user", "<@1>"), ("riot_id", "Faker#KR1")]), None);
+        assert_eq!(rank_args(&[("riot_id", "Faker#KR1")]), None);
+    }
+
+    #[test]
+    fn test_combinations_name_real_options() {
+        for spec in COMMANDS {
+            for combination in spec.combinations {
+                for name in *combination {
+                    assert!(spec.args.iter().any(|arg| arg.name == *name), "{} has no option {}", spec.name, name);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_own_link_commands_take_a_riot_id() {
+        for name in OWN_LINK_COMMANDS {
+            let spec = registry::find(name).unwrap();
+            assert!(spec.args.iter().any(|arg| matches!(arg.kind, ArgKind::RiotId)), "{} has no Riot ID argument", name);
+        }
+    }
+}
@@ -1,197 +1,259 @@
-use serenity::prelude::*;
-use serenity::model::channel::Message;
-use crate::config::{DatabaseContainer, RiotClientContainer};
-use crate::user_cache::UserLinkCache;
+use super::Invocation;
+use crate::config::{DatabaseContainer, RiotClientContainer, UserLocales};
+use crate::user_links::UserLinkRepository;
 use crate::database::models::UserLink;
-use crate::cache::{CachedData, ttl};
+use crate::i18n::{Locale, t};
+use crate::presentation;
+use crate::riot::Platform;
+use crate::utils::parse_riot_id;
 
-pub async fn link(ctx: &Context, msg: &Message, args: Vec<String>) {
-    if args.len() < 2 {
-        let _ = msg.channel_id.say(&ctx.http, "Usage: `link <Name#TAG> <region>`\nExample: `link Faker#KR1 kr`").await;
-        return;
-    }
+const MAX_LINKS: usize = 5;
 
+pub async fn link(inv: &mut Invocation<'_>, args: Vec<String>) {
     let riot_id = &args[0];
     let region = args[1].to_lowercase();
 
-    let parts: Vec<&str> = riot_id.split('#').collect();
-    if parts.len() != 2 {
-        let _ = msg.channel_id.say(&ctx.http, "Invalid Riot ID format. Use `Name#TAG` (e.g., `Faker#KR1`)").await;
-        return;
-    }
+    let (summoner_name, summoner_tag) = match parse_riot_id(riot_id) {
+        Some((name, tag)) => (name.to_string(), tag.to_string()),
+        None => {
+            inv.reply(t!(inv.locale, "riot-id-invalid")).await;
+            return;
+        }
+    };
+
+    let platform = match Platform::from_game_region(&region) {
+        Some(platform) => platform,
+        None => {
+            inv.reply(t!(inv.locale, "region-invalid", region = region, regions = Platform::game_regions().join(", "))).await;
+            return;
+        }
+    };
+    let region = platform.game_region().to_string();
 
-    let summoner_name = parts[0].to_string();
-    let summoner_tag = parts[1].to_string();
+    let data = inv.ctx.data.read().await;
+    let user_links = data.get::<UserLinkRepository>().expect("UserLinkRepository not found");
 
-    let valid_regions = ["na", "euw", "eune", "kr", "br", "lan", "las", "oce", "ru", "tr", "jp", "ph", "sg", "th", "tw", "vn"];
-    if !valid_regions.contains(&region.as_str()) {
-        let _ = msg.channel_id.say(&ctx.http, 
-            format!("Invalid region: `{}`. Valid regions: {}", region, valid_regions.join(", "))
-        ).await;
+    let existing = match user_links.get(inv.author.id).await {
+        Ok(links) => links,
+        Err(e) => {
+            eprintln!("Failed to get user links: {}", e);
+            inv.reply(t!(inv.locale, "links-load-failed")).await;
+            return;
+        }
+    };
+
+    let relink = existing.iter().any(|link| {
+        link.summoner_name.eq_ignore_ascii_case(&summoner_name) && link.summoner_tag.eq_ignore_ascii_case(&summoner_tag)
+    });
+    if !relink && existing.len() >= MAX_LINKS {
+        inv.reply(t!(inv.locale, "link-limit", max = MAX_LINKS, prefix = inv.prefix)).await;
         return;
     }
 
-    let verify_msg = msg.channel_id.say(&ctx.http, "Verifying summoner with Riot API...").await;
+    inv.progress(t!(inv.locale, "link-verifying")).await;
 
-    let data = ctx.data.read().await;
     let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
 
-    let riot_account = match riot_client.get_account_by_riot_id(&summoner_name, &summoner_tag, &region).await {
+    let riot_account = match riot_client.get_account_by_riot_id(&summoner_name, &summoner_tag, platform).await {
         Ok(account) => account,
         Err(e) => {
             let error_msg = match e {
-                crate::riot::RiotApiError::NotFound => {
-                    format!("❌ Summoner **{}#{}** not found in region **{}**. Please check the name and tag.",
-                        summoner_name, summoner_tag, region.to_uppercase())
-                }
-                crate::riot::RiotApiError::RateLimited => {
-                    "Rate limited by Riot API. Please try again in a moment.".to_string()
-                }
-                crate::riot::RiotApiError::Unauthorized => {
-                    "❌ API authentication error. Please contact the bot administrator.".to_string() 
-                }
-                _ => {
-                    format!("❌ Failed to verify summoner: {}. Please try again later", e)
-                }
+                crate::riot::RiotApiError::NotFound => t!(
+                    inv.locale, "link-not-found",
+                    riot_id = format!("{}#{}", summoner_name, summoner_tag),
+                    region = region.to_uppercase()
+                ),
+                crate::riot::RiotApiError::RateLimited => t!(inv.locale, "rate-limited"),
+                crate::riot::RiotApiError::Unauthorized => t!(inv.locale, "api-unauthorized"),
+                _ => t!(inv.locale, "link-verify-failed", error = e),
             };
 
-            if let Ok(mut verify_msg) = verify_msg {
-                let _ = verify_msg.edit(&ctx.http, serenity::builder::EditMessage::new().content(&error_msg)).await;
-            } else {
-                let _ = msg.channel_id.say(&ctx.http, error_msg).await;
-            }
-            return; 
+            inv.reply(error_msg).await;
+            return;
         }
     };
 
     let user_link = UserLink {
-        discord_user_id: msg.author.id,
+        discord_user_id: inv.author.id,
         summoner_name: riot_account.game_name.clone(),
         summoner_tag: riot_account.tag_line.clone(),
         region: region.clone(),
         riot_puuid: Some(riot_account.puuid.clone()),
+        is_primary: false,
+        verified: false,
     };
 
-    let db = data.get::<DatabaseContainer>().expect("Database not found");
-    let cache = data.get::<UserLinkCache>().expect("UserLinkCache not found");
-
-    if let Err(e) = db.save_user_link(&user_link).await {
+    if let Err(e) = user_links.save(&user_link).await {
         eprintln!("Failed to save user link: {}", e);
-
-        if let Ok(mut verify_msg) = verify_msg {
-            let _ = verify_msg.edit(&ctx.http, serenity::builder::EditMessage::new().content("❌ Failed to save your link. Please try again later.")).await;
-        }
+        inv.reply(t!(inv.locale, "link-save-failed")).await;
         return;
     }
 
-    cache.insert(msg.author.id, CachedData::new(user_link));
-
-    let success_msg = format!(
-        "✅ Linked your account to **{}#{}** in region **{}**",
-        riot_account.game_name, riot_account.tag_line, region.to_uppercase()
-    );
+    let is_primary = match user_links.get(inv.author.id).await {
+        Ok(links) => links.iter().any(|link| link.is_primary && link.riot_id() == user_link.riot_id()),
+        Err(_) => false,
+    };
 
-    if let Ok(mut verify_msg) = verify_msg {
-        let _ = verify_msg.edit(&ctx.http, serenity::builder::EditMessage::new().content(&success_msg)).await;
+    let success_msg = if is_primary {
+        t!(inv.locale, "link-primary", riot_id = user_link.riot_id(), region = region.to_uppercase())
     } else {
-        let _ = msg.channel_id.say(&ctx.http, success_msg).await;
-    }
+        t!(
+            inv.locale, "link-added",
+            riot_id = user_link.riot_id(), region = region.to_uppercase(), prefix = inv.prefix
+        )
+    };
+    inv.reply(success_msg).await;
 
+    drop(data);
+    sync_rank_roles(inv).await;
 }
 
-pub async fn unlink(ctx: &Context, msg: &Message) {
-    let data = ctx.data.read().await;
-    let db = data.get::<DatabaseContainer>().expect("Database not found");
-    let cache = data.get::<UserLinkCache>().expect("UserLinkCache not found");
+pub async fn unlink(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let data = inv.ctx.data.read().await;
+    let user_links = data.get::<UserLinkRepository>().expect("UserLinkRepository not found");
 
-    let has_cached = cache.get(&msg.author.id).is_some();
+    let links = match user_links.get(inv.author.id).await {
+        Ok(links) => links,
+        Err(e) => {
+            eprintln!("Failed to check user link: {}", e);
+            inv.reply(t!(inv.locale, "unlink-check-failed")).await;
+            return;
+        }
+    };
 
-    if !has_cached {
-        match db.get_user_link(msg.author.id).await {
-            Ok(Some(_)) => {},
-            Ok(None) => {
-                let _ = msg.channel_id.say(&ctx.http, "You don't have a linked LoL account.").await;
-                return;
-            }
-            Err(e) => {
-                eprintln!("Failed to check user link: {}", e);
-                let _ = msg.channel_id.say(&ctx.http, "Failed to check your link. Please try again later.").await;
-                return;
-            }
+    let target = match (args.first(), links.as_slice()) {
+        (_, []) => {
+            inv.reply(t!(inv.locale, "no-linked-account")).await;
+            return;
         }
-    }
+        (None, [only]) => only.riot_id(),
+        (None, _) => {
+            inv.reply(t!(inv.locale, "unlink-choose", prefix = inv.prefix, accounts = presentation::link_lines(&links))).await;
+            return;
+        }
+        (Some(riot_id), _) => riot_id.clone(),
+    };
 
-    match db.delete_user_link(msg.author.id).await {
+    let (name, tag) = match parse_riot_id(&target) {
+        Some(parts) => parts,
+        None => {
+            inv.reply(t!(inv.locale, "riot-id-invalid")).await;
+            return;
+        }
+    };
+
+    match user_links.delete(inv.author.id, name, tag).await {
         Ok(true) => {
-            cache.remove(&msg.author.id);
-            let _ = msg.channel_id.say(&ctx.http, "✅ Your LoL account has been unlinked.").await;
+            inv.reply(t!(inv.locale, "unlink-done", riot_id = format!("{}#{}", name, tag))).await;
+            drop(data);
+            sync_rank_roles(inv).await;
         }
         Ok(false) => {
-            let _ = msg.channel_id.say(&ctx.http, "You don't have a linked LoL account.").await;
+            inv.reply(t!(inv.locale, "unlink-not-linked", riot_id = format!("{}#{}", name, tag))).await;
         }
         Err(e) => {
             eprintln!("Failed to delete user link: {}", e);
-            let _ = msg.channel_id.say(&ctx.http, "Failed to unlink your account. Please try again later.").await;
+            inv.reply(t!(inv.locale, "unlink-failed")).await;
         }
     }
 }
 
-pub async fn me(ctx: &Context, msg: &Message) {
-    let data = ctx.data.read().await;
-    let db = data.get::<DatabaseContainer>().expect("Database not found");
-    let cache = data.get::<UserLinkCache>().expect("UserLinkCache not found");
-
-    if let Some(cached) = cache.get(&msg.author.id) {
-        if cached.is_stale(ttl::USER_LINK) {
-            drop(cached);
-
-            match db.get_user_link(msg.author.id).await {
-                Ok(Some(fresh_link)) => {
-                    cache.insert(msg.author.id, CachedData::new(fresh_link.clone()));
-
-                    let response = format!(
-                        "**Your linked account:**\n🎮 **{}#{}**\n🌍 Region: **{}**",
-                       fresh_link.summoner_name, fresh_link.summoner_tag, fresh_link.region.to_uppercase()
-                    );
-                    let _ = msg.channel_id.say(&ctx.http, response).await;
-                }
-                Ok(None) => {
-                    cache.remove(&msg.author.id);
-                    let _ = msg.channel_id.say(&ctx.http, "You don't have a linked Riot account.\nUse `link <Name#TAG> <region>` to link one.").await;
-
-                } 
-                Err(e) => {
-                    eprintln!("Failed to refresh  user link: {}", e);
-                    let _ = msg.channel_id.say(&ctx.http, "Failed to retrieve your link. Please try again later.").await; 
-                }
-            }
-        } else {
-            let response = format!(
-                "**Your linked account:**\n🎮 **{}#{}**\n🌍 Region: **{}**",
-                cached.data.summoner_name, cached.data.summoner_tag, cached.data.region.to_uppercase()
-            );
-            let _ = msg.channel_id.say(&ctx.http, response).await;
+pub async fn main(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let (name, tag) = match parse_riot_id(&args[0]) {
+        Some(parts) => parts,
+        None => {
+            inv.reply(t!(inv.locale, "riot-id-invalid")).await;
+            return;
+        }
+    };
+
+    let data = inv.ctx.data.read().await;
+    let user_links = data.get::<UserLinkRepository>().expect("UserLinkRepository not found");
+
+    match user_links.set_primary(inv.author.id, name, tag).await {
+        Ok(true) => {
+            inv.reply(t!(inv.locale, "main-done", riot_id = format!("{}#{}", name, tag))).await;
+            drop(data);
+            sync_rank_roles(inv).await;
+        }
+        Ok(false) => {
+            inv.reply(t!(inv.locale, "main-not-linked", riot_id = format!("{}#{}", name, tag), prefix = inv.prefix)).await;
+        }
+        Err(e) => {
+            eprintln!("Failed to set main account: {}", e);
+            inv.reply(t!(inv.locale, "main-failed")).await;
         }
-        return;
     }
+}
 
-    match db.get_user_link(msg.author.id).await {
-        Ok(Some(link)) => {
-            cache.insert(msg.author.id, CachedData::new(link.clone()));
+pub async fn me(inv: &mut Invocation<'_>, _args: Vec<String>) {
+    let data = inv.ctx.data.read().await;
+    let user_links = data.get::<UserLinkRepository>().expect("UserLinkRepository not found");
 
-            let response = format!(
-                "**Your linked account:**\n🎮 **{}#{}**\n🌍 Region: **{}**",
-                link.summoner_name, link.summoner_tag, link.region.to_uppercase()
-            );
-            let _ = msg.channel_id.say(&ctx.http, response).await;
+    match user_links.get(inv.author.id).await {
+        Ok(links) if links.is_empty() => {
+            inv.reply(t!(inv.locale, "no-linked-account-hint", prefix = inv.prefix)).await;
         }
-        Ok(None) => {
-            let _ = msg.channel_id.say(&ctx.http, "You don't have a linked LoL account.\nUse `link <Name#TAG> <region>` to link one.").await;
+        Ok(links) => {
+            inv.reply_card(presentation::linked_accounts(inv.locale, &links)).await;
         }
         Err(e) => {
             eprintln!("Failed to get user link: {}", e);
-            let _ = msg.channel_id.say(&ctx.http, "Failed to retrieve your link. Please try again later.").await;
+            inv.reply(t!(inv.locale, "link-load-failed")).await;
         }
     }
 }
 
+pub async fn my_language(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let data = inv.ctx.data.read().await;
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+    let user_locales = data.get::<UserLocales>().expect("UserLocales not found");
+
+    let current = user_locales.get(&inv.author.id).map(|locale| *locale);
+    let locale = match args.first().map(|arg| arg.to_lowercase()) {
+        None => {
+            let response = match current {
+                Some(locale) => t!(inv.locale, "my-language-current", language = locale.name()),
+                None => t!(inv.locale, "my-language-unset"),
+            };
+            inv.reply(response).await;
+            return;
+        }
+        Some(value) if value == "reset" => None,
+        Some(value) => match Locale::parse(&value) {
+            Some(locale) => Some(locale),
+            None => {
+                inv.reply(t!(inv.locale, "language-invalid", value = value, languages = Locale::codes().join(", "))).await;
+                return;
+            }
+        },
+    };
+
+    if let Err(e) = db.set_user_language(inv.author.id, locale).await {
+        eprintln!("Failed to save user language: {}", e);
+        inv.reply(t!(inv.locale, "my-language-failed")).await;
+        return;
+    }
+
+    // Confirm in the language just chosen; a reset falls back to the server's.
+    let response = match locale {
+        Some(locale) => {
+            user_locales.insert(inv.author.id, locale);
+            t!(locale, "my-language-set", language = locale.name())
+        }
+        None => {
+            user_locales.remove(&inv.author.id);
+            drop(data);
+            inv.resolve_locale().await;
+            t!(inv.locale, "my-language-reset")
+        }
+    };
+    inv.reply(response).await;
+}
+
+// Linking changes which tier role the author should hold in this server.
+pub(super) async fn sync_rank_roles(inv: &Invocation<'_>) {
+    if let Some(guild_id) = inv.guild_id {
+        crate::rank_roles::sync_member(inv.ctx, guild_id, inv.author.id).await;
+    }
+}
@@ -0,0 +1,217 @@
+use super::Invocation;
+use rand::Rng;
+use std::time::{SystemTime, UNIX_EPOCH};
+use crate::config::{DatabaseContainer, RiotClientContainer};
+use crate::user_links::UserLinkRepository;
+use crate::database::LinkChallenge;
+use crate::i18n::t;
+use crate::riot::{Platform, RiotApiError, RiotClient};
+use crate::utils::parse_riot_id;
+use super::user::sync_rank_roles;
+
+const CHALLENGE_TTL_SECS: i64 = 10 * 60;
+
+// Icons every account owns, so any of them can be asked for.
+const STARTER_ICONS: i64 = 29;
+
+pub async fn verify(inv: &mut Invocation<'_>, args: Vec<String>) {
+    let data = inv.ctx.data.read().await;
+    let db = data.get::<DatabaseContainer>().expect("Database not found");
+    let user_links = data.get::<UserLinkRepository>().expect("UserLinkRepository not found");
+    let riot_client = data.get::<RiotClientContainer>().expect("RiotClient not found");
+
+    let links = match user_links.get(inv.author.id).await {
+        Ok(links) => links,
+        Err(e) => {
+            eprintln!("Failed to get user links: {}", e);
+            inv.reply(t!(inv.locale, "links-load-failed")).await;
+            return;
+        }
+    };
+
+    let link = match args.first() {
+        Some(riot_id) => {
+            let (name, tag) = match parse_riot_id(riot_id) {
+                Some(parts) => parts,
+                None => {
+                    inv.reply(t!(inv.locale, "riot-id-invalid")).await;
+                    return;
+                }
+            };
+            links.into_iter().find(|link| {
+                link.summoner_name.eq_ignore_ascii_case(name) && link.summoner_tag.eq_ignore_ascii_case(tag)
+            })
+        }
+        None => links.into_iter().find(|link| link.is_primary),
+    };
+
+    let link = match link {
+        Some(link) => link,
+        None => {
+            inv.reply(t!(inv.locale, "verify-not-linked", prefix = inv.prefix)).await;
+            return;
+        }
+    };
+
+    if link.verified {
+        inv.reply(t!(inv.locale, "verify-already", riot_id = link.riot_id())).await;
+        return;
+    }
+
+    let (puuid, platform) = match (link.riot_puuid.clone(), Platform::from_game_region(&link.region)) {
+        (Some(puuid), Some(platform)) => (puuid, platform),
+        _ => {
+            inv.reply(t!(inv.locale, "link-outdated", prefix = inv.prefix)).await;
+            return;
+        }
+    };
+
+    inv.progress(t!(inv.locale, "verify-checking")).await;
+
+    let icon = match current_icon(riot_client, &puuid, platform).await {
+        Ok(icon) => icon,
+        Err(RiotApiError::RateLimited) => {
+            inv.reply(t!(inv.locale, "rate-limited")).await;
+            return;
+        }
+        Err(e) => {
+            inv.reply(t!(inv.locale, "verify-check-failed", error = e)).await;
+            return;
+        }
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let pending = match db.get_challenge(inv.author.id).await {
+        Ok(challenge) => challenge.filter(|c| c.riot_puuid == puuid && c.expires_at > now),
+        Err(e) => {
+            eprintln!("Failed to get link challenge: {}", e);
+            inv.reply(t!(inv.locale, "verify-load-failed")).await;
+            return;
+        }
+    };
+
+    if let Some(challenge) = pending {
+        if icon != challenge.icon_id {
+            inv.reply(t!(
+                inv.locale,
+                "verify-icon-mismatch",
+                current = icon,
+                icon = challenge.icon_id,
+                prefix = inv.prefix,
+                minutes = (challenge.expires_at - now + 59) / 60,
+                url = icon_url(challenge.icon_id),
+            )).await;
+            return;
+        }
+
+        if let Err(e) = user_links.set_verified(inv.author.id, &puuid).await {
+            eprintln!("Failed to mark link verified: {}", e);
+            inv.reply(t!(inv.locale, "verify-save-failed")).await;
+            return;
+        }
+        if let Err(e) = db.delete_challenge(inv.author.id).await {
+            eprintln!("Failed to delete link challenge: {}", e);
+        }
+
+        inv.reply(t!(inv.locale, "verify-done", riot_id = link.riot_id())).await;
+        drop(data);
+        sync_rank_roles(inv).await;
+        return;
+    }
+
+    let challenge = LinkChallenge {
+        discord_user_id: inv.author.id,
+        riot_puuid: puuid,
+        icon_id: pick_icon(icon, &mut rand::thread_rng()),
+        expires_at: now + CHALLENGE_TTL_SECS,
+    };
+
+    if let Err(e) = db.save_challenge(&challenge).await {
+        eprintln!("Failed to save link challenge: {}", e);
+        inv.reply(t!(inv.locale, "verify-start-failed")).await;
+        return;
+    }
+
+    inv.reply(t!(
+        inv.locale,
+        "verify-challenge",
+        riot_id = link.riot_id(),
+        icon = challenge.icon_id,
+        prefix = inv.prefix,
+        minutes = CHALLENGE_TTL_SECS / 60,
+        url = icon_url(challenge.icon_id),
+    )).await;
+}
+
+// Straight from Riot: summoners aren't response-cached, and `ProfileCache`
+// may still hold the profile from before the icon was changed.
+async fn current_icon(riot_client: &RiotClient, puuid: &str, platform: Platform) -> Result<i64, RiotApiError> {
+    Ok(riot_client.get_summoner_by_puuid(puuid, platform).await?.profile_icon_id)
+}
+
+fn pick_icon(current: i64, rng: &mut impl Rng) -> i64 {
+    if (0..STARTER_ICONS).contains(&current) {
+        let icon = rng.gen_range(0..STARTER_ICONS - 1);
+        if icon >= current { icon + 1 } else { icon }
+    } else {
+        rng.gen_range(0..STARTER_ICONS)
+    }
+}
+
+fn icon_url(icon_id: i64) -> String {
+    format!(
+        "https://raw.communitydragon.org/latest/plugins/rcp-be-lol-game-data/global/default/v1/profile-icons/{}.jpg",
+        icon_id
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::profile_cache::{create_profile_cache, get_ranked_profile};
+    use crate::riot::ResponseCache;
+    use crate::riot::mock_server::{MockResponse, MockServer};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    const SUMMONER: &str = include_str!("../../tests/fixtures/riot/summoner.json");
+    const SUMMONER_NEW_ICON: &str = include_str!("../../tests/fixtures/riot/summoner_new_icon.json");
+    const LEAGUE_ENTRIES: &str = include_str!("../../tests/fixtures/riot/league_entries.json");
+    const PUUID: &str = "test-puuid-hide-on-bush";
+
+    #[tokio::test]
+    async fn test_icon_change_is_seen_while_cached() {
+        let summoner = "/euw1/lol/summoner/v4/summoners/by-puuid/test-puuid-hide-on-bush";
+        let league = "/euw1/lol/league/v4/entries/by-puuid/test-puuid-hide-on-bush";
+        let server = MockServer::start(vec![
+            (summoner, vec![MockResponse::json(SUMMONER), MockResponse::json(SUMMONER_NEW_ICON)]),
+            (league, vec![MockResponse::json(LEAGUE_ENTRIES)]),
+        ]).await;
+        let client = server.client().with_cache(ResponseCache::new(None));
+        let profile_cache = create_profile_cache();
+
+        // Looked up by `rank` before the challenge, so the old icon is cached.
+        let (before, _) = get_ranked_profile(&profile_cache, &client, PUUID, Platform::Euw1).await.unwrap();
+        assert_eq!(before.summoner.profile_icon_id, 6);
+
+        // The user switched to the challenge icon.
+        assert_eq!(current_icon(&client, PUUID, Platform::Euw1).await.unwrap(), 12);
+        assert_eq!(server.requests(), vec![summoner, league, summoner]);
+    }
+
+    #[test]
+    fn test_pick_icon_differs_from_current() {
+        let mut rng = StdRng::seed_from_u64(7);
+        for current in [0, 5, STARTER_ICONS - 1, 4568] {
+            for _ in 0..200 {
+                let icon = pick_icon(current, &mut rng);
+                assert_ne!(icon, current);
+                assert!((0..STARTER_ICONS).contains(&icon));
+            }
+        }
+    }
+}
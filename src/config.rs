@@ -1,6 +1,7 @@
-use serenity::model::id::GuildId;
+use serenity::model::id::{GuildId, UserId};
 use serenity::prelude::TypeMapKey;
 use dashmap::DashMap;
+use std::collections::HashSet;
 use std::sync::Arc;
 use crate::database::models::ServerConfig;
 
@@ -22,10 +23,43 @@ impl TypeMapKey for RiotClientContainer {
     type Value = Arc<crate::riot::RiotClient>;
 }
 
+// Starts empty and is filled once Data Dragon has been loaded.
+pub struct StaticDataContainer;
+
+impl TypeMapKey for StaticDataContainer {
+    type Value = crate::riot::static_data::StaticDataStore;
+}
+
+// Per-user language overrides; members without one follow the server.
+pub struct UserLocales;
+
+impl TypeMapKey for UserLocales {
+    type Value = Arc<DashMap<UserId, crate::i18n::Locale>>;
+}
+
+pub struct BotOwners;
+
+impl TypeMapKey for BotOwners {
+    type Value = Arc<HashSet<UserId>>;
+}
+
+pub fn parse_bot_owners(value: &str) -> HashSet<UserId> {
+    value
+        .split(',')
+        .filter_map(|id| id.trim().parse::<u64>().ok())
+        .filter(|id| *id != 0)
+        .map(UserId::new)
+        .collect()
+}
+
 pub fn create_config_map() -> Arc<DashMap<GuildId, ServerConfig>> {
     Arc::new(DashMap::new())
 }
 
+pub fn create_user_locales() -> Arc<DashMap<UserId, crate::i18n::Locale>> {
+    Arc::new(DashMap::new())
+}
+
 pub fn get_prefix(config_map: &Arc<DashMap<GuildId, ServerConfig>>, guild_id: Option<GuildId>) -> String {
     let guild_id = match guild_id {
         Some(id) => id,
@@ -0,0 +1,277 @@
+use dashmap::DashMap;
+use serenity::model::id::{GuildId, UserId};
+use serenity::prelude::TypeMapKey;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+// Upper bound for configurable windows; also how long idle entries are kept.
+pub const MAX_WINDOW: Duration = Duration::from_secs(3600);
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CooldownLimit {
+    pub window: Duration,
+    pub burst: u32,
+}
+
+impl CooldownLimit {
+    pub const fn new(window_secs: u64, burst: u32) -> Self {
+        CooldownLimit {
+            window: Duration::from_secs(window_secs),
+            burst,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CooldownScope {
+    User,
+    Guild,
+}
+
+impl CooldownScope {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CooldownScope::User => "user",
+            CooldownScope::Guild => "guild",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "user" => Some(CooldownScope::User),
+            "guild" | "server" => Some(CooldownScope::Guild),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Cooldown {
+    pub user: Option<CooldownLimit>,
+    pub guild: Option<CooldownLimit>,
+}
+
+impl Cooldown {
+    pub const NONE: Cooldown = Cooldown { user: None, guild: None };
+
+    pub fn get(&self, scope: CooldownScope) -> Option<CooldownLimit> {
+        match scope {
+            CooldownScope::User => self.user,
+            CooldownScope::Guild => self.guild,
+        }
+    }
+
+    pub fn with_overrides(mut self, command: &str, overrides: &[CooldownOverride]) -> Self {
+        for o in overrides.iter().filter(|o| o.command == command) {
+            match o.scope {
+                CooldownScope::User => self.user = o.limit,
+                CooldownScope::Guild => self.guild = o.limit,
+            }
+        }
+        self
+    }
+}
+
+// A guild's replacement for one scope of a command's default cooldown;
+// `limit: None` turns that scope off.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CooldownOverride {
+    pub command: String,
+    pub scope: CooldownScope,
+    pub limit: Option<CooldownLimit>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CooldownHit {
+    pub scope: CooldownScope,
+    pub remaining: Duration,
+}
+
+// One command's recent uses, kept together so a call is checked and recorded
+// under a single entry lock.
+#[derive(Default)]
+struct CommandUses {
+    users: HashMap<UserId, VecDeque<Instant>>,
+    guilds: HashMap<GuildId, VecDeque<Instant>>,
+}
+
+#[derive(Default)]
+pub struct CooldownTracker {
+    uses: DashMap<String, CommandUses>,
+}
+
+impl CooldownTracker {
+    pub fn new() -> Self {
+        CooldownTracker::default()
+    }
+
+    // Records a use only if every applicable scope has room, so a rejected
+    // call doesn't extend the wait.
+    pub fn try_use(
+        &self,
+        command: &str,
+        user_id: UserId,
+        guild_id: Option<GuildId>,
+        cooldown: &Cooldown,
+        now: Instant,
+    ) -> Result<(), CooldownHit> {
+        let mut entry = self.uses.entry(command.to_string()).or_default();
+        let CommandUses { users, guilds } = &mut *entry;
+
+        let user = cooldown.user.map(|limit| (CooldownScope::User, limit, users.entry(user_id).or_default()));
+        let guild = cooldown.guild.zip(guild_id)
+            .map(|(limit, guild_id)| (CooldownScope::Guild, limit, guilds.entry(guild_id).or_default()));
+        let mut scopes: Vec<_> = user.into_iter().chain(guild).collect();
+
+        for (scope, limit, uses) in scopes.iter_mut() {
+            prune(uses, limit.window, now);
+            if let Some(remaining) = remaining(uses, *limit, now) {
+                return Err(CooldownHit { scope: *scope, remaining });
+            }
+        }
+
+        for (_, _, uses) in scopes {
+            uses.push_back(now);
+        }
+
+        Ok(())
+    }
+
+    pub fn sweep(&self, max_window: Duration, now: Instant) {
+        let recent = |uses: &VecDeque<Instant>| uses.back().is_some_and(|last| now.duration_since(*last) < max_window);
+        self.uses.retain(|_, uses| {
+            uses.users.retain(|_, uses| recent(uses));
+            uses.guilds.retain(|_, uses| recent(uses));
+            !uses.users.is_empty() || !uses.guilds.is_empty()
+        });
+    }
+}
+
+fn remaining(uses: &VecDeque<Instant>, limit: CooldownLimit, now: Instant) -> Option<Duration> {
+    if limit.burst == 0 || (uses.len() as u32) < limit.burst {
+        return None;
+    }
+
+    let oldest = *uses.get(uses.len() - limit.burst as usize)?;
+    Some((oldest + limit.window).saturating_duration_since(now))
+}
+
+fn prune(uses: &mut VecDeque<Instant>, window: Duration, now: Instant) {
+    while uses.front().is_some_and(|used| now.duration_since(*used) >= window) {
+        uses.pop_front();
+    }
+}
+
+pub struct Cooldowns;
+
+impl TypeMapKey for Cooldowns {
+    type Value = Arc<CooldownTracker>;
+}
+
+pub fn create_cooldown_tracker() -> Arc<CooldownTracker> {
+    Arc::new(CooldownTracker::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER: UserId = UserId::new(1);
+    const OTHER_USER: UserId = UserId::new(2);
+    const GUILD: GuildId = GuildId::new(10);
+
+    #[test]
+    fn test_user_burst_then_wait() {
+        let tracker = CooldownTracker::new();
+        let cooldown = Cooldown { user: Some(CooldownLimit::new(30, 2)), guild: None };
+        let start = Instant::now();
+
+        assert!(tracker.try_use("link", USER, Some(GUILD), &cooldown, start).is_ok());
+        assert!(tracker.try_use("link", USER, Some(GUILD), &cooldown, start + Duration::from_secs(5)).is_ok());
+
+        let hit = tracker.try_use("link", USER, Some(GUILD), &cooldown, start + Duration::from_secs(10)).unwrap_err();
+        assert_eq!(hit.scope, CooldownScope::User);
+        assert_eq!(hit.remaining, Duration::from_secs(20));
+
+        assert!(tracker.try_use("link", OTHER_USER, Some(GUILD), &cooldown, start).is_ok());
+        assert!(tracker.try_use("rank", USER, Some(GUILD), &cooldown, start).is_ok());
+        assert!(tracker.try_use("link", USER, Some(GUILD), &cooldown, start + Duration::from_secs(30)).is_ok());
+    }
+
+    #[test]
+    fn test_guild_scope_is_shared() {
+        let tracker = CooldownTracker::new();
+        let cooldown = Cooldown { user: None, guild: Some(CooldownLimit::new(60, 1)) };
+        let now = Instant::now();
+
+        assert!(tracker.try_use("rank", USER, Some(GUILD), &cooldown, now).is_ok());
+        let hit = tracker.try_use("rank", OTHER_USER, Some(GUILD), &cooldown, now).unwrap_err();
+        assert_eq!(hit.scope, CooldownScope::Guild);
+
+        // Direct messages have no guild bucket.
+        assert!(tracker.try_use("rank", OTHER_USER, None, &cooldown, now).is_ok());
+    }
+
+    #[test]
+    fn test_rejected_use_is_not_recorded() {
+        let tracker = CooldownTracker::new();
+        let cooldown = Cooldown {
+            user: Some(CooldownLimit::new(10, 5)),
+            guild: Some(CooldownLimit::new(10, 1)),
+        };
+        let now = Instant::now();
+
+        assert!(tracker.try_use("rank", USER, Some(GUILD), &cooldown, now).is_ok());
+        for _ in 0..10 {
+            assert!(tracker.try_use("rank", USER, Some(GUILD), &cooldown, now).is_err());
+        }
+        assert!(tracker.try_use("rank", USER, None, &cooldown, now).is_ok());
+    }
+
+    #[test]
+    fn test_concurrent_uses_stay_within_burst() {
+        let tracker = CooldownTracker::new();
+        let cooldown = Cooldown {
+            user: Some(CooldownLimit::new(60, 3)),
+            guild: Some(CooldownLimit::new(60, 5)),
+        };
+        let now = Instant::now();
+
+        let allowed: usize = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..32)
+                .map(|_| s.spawn(|| tracker.try_use("rank", USER, Some(GUILD), &cooldown, now).is_ok()))
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).filter(|ok| *ok).count()
+        });
+        assert_eq!(allowed, 3);
+    }
+
+    #[test]
+    fn test_overrides_replace_scopes() {
+        let defaults = Cooldown {
+            user: Some(CooldownLimit::new(30, 2)),
+            guild: Some(CooldownLimit::new(60, 20)),
+        };
+        let overrides = vec![
+            CooldownOverride { command: "link".to_string(), scope: CooldownScope::User, limit: Some(CooldownLimit::new(5, 1)) },
+            CooldownOverride { command: "link".to_string(), scope: CooldownScope::Guild, limit: None },
+            CooldownOverride { command: "rank".to_string(), scope: CooldownScope::User, limit: None },
+        ];
+
+        let effective = defaults.with_overrides("link", &overrides);
+        assert_eq!(effective.user, Some(CooldownLimit::new(5, 1)));
+        assert_eq!(effective.guild, None);
+    }
+
+    #[test]
+    fn test_sweep_drops_idle_entries() {
+        let tracker = CooldownTracker::new();
+        let cooldown = Cooldown { user: Some(CooldownLimit::new(10, 1)), guild: None };
+        let now = Instant::now();
+
+        tracker.try_use("link", USER, None, &cooldown, now).unwrap();
+        tracker.sweep(Duration::from_secs(10), now + Duration::from_secs(11));
+        assert!(tracker.uses.is_empty());
+    }
+}
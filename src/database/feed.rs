@@ -0,0 +1,56 @@
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub async fn get_last_seen_match(pool: &SqlitePool, puuid: &str) -> Result<Option<String>, sqlx::Error> {
+    let row = sqlx::query("SELECT last_match_id FROM match_feed_state WHERE riot_puuid = ?1")
+        .bind(puuid)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|r| r.get("last_match_id")))
+}
+
+pub async fn set_last_seen_match(pool: &SqlitePool, puuid: &str, match_id: &str) -> Result<(), sqlx::Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    sqlx::query(
+        "INSERT INTO match_feed_state (riot_puuid, last_match_id, updated_at)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(riot_puuid)
+         DO UPDATE SET last_match_id = excluded.last_match_id, updated_at = excluded.updated_at"
+    )
+    .bind(puuid)
+    .bind(match_id)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::migrations::{self, MIGRATIONS};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn test_last_seen_match_is_replaced() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        migrations::run(&pool, MIGRATIONS).await.unwrap();
+
+        assert_eq!(get_last_seen_match(&pool, "p").await.unwrap(), None);
+
+        set_last_seen_match(&pool, "p", "KR_1").await.unwrap();
+        set_last_seen_match(&pool, "p", "KR_2").await.unwrap();
+        assert_eq!(get_last_seen_match(&pool, "p").await.unwrap().as_deref(), Some("KR_2"));
+    }
+}
@@ -1,13 +1,24 @@
 use sqlx::sqlite::SqlitePool;
 use sqlx::Row;
-use serenity::model::id::GuildId;
+use serenity::model::id::{ChannelId, GuildId, RoleId};
 use super::models::ServerConfig;
+use crate::cooldown::{CooldownLimit, CooldownOverride, CooldownScope};
+use crate::i18n::Locale;
+use crate::riot::Tier;
+use std::collections::{BTreeMap, HashMap};
 use std::time::{SystemTime, UNIX_EPOCH};
 
 pub async fn load_all_configs(pool: &SqlitePool) -> Result<Vec<(GuildId, ServerConfig)>, sqlx::Error> {
-    let rows = sqlx::query("SELECT guild_id, prefix FROM guild_configs")
-        .fetch_all(pool)
-        .await?;
+    let rows = sqlx::query(
+        "SELECT c.guild_id, c.prefix, c.require_verified, c.feed_channel_id, c.language, r.role_id AS admin_role_id
+         FROM guild_configs c
+         LEFT JOIN guild_admin_roles r ON r.guild_id = c.guild_id"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut overrides = load_cooldown_overrides(pool).await?;
+    let mut rank_roles = load_rank_roles(pool).await?;
 
     let configs = rows
         .into_iter()
@@ -15,14 +26,32 @@ pub async fn load_all_configs(pool: &SqlitePool) -> Result<Vec<(GuildId, ServerC
             let guild_id_str: String = row.get("guild_id");
             let guild_id = GuildId::new(guild_id_str.parse().unwrap());
             let prefix: String = row.get("prefix");
-            
-            (guild_id, ServerConfig { prefix })
+            let require_verified: bool = row.get("require_verified");
+            let admin_role_id = row
+                .get::<Option<String>, _>("admin_role_id")
+                .and_then(|id| id.parse().ok())
+                .map(RoleId::new);
+            let feed_channel = row
+                .get::<Option<String>, _>("feed_channel_id")
+                .and_then(|id| id.parse().ok())
+                .map(ChannelId::new);
+            let language = row
+                .get::<Option<String>, _>("language")
+                .and_then(|code| Locale::parse(&code));
+
+            let cooldown_overrides = overrides.remove(&guild_id).unwrap_or_default();
+            let rank_roles = rank_roles.remove(&guild_id).unwrap_or_default();
+
+            (guild_id, ServerConfig { prefix, admin_role_id, cooldown_overrides, require_verified, rank_roles, feed_channel, language })
         })
         .collect();
 
     Ok(configs)
 }
 
+// The admin role, cooldown overrides and rank roles live in their own tables,
+// one per migration, like `guild_rank_roles`. A save rewrites all of a
+// guild's rows in one transaction, so a failed save leaves the old rows.
 pub async fn save_config(pool: &SqlitePool, guild_id: GuildId, config: &ServerConfig) -> Result<(), sqlx::Error> {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -31,17 +60,148 @@ pub async fn save_config(pool: &SqlitePool, guild_id: GuildId, config: &ServerCo
 
     let guild_id_str = guild_id.to_string();
 
+    let mut tx = pool.begin().await?;
+
     sqlx::query(
-        "INSERT INTO guild_configs (guild_id, prefix, created_at, updated_at)
-         VALUES (?1, ?2, ?3, ?3)
+        "INSERT INTO guild_configs (guild_id, prefix, require_verified, feed_channel_id, language, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)
          ON CONFLICT(guild_id) 
-         DO UPDATE SET prefix = excluded.prefix, updated_at = excluded.updated_at"
+         DO UPDATE SET
+            prefix = excluded.prefix,
+            require_verified = excluded.require_verified,
+            feed_channel_id = excluded.feed_channel_id,
+            language = excluded.language,
+            updated_at = excluded.updated_at"
     )
     .bind(&guild_id_str)
     .bind(&config.prefix)
+    .bind(config.require_verified)
+    .bind(config.feed_channel.map(|id| id.to_string()))
+    .bind(config.language.map(|locale| locale.code()))
     .bind(now)
-    .execute(pool)
+    .execute(&mut *tx)
     .await?;
 
+    match config.admin_role_id {
+        Some(role_id) => {
+            sqlx::query(
+                "INSERT INTO guild_admin_roles (guild_id, role_id, updated_at)
+                 VALUES (?1, ?2, ?3)
+                 ON CONFLICT(guild_id)
+                 DO UPDATE SET role_id = excluded.role_id, updated_at = excluded.updated_at"
+            )
+            .bind(&guild_id_str)
+            .bind(role_id.to_string())
+            .bind(now)
+            .execute(&mut *tx)
+            .await?;
+        }
+        None => {
+            sqlx::query("DELETE FROM guild_admin_roles WHERE guild_id = ?1")
+                .bind(&guild_id_str)
+                .execute(&mut *tx)
+                .await?;
+        }
+    }
+
+    sqlx::query("DELETE FROM guild_cooldowns WHERE guild_id = ?1")
+        .bind(&guild_id_str)
+        .execute(&mut *tx)
+        .await?;
+
+    for o in &config.cooldown_overrides {
+        let (window_secs, burst) = match o.limit {
+            Some(limit) => (limit.window.as_secs() as i64, limit.burst as i64),
+            None => (0, 0),
+        };
+
+        sqlx::query(
+            "INSERT INTO guild_cooldowns (guild_id, command, scope, window_secs, burst, updated_at)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+        )
+        .bind(&guild_id_str)
+        .bind(&o.command)
+        .bind(o.scope.as_str())
+        .bind(window_secs)
+        .bind(burst)
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    sqlx::query("DELETE FROM guild_rank_roles WHERE guild_id = ?1")
+        .bind(&guild_id_str)
+        .execute(&mut *tx)
+        .await?;
+
+    for (tier, role_id) in &config.rank_roles {
+        sqlx::query(
+            "INSERT INTO guild_rank_roles (guild_id, tier, role_id, updated_at)
+             VALUES (?1, ?2, ?3, ?4)"
+        )
+        .bind(&guild_id_str)
+        .bind(tier.as_str())
+        .bind(role_id.to_string())
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+
     Ok(())
 }
+
+async fn load_cooldown_overrides(pool: &SqlitePool) -> Result<HashMap<GuildId, Vec<CooldownOverride>>, sqlx::Error> {
+    let rows = sqlx::query("SELECT guild_id, command, scope, window_secs, burst FROM guild_cooldowns")
+        .fetch_all(pool)
+        .await?;
+
+    let mut overrides: HashMap<GuildId, Vec<CooldownOverride>> = HashMap::new();
+    for row in rows {
+        let guild_id = GuildId::new(row.get::<String, _>("guild_id").parse().unwrap());
+        let scope = match CooldownScope::parse(&row.get::<String, _>("scope")) {
+            Some(scope) => scope,
+            None => continue,
+        };
+        let window_secs: i64 = row.get("window_secs");
+        let burst: i64 = row.get("burst");
+
+        let limit = if window_secs > 0 && burst > 0 {
+            Some(CooldownLimit::new(window_secs as u64, burst as u32))
+        } else {
+            None
+        };
+
+        overrides.entry(guild_id).or_default().push(CooldownOverride {
+            command: row.get("command"),
+            scope,
+            limit,
+        });
+    }
+
+    Ok(overrides)
+}
+
+async fn load_rank_roles(pool: &SqlitePool) -> Result<HashMap<GuildId, BTreeMap<Tier, RoleId>>, sqlx::Error> {
+    let rows = sqlx::query("SELECT guild_id, tier, role_id FROM guild_rank_roles")
+        .fetch_all(pool)
+        .await?;
+
+    let mut rank_roles: HashMap<GuildId, BTreeMap<Tier, RoleId>> = HashMap::new();
+    for row in rows {
+        let guild_id = GuildId::new(row.get::<String, _>("guild_id").parse().unwrap());
+        let tier = match Tier::parse(&row.get::<String, _>("tier")) {
+            Some(tier) => tier,
+            None => continue,
+        };
+        let role_id = match row.get::<String, _>("role_id").parse() {
+            Ok(id) => RoleId::new(id),
+            Err(_) => continue,
+        };
+
+        rank_roles.entry(guild_id).or_default().insert(tier, role_id);
+    }
+
+    Ok(rank_roles)
+}
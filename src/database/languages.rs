@@ -0,0 +1,80 @@
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+use serenity::model::id::UserId;
+use crate::i18n::Locale;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Rows whose language is no longer shipped are skipped.
+pub async fn load_user_languages(pool: &SqlitePool) -> Result<Vec<(UserId, Locale)>, sqlx::Error> {
+    let rows = sqlx::query("SELECT discord_user_id, language FROM user_languages")
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            let user_id = row.get::<String, _>("discord_user_id").parse().ok().map(UserId::new)?;
+            let locale = Locale::parse(&row.get::<String, _>("language"))?;
+            Some((user_id, locale))
+        })
+        .collect())
+}
+
+// `None` removes the override.
+pub async fn set_user_language(pool: &SqlitePool, user_id: UserId, locale: Option<Locale>) -> Result<(), sqlx::Error> {
+    let Some(locale) = locale else {
+        sqlx::query("DELETE FROM user_languages WHERE discord_user_id = ?1")
+            .bind(user_id.to_string())
+            .execute(pool)
+            .await?;
+        return Ok(());
+    };
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    sqlx::query(
+        "INSERT INTO user_languages (discord_user_id, language, updated_at)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(discord_user_id)
+         DO UPDATE SET language = excluded.language, updated_at = excluded.updated_at"
+    )
+    .bind(user_id.to_string())
+    .bind(locale.code())
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::migrations::{self, MIGRATIONS};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    #[tokio::test]
+    async fn test_user_language_set_and_reset() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        migrations::run(&pool, MIGRATIONS).await.unwrap();
+
+        let user = UserId::new(1);
+        set_user_language(&pool, user, Some(Locale::Es)).await.unwrap();
+        set_user_language(&pool, user, Some(Locale::Ko)).await.unwrap();
+        set_user_language(&pool, UserId::new(2), Some(Locale::Pt)).await.unwrap();
+
+        let mut languages = load_user_languages(&pool).await.unwrap();
+        languages.sort_by_key(|(user_id, _)| *user_id);
+        assert_eq!(languages, vec![(user, Locale::Ko), (UserId::new(2), Locale::Pt)]);
+
+        set_user_language(&pool, user, None).await.unwrap();
+        assert_eq!(load_user_languages(&pool).await.unwrap(), vec![(UserId::new(2), Locale::Pt)]);
+    }
+}
@@ -0,0 +1,45 @@
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+use crate::riot::MatchDto;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub async fn get_cached_match(pool: &SqlitePool, match_id: &str) -> Result<Option<MatchDto>, sqlx::Error> {
+    let row = sqlx::query("SELECT payload FROM match_cache WHERE match_id = ?1")
+        .bind(match_id)
+        .fetch_optional(pool)
+        .await?;
+
+    match row {
+        Some(row) => {
+            let payload: String = row.get("payload");
+            let match_dto = serde_json::from_str(&payload)
+                .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+            Ok(Some(match_dto))
+        }
+        None => Ok(None),
+    }
+}
+
+pub async fn save_cached_match(pool: &SqlitePool, match_dto: &MatchDto) -> Result<(), sqlx::Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let payload = serde_json::to_string(match_dto)
+        .map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+    sqlx::query(
+        "INSERT INTO match_cache (match_id, payload, game_creation, created_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(match_id) DO NOTHING"
+    )
+    .bind(&match_dto.metadata.match_id)
+    .bind(&payload)
+    .bind(match_dto.info.game_creation)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
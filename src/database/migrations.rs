@@ -0,0 +1,322 @@
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// Migrations live in `migrations/NNN_name.sql` with a matching
+// `NNN_name.down.sql`. Append new ones here; never edit an applied file,
+// its checksum is recorded and checked on every start.
+macro_rules! migration {
+    ($version:expr, $file:literal) => {
+        Migration {
+            version: $version,
+            name: $file,
+            up: include_str!(concat!("../../migrations/", $file, ".sql")),
+            down: include_str!(concat!("../../migrations/", $file, ".down.sql")),
+        }
+    };
+}
+
+pub struct Migration {
+    pub version: i64,
+    pub name: &'static str,
+    pub up: &'static str,
+    pub down: &'static str,
+}
+
+impl Migration {
+    fn checksum(&self) -> String {
+        Sha256::digest(self.up.as_bytes())
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+}
+
+pub static MIGRATIONS: &[Migration] = &[
+    migration!(1, "001_init"),
+    migration!(2, "002_user_links"),
+    migration!(3, "003_match_cache"),
+    migration!(4, "004_guild_admin_roles"),
+    migration!(5, "005_guild_cooldowns"),
+    migration!(6, "006_multiple_user_links"),
+    migration!(7, "007_link_verification"),
+    migration!(8, "008_guild_rank_roles"),
+    migration!(9, "009_rank_snapshots"),
+    migration!(10, "010_match_feed"),
+    migration!(11, "011_languages"),
+    migration!(12, "012_riot_response_cache"),
+    migration!(13, "013_user_links_nocase"),
+];
+
+#[derive(Debug)]
+pub enum MigrationError {
+    ChecksumMismatch { version: i64, name: String },
+    Unknown { version: i64, name: String },
+}
+
+impl fmt::Display for MigrationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MigrationError::ChecksumMismatch { version, name } => {
+                write!(f, "Migration {} ({}) was modified after it was applied", version, name)
+            }
+            MigrationError::Unknown { version, name } => {
+                write!(f, "Database has migration {} ({}) which this build does not know about", version, name)
+            }
+        }
+    }
+}
+
+impl std::error::Error for MigrationError {}
+
+impl From<MigrationError> for sqlx::Error {
+    fn from(e: MigrationError) -> Self {
+        sqlx::Error::Configuration(Box::new(e))
+    }
+}
+
+struct Applied {
+    name: String,
+    checksum: String,
+}
+
+// Applies every pending migration, each in its own transaction. Returns the
+// versions that were applied.
+pub async fn run(pool: &SqlitePool, migrations: &[Migration]) -> Result<Vec<i64>, sqlx::Error> {
+    let applied = applied(pool).await?;
+
+    for (version, entry) in &applied {
+        match migrations.iter().find(|m| m.version == *version) {
+            Some(migration) if migration.checksum() != entry.checksum => {
+                return Err(MigrationError::ChecksumMismatch { version: *version, name: entry.name.clone() }.into());
+            }
+            Some(_) => {}
+            None => {
+                return Err(MigrationError::Unknown { version: *version, name: entry.name.clone() }.into());
+            }
+        }
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let mut ran = Vec::new();
+    for migration in migrations.iter().filter(|m| !applied.contains_key(&m.version)) {
+        let mut tx = pool.begin().await?;
+
+        sqlx::raw_sql(migration.up).execute(&mut *tx).await?;
+
+        sqlx::query(
+            "INSERT INTO schema_migrations (version, name, checksum, applied_at)
+             VALUES (?1, ?2, ?3, ?4)"
+        )
+        .bind(migration.version)
+        .bind(migration.name)
+        .bind(migration.checksum())
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+
+        tx.commit().await?;
+        ran.push(migration.version);
+    }
+
+    Ok(ran)
+}
+
+// Reverts applied migrations newer than `target`, newest first. Meant for
+// development; the down files drop data.
+pub async fn rollback(pool: &SqlitePool, migrations: &[Migration], target: i64) -> Result<Vec<i64>, sqlx::Error> {
+    let applied = applied(pool).await?;
+
+    let mut versions: Vec<i64> = applied.keys().copied().filter(|v| *v > target).collect();
+    versions.sort_unstable_by(|a, b| b.cmp(a));
+
+    let mut reverted = Vec::new();
+    for version in versions {
+        let migration = migrations.iter().find(|m| m.version == version).ok_or_else(|| {
+            MigrationError::Unknown { version, name: applied[&version].name.clone() }
+        })?;
+
+        let mut tx = pool.begin().await?;
+
+        sqlx::raw_sql(migration.down).execute(&mut *tx).await?;
+
+        sqlx::query("DELETE FROM schema_migrations WHERE version = ?1")
+            .bind(version)
+            .execute(&mut *tx)
+            .await?;
+
+        tx.commit().await?;
+        reverted.push(version);
+    }
+
+    Ok(reverted)
+}
+
+pub async fn current_version(pool: &SqlitePool) -> Result<i64, sqlx::Error> {
+    Ok(applied(pool).await?.keys().copied().max().unwrap_or(0))
+}
+
+async fn applied(pool: &SqlitePool) -> Result<HashMap<i64, Applied>, sqlx::Error> {
+    sqlx::query(
+        "CREATE TABLE IF NOT EXISTS schema_migrations (
+            version INTEGER PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            checksum TEXT NOT NULL,
+            applied_at INTEGER NOT NULL
+        )"
+    )
+    .execute(pool)
+    .await?;
+
+    let rows = sqlx::query("SELECT version, name, checksum FROM schema_migrations")
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let version: i64 = row.get("version");
+            (version, Applied { name: row.get("name"), checksum: row.get("checksum") })
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    // One connection, otherwise every pooled connection gets its own
+    // in-memory database.
+    async fn memory_pool() -> SqlitePool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap()
+    }
+
+    async fn table_exists(pool: &SqlitePool, name: &str) -> bool {
+        sqlx::query("SELECT name FROM sqlite_master WHERE type = 'table' AND name = ?1")
+            .bind(name)
+            .fetch_optional(pool)
+            .await
+            .unwrap()
+            .is_some()
+    }
+
+    #[tokio::test]
+    async fn test_applies_pending_once() {
+        let pool = memory_pool().await;
+
+        let ran = run(&pool, MIGRATIONS).await.unwrap();
+        assert_eq!(ran, MIGRATIONS.iter().map(|m| m.version).collect::<Vec<_>>());
+        assert!(table_exists(&pool, "user_links").await);
+        assert!(table_exists(&pool, "guild_cooldowns").await);
+
+        assert!(run(&pool, MIGRATIONS).await.unwrap().is_empty());
+        assert_eq!(current_version(&pool).await.unwrap(), MIGRATIONS.last().unwrap().version);
+    }
+
+    #[tokio::test]
+    async fn test_checksum_mismatch_refuses() {
+        let pool = memory_pool().await;
+        run(&pool, &MIGRATIONS[..1]).await.unwrap();
+
+        let edited = [Migration { up: "SELECT 1;", ..migration!(1, "001_init") }];
+        let err = run(&pool, &edited).await.unwrap_err();
+        assert!(err.to_string().contains("modified"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_unknown_applied_version_refuses() {
+        let pool = memory_pool().await;
+        run(&pool, MIGRATIONS).await.unwrap();
+
+        assert!(run(&pool, &MIGRATIONS[..2]).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_failed_migration_rolls_back() {
+        let pool = memory_pool().await;
+        let broken = [Migration {
+            version: 1,
+            name: "broken",
+            up: "CREATE TABLE half_done (id INTEGER); INSERT INTO missing VALUES (1);",
+            down: "",
+        }];
+
+        assert!(run(&pool, &broken).await.is_err());
+        assert!(!table_exists(&pool, "half_done").await);
+        assert_eq!(current_version(&pool).await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rollback_and_reapply() {
+        let pool = memory_pool().await;
+        run(&pool, MIGRATIONS).await.unwrap();
+
+        let reverted = rollback(&pool, MIGRATIONS, 3).await.unwrap();
+        assert_eq!(reverted, vec![13, 12, 11, 10, 9, 8, 7, 6, 5, 4]);
+        assert!(!table_exists(&pool, "guild_cooldowns").await);
+        assert!(table_exists(&pool, "match_cache").await);
+        assert_eq!(current_version(&pool).await.unwrap(), 3);
+
+        assert_eq!(run(&pool, MIGRATIONS).await.unwrap(), vec![4, 5, 6, 7, 8, 9, 10, 11, 12, 13]);
+    }
+
+    #[tokio::test]
+    async fn test_existing_link_becomes_primary() {
+        let pool = memory_pool().await;
+        run(&pool, &MIGRATIONS[..5]).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO user_links (discord_user_id, summoner_name, summoner_tag, region, riot_puuid, created_at, updated_at)
+             VALUES ('1', 'Faker', 'KR1', 'kr', 'puuid', 0, 0)"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        run(&pool, MIGRATIONS).await.unwrap();
+
+        let is_primary: bool = sqlx::query("SELECT is_primary FROM user_links WHERE discord_user_id = '1'")
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+            .get("is_primary");
+        assert!(is_primary);
+    }
+
+    #[tokio::test]
+    async fn test_case_duplicate_links_are_merged() {
+        let pool = memory_pool().await;
+        run(&pool, &MIGRATIONS[..12]).await.unwrap();
+
+        sqlx::query(
+            "INSERT INTO user_links (discord_user_id, summoner_name, summoner_tag, region, is_primary, created_at, updated_at)
+             VALUES ('1', 'foo', 'euw', 'euw', 0, 0, 0), ('1', 'Foo', 'EUW', 'euw', 1, 1, 1), ('2', 'foo', 'euw', 'euw', 1, 0, 0)"
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        run(&pool, MIGRATIONS).await.unwrap();
+
+        let links: Vec<(String, String)> = sqlx::query("SELECT discord_user_id, summoner_name FROM user_links ORDER BY discord_user_id")
+            .fetch_all(&pool)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.get("discord_user_id"), row.get("summoner_name")))
+            .collect();
+        assert_eq!(links, vec![("1".to_string(), "Foo".to_string()), ("2".to_string(), "foo".to_string())]);
+    }
+}
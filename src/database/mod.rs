@@ -1,11 +1,20 @@
 mod guild;
 mod user;
+mod matches;
+mod migrations;
+mod verification;
+mod snapshots;
+mod feed;
+mod languages;
+mod responses;
 
 pub mod models;
 
 use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
 use serenity::model::id::{GuildId, UserId};
-pub use models::{ServerConfig, UserLink};
+use crate::i18n::Locale;
+use crate::riot::{LeagueEntry, MatchDto};
+pub use models::{LinkChallenge, RankSnapshot, ServerConfig, UserLink};
 
 pub struct Database {
     pool: SqlitePool,
@@ -13,22 +22,47 @@ pub struct Database {
 
 impl Database {
     pub async fn new(database_url: &str) -> Result<Self, sqlx::Error> {
+        let db = Database::connect(database_url).await?;
+
+        let applied = migrations::run(&db.pool, migrations::MIGRATIONS).await?;
+        if !applied.is_empty() {
+            println!("Applied database migrations: {:?}", applied);
+        }
+
+        Ok(db)
+    }
+
+    // Connects without touching the schema.
+    pub async fn connect(database_url: &str) -> Result<Self, sqlx::Error> {
         let pool = SqlitePoolOptions::new()
             .max_connections(5)
             .connect(database_url)
             .await?;
 
-        sqlx::query(include_str!("../../migrations/001_init.sql"))
-            .execute(&pool)
-            .await?;
-        
-        sqlx::query(include_str!("../../migrations/002_user_links.sql"))
-            .execute(&pool)
+        Ok(Database { pool })
+    }
+
+    // A private SQLite database for tests; one connection, since every
+    // connection to `sqlite::memory:` gets its own database.
+    #[cfg(test)]
+    pub async fn in_memory() -> Result<Self, sqlx::Error> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
             .await?;
+        migrations::run(&pool, migrations::MIGRATIONS).await?;
 
         Ok(Database { pool })
     }
 
+    pub async fn rollback(&self, target_version: i64) -> Result<Vec<i64>, sqlx::Error> {
+        migrations::rollback(&self.pool, migrations::MIGRATIONS, target_version).await
+    }
+
+    pub async fn schema_version(&self) -> Result<i64, sqlx::Error> {
+        migrations::current_version(&self.pool).await
+    }
+
     pub async fn load_all_configs(&self) -> Result<Vec<(GuildId, ServerConfig)>, sqlx::Error> {
         guild::load_all_configs(&self.pool).await
     }
@@ -37,15 +71,95 @@ impl Database {
         guild::save_config(&self.pool, guild_id, config).await
     }
 
-    pub async fn get_user_link(&self, user_id: UserId) -> Result<Option<UserLink>, sqlx::Error> {
-        user::get_user_link(&self.pool, user_id).await
+    pub async fn load_user_languages(&self) -> Result<Vec<(UserId, Locale)>, sqlx::Error> {
+        languages::load_user_languages(&self.pool).await
+    }
+
+    pub async fn set_user_language(&self, user_id: UserId, locale: Option<Locale>) -> Result<(), sqlx::Error> {
+        languages::set_user_language(&self.pool, user_id, locale).await
+    }
+
+    pub async fn get_user_links(&self, user_id: UserId) -> Result<Vec<UserLink>, sqlx::Error> {
+        user::get_user_links(&self.pool, user_id).await
+    }
+
+    pub async fn get_primary_links(&self) -> Result<Vec<UserLink>, sqlx::Error> {
+        user::get_primary_links(&self.pool).await
+    }
+
+    pub async fn get_all_links(&self) -> Result<Vec<UserLink>, sqlx::Error> {
+        user::get_all_links(&self.pool).await
     }
 
     pub async fn save_user_link(&self, link: &UserLink) -> Result<(), sqlx::Error> {
         user::save_user_link(&self.pool, link).await
     }
 
-    pub async fn delete_user_link(&self, user_id: UserId) -> Result<bool, sqlx::Error> {
-        user::delete_user_link(&self.pool, user_id).await
+    pub async fn delete_user_link(&self, user_id: UserId, name: &str, tag: &str) -> Result<bool, sqlx::Error> {
+        user::delete_user_link(&self.pool, user_id, name, tag).await
+    }
+
+    pub async fn set_primary_link(&self, user_id: UserId, name: &str, tag: &str) -> Result<bool, sqlx::Error> {
+        user::set_primary_link(&self.pool, user_id, name, tag).await
+    }
+
+    pub async fn set_link_verified(&self, user_id: UserId, puuid: &str) -> Result<bool, sqlx::Error> {
+        user::set_link_verified(&self.pool, user_id, puuid).await
+    }
+
+    pub async fn get_challenge(&self, user_id: UserId) -> Result<Option<LinkChallenge>, sqlx::Error> {
+        verification::get_challenge(&self.pool, user_id).await
+    }
+
+    pub async fn save_challenge(&self, challenge: &LinkChallenge) -> Result<(), sqlx::Error> {
+        verification::save_challenge(&self.pool, challenge).await
+    }
+
+    pub async fn delete_challenge(&self, user_id: UserId) -> Result<(), sqlx::Error> {
+        verification::delete_challenge(&self.pool, user_id).await
+    }
+
+    pub async fn search_riot_ids(&self, prefix: &str, user_ids: &[UserId], limit: u32) -> Result<Vec<String>, sqlx::Error> {
+        user::search_riot_ids(&self.pool, prefix, user_ids, limit).await
+    }
+
+    pub async fn get_cached_match(&self, match_id: &str) -> Result<Option<MatchDto>, sqlx::Error> {
+        matches::get_cached_match(&self.pool, match_id).await
+    }
+
+    pub async fn save_cached_match(&self, match_dto: &MatchDto) -> Result<(), sqlx::Error> {
+        matches::save_cached_match(&self.pool, match_dto).await
+    }
+
+    pub async fn get_tracked_accounts(&self) -> Result<Vec<(String, String)>, sqlx::Error> {
+        snapshots::get_tracked_accounts(&self.pool).await
+    }
+
+    pub async fn record_snapshot(&self, puuid: &str, entry: &LeagueEntry, captured_at: i64) -> Result<bool, sqlx::Error> {
+        snapshots::record_snapshot(&self.pool, puuid, entry, captured_at).await
+    }
+
+    pub async fn get_snapshots_since(&self, puuid: &str, queue_type: &str, since: i64) -> Result<Vec<RankSnapshot>, sqlx::Error> {
+        snapshots::get_snapshots_since(&self.pool, puuid, queue_type, since).await
+    }
+
+    pub async fn get_last_seen_match(&self, puuid: &str) -> Result<Option<String>, sqlx::Error> {
+        feed::get_last_seen_match(&self.pool, puuid).await
+    }
+
+    pub async fn set_last_seen_match(&self, puuid: &str, match_id: &str) -> Result<(), sqlx::Error> {
+        feed::set_last_seen_match(&self.pool, puuid, match_id).await
+    }
+
+    pub async fn get_riot_response(&self, key: &str, now: i64) -> Result<Option<(String, i64)>, sqlx::Error> {
+        responses::get_riot_response(&self.pool, key, now).await
+    }
+
+    pub async fn save_riot_response(&self, key: &str, body: &str, fetched_at: i64, expires_at: i64) -> Result<(), sqlx::Error> {
+        responses::save_riot_response(&self.pool, key, body, fetched_at, expires_at).await
+    }
+
+    pub async fn prune_riot_responses(&self, now: i64) -> Result<u64, sqlx::Error> {
+        responses::prune_riot_responses(&self.pool, now).await
     }
 }
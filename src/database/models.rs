@@ -1,14 +1,30 @@
-use serenity::model::id::UserId;
+use serenity::model::id::{ChannelId, RoleId, UserId};
+use std::collections::BTreeMap;
+use crate::cooldown::CooldownOverride;
+use crate::i18n::Locale;
+use crate::riot::Tier;
 
 #[derive(Clone, Debug)]
 pub struct ServerConfig {
     pub prefix: String,
+    pub admin_role_id: Option<RoleId>,
+    pub cooldown_overrides: Vec<CooldownOverride>,
+    pub require_verified: bool,
+    pub rank_roles: BTreeMap<Tier, RoleId>,
+    pub feed_channel: Option<ChannelId>,
+    pub language: Option<Locale>,
 }
 
 impl Default for ServerConfig {
     fn default() -> Self {
         ServerConfig {
             prefix: "!".to_string(),
+            admin_role_id: None,
+            cooldown_overrides: Vec::new(),
+            require_verified: false,
+            rank_roles: BTreeMap::new(),
+            feed_channel: None,
+            language: None,
         }
     }
 }
@@ -20,4 +36,32 @@ pub struct UserLink {
     pub summoner_tag: String,
     pub region: String,
     pub riot_puuid: Option<String>,
+    pub is_primary: bool,
+    pub verified: bool,
+}
+
+impl UserLink {
+    pub fn riot_id(&self) -> String {
+        format!("{}#{}", self.summoner_name, self.summoner_tag)
+    }
+}
+
+// A pending ownership check: the user has to switch the account's profile
+// icon to `icon_id` before `expires_at` (unix seconds).
+#[derive(Clone, Debug)]
+pub struct LinkChallenge {
+    pub discord_user_id: UserId,
+    pub riot_puuid: String,
+    pub icon_id: i64,
+    pub expires_at: i64,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RankSnapshot {
+    pub tier: String,
+    pub rank: String,
+    pub league_points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub captured_at: i64,
 }
@@ -0,0 +1,39 @@
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+
+// The body and when it was fetched, unless the row has expired.
+pub async fn get_riot_response(pool: &SqlitePool, key: &str, now: i64) -> Result<Option<(String, i64)>, sqlx::Error> {
+    let row = sqlx::query("SELECT body, fetched_at FROM riot_response_cache WHERE cache_key = ?1 AND expires_at > ?2")
+        .bind(key)
+        .bind(now)
+        .fetch_optional(pool)
+        .await?;
+
+    Ok(row.map(|r| (r.get("body"), r.get("fetched_at"))))
+}
+
+pub async fn save_riot_response(pool: &SqlitePool, key: &str, body: &str, fetched_at: i64, expires_at: i64) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO riot_response_cache (cache_key, body, fetched_at, expires_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(cache_key)
+         DO UPDATE SET body = excluded.body, fetched_at = excluded.fetched_at, expires_at = excluded.expires_at"
+    )
+    .bind(key)
+    .bind(body)
+    .bind(fetched_at)
+    .bind(expires_at)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+pub async fn prune_riot_responses(pool: &SqlitePool, now: i64) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM riot_response_cache WHERE expires_at <= ?1")
+        .bind(now)
+        .execute(pool)
+        .await?;
+
+    Ok(result.rows_affected())
+}
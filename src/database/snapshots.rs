@@ -0,0 +1,155 @@
+use sqlx::sqlite::{SqlitePool, SqliteRow};
+use sqlx::Row;
+use super::models::RankSnapshot;
+use crate::riot::LeagueEntry;
+
+// Distinct accounts across all links, with the region to query them in.
+pub async fn get_tracked_accounts(pool: &SqlitePool) -> Result<Vec<(String, String)>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT riot_puuid, MIN(region) AS region
+         FROM user_links
+         WHERE riot_puuid IS NOT NULL
+         GROUP BY riot_puuid"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| (r.get("riot_puuid"), r.get("region")))
+        .collect())
+}
+
+// Skips the insert when nothing changed since the latest snapshot, so idle
+// accounts don't grow the table. Returns whether a row was written.
+pub async fn record_snapshot(pool: &SqlitePool, puuid: &str, entry: &LeagueEntry, captured_at: i64) -> Result<bool, sqlx::Error> {
+    let latest = sqlx::query(
+        "SELECT tier, rank, league_points, wins, losses, captured_at
+         FROM rank_snapshots
+         WHERE riot_puuid = ?1 AND queue_type = ?2
+         ORDER BY captured_at DESC
+         LIMIT 1"
+    )
+    .bind(puuid)
+    .bind(&entry.queue_type)
+    .fetch_optional(pool)
+    .await?
+    .map(|r| snapshot_from_row(&r));
+
+    let unchanged = latest.is_some_and(|latest| {
+        latest.tier == entry.tier
+            && latest.rank == entry.rank
+            && latest.league_points == entry.league_points
+            && latest.wins == entry.wins
+            && latest.losses == entry.losses
+    });
+    if unchanged {
+        return Ok(false);
+    }
+
+    sqlx::query(
+        "INSERT INTO rank_snapshots (riot_puuid, queue_type, tier, rank, league_points, wins, losses, captured_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+    )
+    .bind(puuid)
+    .bind(&entry.queue_type)
+    .bind(&entry.tier)
+    .bind(&entry.rank)
+    .bind(entry.league_points)
+    .bind(entry.wins)
+    .bind(entry.losses)
+    .bind(captured_at)
+    .execute(pool)
+    .await?;
+
+    Ok(true)
+}
+
+// Snapshots taken since `since`, oldest first, preceded by the last one from
+// before it so the window has a starting point.
+pub async fn get_snapshots_since(pool: &SqlitePool, puuid: &str, queue_type: &str, since: i64) -> Result<Vec<RankSnapshot>, sqlx::Error> {
+    let baseline = sqlx::query(
+        "SELECT tier, rank, league_points, wins, losses, captured_at
+         FROM rank_snapshots
+         WHERE riot_puuid = ?1 AND queue_type = ?2 AND captured_at < ?3
+         ORDER BY captured_at DESC
+         LIMIT 1"
+    )
+    .bind(puuid)
+    .bind(queue_type)
+    .bind(since)
+    .fetch_optional(pool)
+    .await?;
+
+    let rows = sqlx::query(
+        "SELECT tier, rank, league_points, wins, losses, captured_at
+         FROM rank_snapshots
+         WHERE riot_puuid = ?1 AND queue_type = ?2 AND captured_at >= ?3
+         ORDER BY captured_at"
+    )
+    .bind(puuid)
+    .bind(queue_type)
+    .bind(since)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(baseline
+        .iter()
+        .chain(rows.iter())
+        .map(snapshot_from_row)
+        .collect())
+}
+
+fn snapshot_from_row(r: &SqliteRow) -> RankSnapshot {
+    RankSnapshot {
+        tier: r.get("tier"),
+        rank: r.get("rank"),
+        league_points: r.get("league_points"),
+        wins: r.get("wins"),
+        losses: r.get("losses"),
+        captured_at: r.get("captured_at"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::migrations::{self, MIGRATIONS};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn entry(lp: i32, wins: i32) -> LeagueEntry {
+        LeagueEntry {
+            queue_type: LeagueEntry::RANKED_SOLO.to_string(),
+            tier: "GOLD".to_string(),
+            rank: "II".to_string(),
+            league_points: lp,
+            wins,
+            losses: 10,
+            hot_streak: false,
+            veteran: false,
+            fresh_blood: false,
+            inactive: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_snapshots_since_include_baseline() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        migrations::run(&pool, MIGRATIONS).await.unwrap();
+
+        assert!(record_snapshot(&pool, "p", &entry(10, 10), 100).await.unwrap());
+        assert!(!record_snapshot(&pool, "p", &entry(10, 10), 150).await.unwrap());
+        assert!(record_snapshot(&pool, "p", &entry(30, 11), 200).await.unwrap());
+        assert!(record_snapshot(&pool, "p", &entry(50, 12), 300).await.unwrap());
+
+        let snapshots = get_snapshots_since(&pool, "p", LeagueEntry::RANKED_SOLO, 200).await.unwrap();
+        let times: Vec<i64> = snapshots.iter().map(|s| s.captured_at).collect();
+        assert_eq!(times, vec![100, 200, 300]);
+
+        assert!(get_snapshots_since(&pool, "p", LeagueEntry::RANKED_FLEX, 0).await.unwrap().is_empty());
+    }
+}
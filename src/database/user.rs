@@ -1,30 +1,54 @@
-use sqlx::sqlite::SqlitePool;
+use sqlx::sqlite::{SqlitePool, SqliteRow};
 use sqlx::Row;
 use serenity::model::id::UserId;
 use super::models::UserLink;
 use std::time::{SystemTime, UNIX_EPOCH};
 
-pub async fn get_user_link(pool: &SqlitePool, user_id: UserId) -> Result<Option<UserLink>, sqlx::Error> {
+pub async fn get_user_links(pool: &SqlitePool, user_id: UserId) -> Result<Vec<UserLink>, sqlx::Error> {
     let user_id_str = user_id.to_string();
 
-    let row = sqlx::query(
-        "SELECT discord_user_id, summoner_name, summoner_tag, region, riot_puuid 
-         FROM user_links 
-         WHERE discord_user_id = ?1"
+    let rows = sqlx::query(
+        "SELECT discord_user_id, summoner_name, summoner_tag, region, riot_puuid, is_primary, verified
+         FROM user_links
+         WHERE discord_user_id = ?1
+         ORDER BY is_primary DESC, created_at, id"
     )
     .bind(&user_id_str)
-    .fetch_optional(pool)
+    .fetch_all(pool)
     .await?;
 
-    Ok(row.map(|r| UserLink {
-        discord_user_id: UserId::new(r.get::<String, _>("discord_user_id").parse().unwrap()),
-        summoner_name: r.get("summoner_name"),
-        summoner_tag: r.get("summoner_tag"),
-        region: r.get("region"),
-        riot_puuid: r.get("riot_puuid"),
-    }))
+    Ok(rows.into_iter().map(|r| link_from_row(&r)).collect())
+}
+
+// Every user's primary account, for jobs that walk all linked members.
+pub async fn get_primary_links(pool: &SqlitePool) -> Result<Vec<UserLink>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT discord_user_id, summoner_name, summoner_tag, region, riot_puuid, is_primary, verified
+         FROM user_links
+         WHERE is_primary = 1"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| link_from_row(&r)).collect())
 }
 
+// Every link with a known PUUID, for jobs that follow all accounts.
+pub async fn get_all_links(pool: &SqlitePool) -> Result<Vec<UserLink>, sqlx::Error> {
+    let rows = sqlx::query(
+        "SELECT discord_user_id, summoner_name, summoner_tag, region, riot_puuid, is_primary, verified
+         FROM user_links
+         WHERE riot_puuid IS NOT NULL"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows.into_iter().map(|r| link_from_row(&r)).collect())
+}
+
+// Adds the account, or refreshes it if the user already linked this Riot ID.
+// The first account a user links becomes their primary one. Verification
+// only survives a relink that resolves to the same PUUID.
 pub async fn save_user_link(pool: &SqlitePool, link: &UserLink) -> Result<(), sqlx::Error> {
     let now = SystemTime::now()
         .duration_since(UNIX_EPOCH)
@@ -34,13 +58,16 @@ pub async fn save_user_link(pool: &SqlitePool, link: &UserLink) -> Result<(), sq
     let user_id_str = link.discord_user_id.to_string();
 
     sqlx::query(
-        "INSERT INTO user_links (discord_user_id, summoner_name, summoner_tag, region, riot_puuid, created_at, updated_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)
-         ON CONFLICT(discord_user_id)
+        "INSERT INTO user_links (discord_user_id, summoner_name, summoner_tag, region, riot_puuid, is_primary, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5,
+            NOT EXISTS (SELECT 1 FROM user_links WHERE discord_user_id = ?1 AND is_primary = 1),
+            ?6, ?6)
+         ON CONFLICT(discord_user_id, summoner_name COLLATE NOCASE, summoner_tag COLLATE NOCASE)
          DO UPDATE SET 
             summoner_name = excluded.summoner_name,
             summoner_tag = excluded.summoner_tag,
             region = excluded.region,
+            verified = user_links.verified AND user_links.riot_puuid IS excluded.riot_puuid,
             riot_puuid = excluded.riot_puuid,
             updated_at = excluded.updated_at"
     )
@@ -56,13 +83,205 @@ pub async fn save_user_link(pool: &SqlitePool, link: &UserLink) -> Result<(), sq
     Ok(())
 }
 
-pub async fn delete_user_link(pool: &SqlitePool, user_id: UserId) -> Result<bool, sqlx::Error> {
+// Removes one linked account. If it was the primary one, the oldest
+// remaining account takes over.
+pub async fn delete_user_link(pool: &SqlitePool, user_id: UserId, name: &str, tag: &str) -> Result<bool, sqlx::Error> {
+    let user_id_str = user_id.to_string();
+    let mut tx = pool.begin().await?;
+
+    let result = sqlx::query(
+        "DELETE FROM user_links
+         WHERE discord_user_id = ?1
+           AND summoner_name = ?2 COLLATE NOCASE
+           AND summoner_tag = ?3 COLLATE NOCASE"
+    )
+    .bind(&user_id_str)
+    .bind(name)
+    .bind(tag)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query(
+        "UPDATE user_links SET is_primary = 1
+         WHERE id = (
+            SELECT id FROM user_links
+            WHERE discord_user_id = ?1
+            ORDER BY created_at, id
+            LIMIT 1
+         )
+         AND NOT EXISTS (SELECT 1 FROM user_links WHERE discord_user_id = ?1 AND is_primary = 1)"
+    )
+    .bind(&user_id_str)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+pub async fn set_primary_link(pool: &SqlitePool, user_id: UserId, name: &str, tag: &str) -> Result<bool, sqlx::Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
     let user_id_str = user_id.to_string();
+    let mut tx = pool.begin().await?;
 
-    let result = sqlx::query("DELETE FROM user_links WHERE discord_user_id = ?1")
+    let exists = sqlx::query(
+        "SELECT 1 FROM user_links
+         WHERE discord_user_id = ?1
+           AND summoner_name = ?2 COLLATE NOCASE
+           AND summoner_tag = ?3 COLLATE NOCASE"
+    )
+    .bind(&user_id_str)
+    .bind(name)
+    .bind(tag)
+    .fetch_optional(&mut *tx)
+    .await?
+    .is_some();
+
+    if !exists {
+        return Ok(false);
+    }
+
+    sqlx::query("UPDATE user_links SET is_primary = 0 WHERE discord_user_id = ?1 AND is_primary = 1")
         .bind(&user_id_str)
-        .execute(pool)
+        .execute(&mut *tx)
         .await?;
 
+    sqlx::query(
+        "UPDATE user_links SET is_primary = 1, updated_at = ?4
+         WHERE discord_user_id = ?1
+           AND summoner_name = ?2 COLLATE NOCASE
+           AND summoner_tag = ?3 COLLATE NOCASE"
+    )
+    .bind(&user_id_str)
+    .bind(name)
+    .bind(tag)
+    .bind(now)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(true)
+}
+
+pub async fn set_link_verified(pool: &SqlitePool, user_id: UserId, puuid: &str) -> Result<bool, sqlx::Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let result = sqlx::query(
+        "UPDATE user_links SET verified = 1, updated_at = ?3
+         WHERE discord_user_id = ?1 AND riot_puuid = ?2"
+    )
+    .bind(user_id.to_string())
+    .bind(puuid)
+    .bind(now)
+    .execute(pool)
+    .await?;
+
     Ok(result.rows_affected() > 0)
 }
+
+// Riot IDs starting with `prefix`, among the accounts linked by `user_ids`.
+pub async fn search_riot_ids(pool: &SqlitePool, prefix: &str, user_ids: &[UserId], limit: u32) -> Result<Vec<String>, sqlx::Error> {
+    let pattern = format!(
+        "{}%",
+        prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_")
+    );
+    let user_ids: Vec<String> = user_ids.iter().map(|id| id.to_string()).collect();
+    let user_ids = serde_json::to_string(&user_ids).map_err(|e| sqlx::Error::Encode(Box::new(e)))?;
+
+    let rows = sqlx::query(
+        "SELECT DISTINCT summoner_name, summoner_tag
+         FROM user_links
+         WHERE summoner_name || '#' || summoner_tag LIKE ?1 ESCAPE '\\'
+           AND discord_user_id IN (SELECT value FROM json_each(?2))
+         ORDER BY summoner_name
+         LIMIT ?3"
+    )
+    .bind(&pattern)
+    .bind(&user_ids)
+    .bind(limit)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|r| format!("{}#{}", r.get::<String, _>("summoner_name"), r.get::<String, _>("summoner_tag")))
+        .collect())
+}
+
+fn link_from_row(r: &SqliteRow) -> UserLink {
+    UserLink {
+        discord_user_id: UserId::new(r.get::<String, _>("discord_user_id").parse().unwrap()),
+        summoner_name: r.get("summoner_name"),
+        summoner_tag: r.get("summoner_tag"),
+        region: r.get("region"),
+        riot_puuid: r.get("riot_puuid"),
+        is_primary: r.get("is_primary"),
+        verified: r.get("verified"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::migrations::{self, MIGRATIONS};
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    fn link(user_id: u64, name: &str) -> UserLink {
+        UserLink {
+            discord_user_id: UserId::new(user_id),
+            summoner_name: name.to_string(),
+            summoner_tag: "EUW".to_string(),
+            region: "euw".to_string(),
+            riot_puuid: None,
+            is_primary: false,
+            verified: false,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_only_sees_given_users() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        migrations::run(&pool, MIGRATIONS).await.unwrap();
+
+        save_user_link(&pool, &link(1, "Faker")).await.unwrap();
+        save_user_link(&pool, &link(2, "Fake Account")).await.unwrap();
+        save_user_link(&pool, &link(3, "Caps")).await.unwrap();
+
+        let members = [UserId::new(1), UserId::new(3)];
+        assert_eq!(search_riot_ids(&pool, "fa", &members, 25).await.unwrap(), vec!["Faker#EUW"]);
+        assert_eq!(search_riot_ids(&pool, "", &members, 25).await.unwrap(), vec!["Caps#EUW", "Faker#EUW"]);
+        assert!(search_riot_ids(&pool, "", &[], 25).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_relink_in_other_case_updates_the_link() {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        migrations::run(&pool, MIGRATIONS).await.unwrap();
+
+        save_user_link(&pool, &link(1, "Foo")).await.unwrap();
+        let mut relink = link(1, "foo");
+        relink.summoner_tag = "euw".to_string();
+        save_user_link(&pool, &relink).await.unwrap();
+
+        let links = get_user_links(&pool, UserId::new(1)).await.unwrap();
+        assert_eq!(links.len(), 1);
+        assert_eq!(links[0].riot_id(), "foo#euw");
+    }
+}
@@ -0,0 +1,67 @@
+use sqlx::sqlite::SqlitePool;
+use sqlx::Row;
+use serenity::model::id::UserId;
+use super::models::LinkChallenge;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub async fn get_challenge(pool: &SqlitePool, user_id: UserId) -> Result<Option<LinkChallenge>, sqlx::Error> {
+    let row = sqlx::query(
+        "SELECT discord_user_id, riot_puuid, icon_id, expires_at
+         FROM link_challenges
+         WHERE discord_user_id = ?1"
+    )
+    .bind(user_id.to_string())
+    .fetch_optional(pool)
+    .await?;
+
+    Ok(row.map(|r| LinkChallenge {
+        discord_user_id: UserId::new(r.get::<String, _>("discord_user_id").parse().unwrap()),
+        riot_puuid: r.get("riot_puuid"),
+        icon_id: r.get("icon_id"),
+        expires_at: r.get("expires_at"),
+    }))
+}
+
+// A user has at most one pending challenge; starting a new one replaces it.
+pub async fn save_challenge(pool: &SqlitePool, challenge: &LinkChallenge) -> Result<(), sqlx::Error> {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64;
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM link_challenges WHERE expires_at <= ?1")
+        .bind(now)
+        .execute(&mut *tx)
+        .await?;
+
+    sqlx::query(
+        "INSERT INTO link_challenges (discord_user_id, riot_puuid, icon_id, expires_at)
+         VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(discord_user_id)
+         DO UPDATE SET
+            riot_puuid = excluded.riot_puuid,
+            icon_id = excluded.icon_id,
+            expires_at = excluded.expires_at"
+    )
+    .bind(challenge.discord_user_id.to_string())
+    .bind(&challenge.riot_puuid)
+    .bind(challenge.icon_id)
+    .bind(challenge.expires_at)
+    .execute(&mut *tx)
+    .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+pub async fn delete_challenge(pool: &SqlitePool, user_id: UserId) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM link_challenges WHERE discord_user_id = ?1")
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
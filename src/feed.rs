@@ -0,0 +1,364 @@
+use serenity::builder::{CreateEmbed, CreateEmbedFooter, CreateMessage};
+use serenity::model::id::{ChannelId, GuildId, UserId};
+use serenity::model::Timestamp;
+use serenity::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
+use crate::config::{ConfigMap, DatabaseContainer, RiotClientContainer};
+use crate::database::{Database, UserLink};
+use crate::i18n::{Locale, t};
+use crate::members;
+use crate::presentation::{self, LOSS_COLOR, WIN_COLOR};
+use crate::rank_history;
+use crate::riot::{LeagueEntry, MatchDto, Platform, RiotApiError, RiotClient, queue_name};
+
+pub const POLL_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+const MATCHES_PER_POLL: u32 = 5;
+// Remakes end before this and aren't worth a post.
+const MIN_GAME_SECS: i64 = 5 * 60;
+
+// Where one account's games get posted: one channel per guild, mentioning
+// the member who linked it there, in the guild's language.
+struct Audience {
+    channel_id: ChannelId,
+    user_id: UserId,
+    locale: Locale,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Announcement {
+    pub riot_id: String,
+    pub win: bool,
+    pub champion: String,
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub kda: f64,
+    pub creep_score: i32,
+    pub duration_secs: i64,
+    pub queue_id: i32,
+    pub lp_change: Option<i32>,
+    pub match_id: String,
+    pub ended_at: i64,
+}
+
+impl Announcement {
+    pub fn from_match(match_dto: &MatchDto, puuid: &str, riot_id: &str) -> Option<Self> {
+        let participant = match_dto.participant(puuid)?;
+        let info = &match_dto.info;
+
+        Some(Announcement {
+            riot_id: riot_id.to_string(),
+            win: participant.win,
+            champion: participant.champion_name.clone(),
+            kills: participant.kills,
+            deaths: participant.deaths,
+            assists: participant.assists,
+            kda: participant.kda(),
+            creep_score: participant.creep_score(),
+            duration_secs: info.game_duration,
+            queue_id: info.queue_id,
+            lp_change: None,
+            match_id: match_dto.metadata.match_id.clone(),
+            ended_at: info.game_end_timestamp.unwrap_or(info.game_creation) / 1000,
+        })
+    }
+
+    fn embed(&self, locale: Locale, user_id: UserId) -> CreateEmbed {
+        let (title, color) = if self.win {
+            (t!(locale, "feed-victory", riot_id = self.riot_id), WIN_COLOR)
+        } else {
+            (t!(locale, "feed-defeat", riot_id = self.riot_id), LOSS_COLOR)
+        };
+
+        let mut embed = CreateEmbed::new()
+            .title(title)
+            .color(color)
+            .description(format!(
+                "{}\n{}/{}/{} ({:.2} KDA) · {} CS · {}",
+                t!(locale, "feed-played", user = user_id, champion = self.champion),
+                self.kills,
+                self.deaths,
+                self.assists,
+                self.kda,
+                self.creep_score,
+                presentation::duration(self.duration_secs),
+            ))
+            .field(t!(locale, "feed-queue"), queue_name(self.queue_id), true)
+            .footer(CreateEmbedFooter::new(&self.match_id));
+
+        if let Some(lp_change) = self.lp_change {
+            let lp = t!(locale, "feed-lp");
+            embed = embed.field(&lp, format!("{:+} {}", lp_change, lp), true);
+        }
+        if let Ok(ended_at) = Timestamp::from_unix_timestamp(self.ended_at) {
+            embed = embed.timestamp(ended_at);
+        }
+
+        embed
+    }
+}
+
+// Riot lists match IDs newest first. Returns the ones after `last_seen`,
+// oldest first; if `last_seen` already scrolled out of the list, all of them.
+pub fn new_match_ids<'a>(ids: &'a [String], last_seen: &str) -> Vec<&'a str> {
+    let mut new: Vec<&str> = ids
+        .iter()
+        .take_while(|id| *id != last_seen)
+        .map(|id| id.as_str())
+        .collect();
+    new.reverse();
+    new
+}
+
+fn ranked_queue_type(queue_id: i32) -> Option<&'static str> {
+    match queue_id {
+        420 => Some(LeagueEntry::RANKED_SOLO),
+        440 => Some(LeagueEntry::RANKED_FLEX),
+        _ => None,
+    }
+}
+
+pub fn spawn_poller(ctx: Context) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(POLL_INTERVAL);
+
+        loop {
+            interval.tick().await;
+            poll(&ctx).await;
+        }
+    });
+}
+
+async fn poll(ctx: &Context) {
+    let (db, config_map, riot_client) = {
+        let data = ctx.data.read().await;
+        (
+            data.get::<DatabaseContainer>().expect("Database not found").clone(),
+            data.get::<ConfigMap>().expect("ConfigMap not found").clone(),
+            data.get::<RiotClientContainer>().expect("RiotClient not found").clone(),
+        )
+    };
+
+    let feeds: Vec<(GuildId, ChannelId, bool, Locale)> = config_map
+        .iter()
+        .filter_map(|entry| {
+            let locale = entry.language.unwrap_or_default();
+            Some((*entry.key(), entry.feed_channel?, entry.require_verified, locale))
+        })
+        .collect();
+    if feeds.is_empty() {
+        return;
+    }
+
+    let mut guild_members = Vec::with_capacity(feeds.len());
+    for (guild_id, channel_id, require_verified, locale) in feeds {
+        match members::fetch_all(&ctx.http, guild_id).await {
+            Ok(members) => {
+                let user_ids: HashSet<UserId> = members.iter().map(|member| member.user.id).collect();
+                guild_members.push((channel_id, require_verified, locale, user_ids));
+            }
+            Err(e) => eprintln!("Failed to fetch members of {} for the match feed: {}", guild_id, e),
+        }
+    }
+
+    let links = match db.get_all_links().await {
+        Ok(links) => links,
+        Err(e) => {
+            eprintln!("Failed to load links for the match feed: {}", e);
+            return;
+        }
+    };
+
+    let mut accounts: HashMap<String, Vec<UserLink>> = HashMap::new();
+    for link in links {
+        if let Some(puuid) = link.riot_puuid.clone() {
+            accounts.entry(puuid).or_default().push(link);
+        }
+    }
+
+    // Each account is polled once no matter how many guilds follow it.
+    for (puuid, links) in accounts {
+        let audience: Vec<Audience> = guild_members
+            .iter()
+            .filter_map(|(channel_id, require_verified, locale, user_ids)| {
+                let link = links
+                    .iter()
+                    .find(|link| user_ids.contains(&link.discord_user_id) && (link.verified || !require_verified))?;
+                Some(Audience { channel_id: *channel_id, user_id: link.discord_user_id, locale: *locale })
+            })
+            .collect();
+
+        if audience.is_empty() {
+            continue;
+        }
+
+        let platform = match Platform::from_game_region(&links[0].region) {
+            Some(platform) => platform,
+            None => continue,
+        };
+
+        if let Err(e) = poll_account(ctx, &db, &riot_client, &puuid, platform, &links[0], &audience).await {
+            eprintln!("Failed to poll matches for {}: {}", puuid, e);
+        }
+    }
+}
+
+async fn poll_account(
+    ctx: &Context,
+    db: &Database,
+    riot_client: &RiotClient,
+    puuid: &str,
+    platform: Platform,
+    link: &UserLink,
+    audience: &[Audience],
+) -> Result<(), RiotApiError> {
+    let ids = riot_client.get_match_ids_by_puuid(puuid, platform, MATCHES_PER_POLL, None).await?;
+    let newest = match ids.first() {
+        Some(newest) => newest,
+        None => return Ok(()),
+    };
+
+    let last_seen = match db.get_last_seen_match(puuid).await {
+        Ok(last_seen) => last_seen,
+        Err(e) => {
+            eprintln!("Failed to load match feed state for {}: {}", puuid, e);
+            return Ok(());
+        }
+    };
+
+    // A newly followed account starts from its latest game instead of
+    // posting its backlog.
+    let new_ids = match &last_seen {
+        Some(last_seen) => new_match_ids(&ids, last_seen),
+        None => Vec::new(),
+    };
+
+    let mut matches = Vec::with_capacity(new_ids.len());
+    for match_id in new_ids {
+        let match_dto = match db.get_cached_match(match_id).await {
+            Ok(Some(match_dto)) => match_dto,
+            _ => {
+                let match_dto = riot_client.get_match(match_id, platform).await?;
+                if let Err(e) = db.save_cached_match(&match_dto).await {
+                    eprintln!("Failed to cache match {}: {}", match_id, e);
+                }
+                match_dto
+            }
+        };
+        matches.push(match_dto);
+    }
+
+    let mut captured = false;
+    for match_dto in &matches {
+        if match_dto.info.game_duration < MIN_GAME_SECS {
+            continue;
+        }
+        let mut announcement = match Announcement::from_match(match_dto, puuid, &link.riot_id()) {
+            Some(announcement) => announcement,
+            None => continue,
+        };
+
+        // LP can only be pinned on a game when it's the only new one in
+        // its queue.
+        if let Some(queue_type) = ranked_queue_type(match_dto.info.queue_id) {
+            let same_queue = matches.iter().filter(|m| m.info.queue_id == match_dto.info.queue_id).count();
+            if same_queue == 1 {
+                if !captured {
+                    if let Err(e) = rank_history::capture(db, riot_client, puuid, platform).await {
+                        eprintln!("Failed to capture rank snapshot: {}", e);
+                    }
+                    captured = true;
+                }
+                announcement.lp_change = lp_change_since(db, puuid, queue_type, match_dto.info.game_creation / 1000).await;
+            }
+        }
+
+        for target in audience {
+            let message = CreateMessage::new().embed(announcement.embed(target.locale, target.user_id));
+            if let Err(e) = target.channel_id.send_message(&ctx.http, message).await {
+                eprintln!("Failed to post match {} in {}: {}", announcement.match_id, target.channel_id, e);
+            }
+        }
+    }
+
+    if last_seen.as_deref() == Some(newest.as_str()) {
+        return Ok(());
+    }
+    if let Err(e) = db.set_last_seen_match(puuid, newest).await {
+        eprintln!("Failed to save match feed state for {}: {}", puuid, e);
+    }
+
+    Ok(())
+}
+
+// Change from the last snapshot taken before the game started to the
+// latest one.
+async fn lp_change_since(db: &Database, puuid: &str, queue_type: &str, started_at: i64) -> Option<i32> {
+    let snapshots = match db.get_snapshots_since(puuid, queue_type, started_at).await {
+        Ok(snapshots) => snapshots,
+        Err(e) => {
+            eprintln!("Failed to load rank snapshots: {}", e);
+            return None;
+        }
+    };
+
+    let before = snapshots.first().filter(|s| s.captured_at < started_at)?;
+    let after = snapshots.last().filter(|s| s.captured_at >= started_at)?;
+    rank_history::summarize(&[before.clone(), after.clone()]).map(|progress| progress.lp_change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MATCH: &str = include_str!("../tests/fixtures/riot/match.json");
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_new_match_ids_oldest_first() {
+        let listed = ids(&["KR_5", "KR_4", "KR_3", "KR_2"]);
+
+        assert_eq!(new_match_ids(&listed, "KR_3"), vec!["KR_4", "KR_5"]);
+        assert!(new_match_ids(&listed, "KR_5").is_empty());
+        assert_eq!(new_match_ids(&listed, "KR_1").len(), 4);
+    }
+
+    #[test]
+    fn test_announcement_from_match() {
+        let match_dto: MatchDto = serde_json::from_str(MATCH).unwrap();
+        let puuid = "test-puuid-hide-on-bush";
+
+        let announcement = Announcement::from_match(&match_dto, puuid, "Hide on bush#KR1").unwrap();
+        assert!(announcement.win);
+        assert_eq!(announcement.champion, "Leblanc");
+        assert_eq!(announcement.creep_score, 243);
+        assert_eq!(announcement.lp_change, None);
+
+        assert!(Announcement::from_match(&match_dto, "someone-else", "x#y").is_none());
+    }
+
+    #[test]
+    fn test_embed_uses_the_guild_language() {
+        let match_dto: MatchDto = serde_json::from_str(MATCH).unwrap();
+        let mut announcement = Announcement::from_match(&match_dto, "test-puuid-hide-on-bush", "Hide on bush#KR1").unwrap();
+        announcement.lp_change = Some(18);
+
+        let embed = serde_json::to_value(announcement.embed(Locale::Pt, UserId::new(7))).unwrap();
+        assert_eq!(embed["title"], "✅ Vitória — Hide on bush#KR1");
+        assert!(embed["description"].as_str().unwrap().starts_with("<@7> jogou de **Leblanc**\n"));
+        assert_eq!(embed["fields"][1]["name"], "PDL");
+        assert_eq!(embed["fields"][1]["value"], "+18 PDL");
+    }
+
+    #[test]
+    fn test_only_ranked_queues_track_lp() {
+        assert_eq!(ranked_queue_type(420), Some(LeagueEntry::RANKED_SOLO));
+        assert_eq!(ranked_queue_type(440), Some(LeagueEntry::RANKED_FLEX));
+        assert_eq!(ranked_queue_type(450), None);
+    }
+}
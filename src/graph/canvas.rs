@@ -0,0 +1,143 @@
+use super::font;
+
+pub type Rgb = [u8; 3];
+
+pub const fn rgb(hex: u32) -> Rgb {
+    [(hex >> 16) as u8, (hex >> 8) as u8, hex as u8]
+}
+
+// Mixes `top` over `base`; `alpha` is out of 255.
+pub fn blend(base: Rgb, top: Rgb, alpha: u8) -> Rgb {
+    let mix = |b: u8, t: u8| ((b as u32 * (255 - alpha as u32) + t as u32 * alpha as u32) / 255) as u8;
+    [mix(base[0], top[0]), mix(base[1], top[1]), mix(base[2], top[2])]
+}
+
+// An RGB pixel buffer with just enough drawing primitives for charts.
+// Coordinates are signed so shapes can be clipped at the edges.
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    pub fn new(width: u32, height: u32, background: Rgb) -> Self {
+        let pixels = background.repeat((width * height) as usize);
+        Canvas { width, height, pixels }
+    }
+
+    pub fn set(&mut self, x: i32, y: i32, color: Rgb) {
+        if x < 0 || y < 0 || x >= self.width as i32 || y >= self.height as i32 {
+            return;
+        }
+        let i = (y as usize * self.width as usize + x as usize) * 3;
+        self.pixels[i..i + 3].copy_from_slice(&color);
+    }
+
+    pub fn fill_rect(&mut self, x: i32, y: i32, w: i32, h: i32, color: Rgb) {
+        for py in y..y + h {
+            for px in x..x + w {
+                self.set(px, py, color);
+            }
+        }
+    }
+
+    // Bresenham with a square brush of `thickness` pixels.
+    pub fn line(&mut self, x0: i32, y0: i32, x1: i32, y1: i32, thickness: i32, color: Rgb) {
+        let (dx, dy) = ((x1 - x0).abs(), -(y1 - y0).abs());
+        let (sx, sy) = (if x0 < x1 { 1 } else { -1 }, if y0 < y1 { 1 } else { -1 });
+        let (mut x, mut y, mut err) = (x0, y0, dx + dy);
+        let offset = thickness / 2;
+
+        loop {
+            self.fill_rect(x - offset, y - offset, thickness, thickness, color);
+            if x == x1 && y == y1 {
+                break;
+            }
+            let e2 = 2 * err;
+            if e2 >= dy {
+                err += dy;
+                x += sx;
+            }
+            if e2 <= dx {
+                err += dx;
+                y += sy;
+            }
+        }
+    }
+
+    pub fn fill_circle(&mut self, cx: i32, cy: i32, radius: i32, color: Rgb) {
+        for y in -radius..=radius {
+            for x in -radius..=radius {
+                if x * x + y * y <= radius * radius {
+                    self.set(cx + x, cy + y, color);
+                }
+            }
+        }
+    }
+
+    // Draws from the top-left corner and returns the width used.
+    pub fn text(&mut self, x: i32, y: i32, text: &str, scale: u32, color: Rgb) -> i32 {
+        let mut cursor = x;
+        for c in text.chars() {
+            let rows = font::glyph(c);
+            for (row, bits) in rows.iter().enumerate() {
+                for col in 0..font::WIDTH {
+                    if bits & (1 << (font::WIDTH - 1 - col)) != 0 {
+                        let px = cursor + (col * scale) as i32;
+                        let py = y + (row as u32 * scale) as i32;
+                        self.fill_rect(px, py, scale as i32, scale as i32, color);
+                    }
+                }
+            }
+            cursor += ((font::WIDTH + 1) * scale) as i32;
+        }
+        cursor - x
+    }
+
+    pub fn text_width(text: &str, scale: u32) -> i32 {
+        (text.chars().count() as u32 * (font::WIDTH + 1) * scale) as i32
+    }
+
+    pub fn text_height(scale: u32) -> i32 {
+        (font::HEIGHT * scale) as i32
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, png::EncodingError> {
+        let mut bytes = Vec::new();
+        {
+            let mut encoder = png::Encoder::new(&mut bytes, self.width, self.height);
+            encoder.set_color(png::ColorType::Rgb);
+            encoder.set_depth(png::BitDepth::Eight);
+            let mut writer = encoder.write_header()?;
+            writer.write_image_data(&self.pixels)?;
+        }
+        Ok(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_drawing_clips_at_edges() {
+        let mut canvas = Canvas::new(4, 4, rgb(0x000000));
+        canvas.fill_rect(-2, -2, 10, 3, rgb(0xFFFFFF));
+        canvas.set(100, 100, rgb(0xFFFFFF));
+
+        assert_eq!(&canvas.pixels[0..3], &[255, 255, 255]);
+        assert_eq!(&canvas.pixels[4 * 3..4 * 3 + 3], &[0, 0, 0]);
+    }
+
+    #[test]
+    fn test_line_reaches_both_ends() {
+        let mut canvas = Canvas::new(10, 10, rgb(0x000000));
+        canvas.line(1, 8, 8, 1, 1, rgb(0xFF0000));
+
+        let at = |x: usize, y: usize| canvas.pixels[(y * 10 + x) * 3];
+        assert_eq!(at(1, 8), 255);
+        assert_eq!(at(8, 1), 255);
+        assert_eq!(at(4, 5), 255);
+    }
+}
@@ -0,0 +1,244 @@
+mod canvas;
+mod font;
+
+pub use canvas::Canvas;
+
+use canvas::{blend, rgb, Rgb};
+use chrono::{TimeZone, Utc};
+use crate::riot::Tier;
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 400;
+const MARGIN_LEFT: i32 = 112;
+const MARGIN_RIGHT: i32 = 24;
+const MARGIN_TOP: i32 = 44;
+const MARGIN_BOTTOM: i32 = 36;
+
+const BACKGROUND: Rgb = rgb(0x2B2D31);
+const TEXT: Rgb = rgb(0xDBDEE1);
+const MUTED: Rgb = rgb(0x949BA4);
+const SERIES: Rgb = rgb(0xFFFFFF);
+const PROMOTION: Rgb = rgb(0x57F287);
+const DEMOTION: Rgb = rgb(0xED4245);
+
+// Matches the ladder used by rank_history: 400 per tier, 100 per division,
+// with every apex tier sharing the band that starts at Master.
+const TIER_POINTS: i32 = 400;
+const DIVISION_POINTS: i32 = 100;
+const APEX_BAND: usize = 7;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Marker {
+    Promotion,
+    Demotion,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LpPoint {
+    pub time: i64,
+    pub points: i32,
+    pub marker: Option<Marker>,
+}
+
+pub struct LpChart<'a> {
+    pub title: &'a str,
+    pub points: &'a [LpPoint],
+}
+
+pub fn render_png(chart: &LpChart) -> Result<Vec<u8>, png::EncodingError> {
+    render(chart).to_png()
+}
+
+pub fn render(chart: &LpChart) -> Canvas {
+    let mut canvas = Canvas::new(WIDTH, HEIGHT, BACKGROUND);
+    let plot = Plot::fit(chart.points);
+
+    draw_bands(&mut canvas, &plot);
+    draw_series(&mut canvas, &plot, chart.points);
+
+    canvas.text(MARGIN_LEFT, 14, &chart.title.to_uppercase(), 2, TEXT);
+    if let (Some(first), Some(last)) = (chart.points.first(), chart.points.last()) {
+        let y = HEIGHT as i32 - MARGIN_BOTTOM + 12;
+        canvas.text(MARGIN_LEFT, y, &date_label(first.time), 2, MUTED);
+        if last.time != first.time {
+            let label = date_label(last.time);
+            let x = WIDTH as i32 - MARGIN_RIGHT - Canvas::text_width(&label, 2);
+            canvas.text(x, y, &label, 2, MUTED);
+        }
+    }
+
+    canvas
+}
+
+// Maps ladder points and timestamps onto the plot area.
+struct Plot {
+    low: i32,
+    high: i32,
+    start: i64,
+    end: i64,
+}
+
+impl Plot {
+    // Pads the data range and snaps it to whole divisions so the chart
+    // always shows at least two of them.
+    fn fit(points: &[LpPoint]) -> Self {
+        let min = points.iter().map(|p| p.points).min().unwrap_or(0);
+        let max = points.iter().map(|p| p.points).max().unwrap_or(0);
+
+        let low = ((min - 50).max(0) / DIVISION_POINTS) * DIVISION_POINTS;
+        let mut high = ((max + 50 + DIVISION_POINTS - 1) / DIVISION_POINTS) * DIVISION_POINTS;
+        if high - low < 2 * DIVISION_POINTS {
+            high = low + 2 * DIVISION_POINTS;
+        }
+
+        Plot {
+            low,
+            high,
+            start: points.first().map_or(0, |p| p.time),
+            end: points.last().map_or(0, |p| p.time),
+        }
+    }
+
+    fn width() -> i32 {
+        WIDTH as i32 - MARGIN_LEFT - MARGIN_RIGHT
+    }
+
+    fn height() -> i32 {
+        HEIGHT as i32 - MARGIN_TOP - MARGIN_BOTTOM
+    }
+
+    fn y(&self, points: i32) -> i32 {
+        let points = points.clamp(self.low, self.high);
+        MARGIN_TOP + ((self.high - points) as i64 * Plot::height() as i64 / (self.high - self.low) as i64) as i32
+    }
+
+    fn x(&self, time: i64) -> i32 {
+        if self.end <= self.start {
+            return MARGIN_LEFT + Plot::width() / 2;
+        }
+        MARGIN_LEFT + ((time - self.start) * Plot::width() as i64 / (self.end - self.start)) as i32
+    }
+}
+
+fn draw_bands(canvas: &mut Canvas, plot: &Plot) {
+    for (band, tier) in Tier::ALL.iter().enumerate().take(APEX_BAND + 1) {
+        let bottom = band as i32 * TIER_POINTS;
+        let top = if band == APEX_BAND { i32::MAX } else { bottom + TIER_POINTS };
+        if top <= plot.low || bottom >= plot.high {
+            continue;
+        }
+
+        let (y_top, y_bottom) = (plot.y(top.min(plot.high)), plot.y(bottom.max(plot.low)));
+        let color = rgb(tier.color());
+        canvas.fill_rect(MARGIN_LEFT, y_top, Plot::width(), y_bottom - y_top, blend(BACKGROUND, color, 56));
+
+        let label = if band == APEX_BAND { "MASTER+" } else { tier.as_str() };
+        if y_bottom - y_top >= Canvas::text_height(2) + 4 {
+            let y = (y_top + y_bottom - Canvas::text_height(2)) / 2;
+            canvas.text(8, y, label, 2, color);
+        }
+    }
+
+    // Division lines are faint; tier boundaries stand out.
+    let apex = APEX_BAND as i32 * TIER_POINTS;
+    for points in (plot.low..=plot.high).step_by(DIVISION_POINTS as usize) {
+        if points > apex {
+            break;
+        }
+        let alpha = if points % TIER_POINTS == 0 { 140 } else { 40 };
+        let y = plot.y(points);
+        canvas.fill_rect(MARGIN_LEFT, y, Plot::width(), 1, blend(BACKGROUND, MUTED, alpha));
+    }
+}
+
+fn draw_series(canvas: &mut Canvas, plot: &Plot, points: &[LpPoint]) {
+    let positions: Vec<(i32, i32)> = points.iter().map(|p| (plot.x(p.time), plot.y(p.points))).collect();
+
+    for pair in positions.windows(2) {
+        canvas.line(pair[0].0, pair[0].1, pair[1].0, pair[1].1, 2, SERIES);
+    }
+
+    for (point, &(x, y)) in points.iter().zip(&positions) {
+        match point.marker {
+            Some(Marker::Promotion) => canvas.fill_circle(x, y, 5, PROMOTION),
+            Some(Marker::Demotion) => canvas.fill_circle(x, y, 5, DEMOTION),
+            None => canvas.fill_circle(x, y, 2, SERIES),
+        }
+    }
+}
+
+fn date_label(time: i64) -> String {
+    Utc.timestamp_opt(time, 0)
+        .single()
+        .map(|date| date.format("%b %d").to_string().to_uppercase())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SNAPSHOT: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/graph/lp_chart.png");
+    const DAY: i64 = 24 * 60 * 60;
+    const START: i64 = 1_760_000_000;
+
+    fn sample_points() -> Vec<LpPoint> {
+        // Gold II climbing into Platinum IV, then dropping back out.
+        [
+            (0, 1_472, None),
+            (1, 1_530, Some(Marker::Promotion)),
+            (2, 1_551, None),
+            (3, 1_590, None),
+            (4, 1_612, Some(Marker::Promotion)),
+            (5, 1_640, None),
+            (6, 1_595, Some(Marker::Demotion)),
+            (7, 1_621, Some(Marker::Promotion)),
+        ]
+        .into_iter()
+        .map(|(day, points, marker)| LpPoint { time: START + day * DAY, points, marker })
+        .collect()
+    }
+
+    fn decode(bytes: &[u8]) -> (u32, u32, Vec<u8>) {
+        let mut reader = png::Decoder::new(bytes).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).unwrap();
+        pixels.truncate(info.buffer_size());
+        (info.width, info.height, pixels)
+    }
+
+    // Regenerate with `UPDATE_SNAPSHOTS=1 cargo test` after intended changes.
+    // Pixels are compared rather than bytes so encoder updates don't matter.
+    #[test]
+    fn test_render_matches_snapshot() {
+        let points = sample_points();
+        let png = render_png(&LpChart { title: "Faker#KR1 - Solo/Duo", points: &points }).unwrap();
+
+        if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+            std::fs::write(SNAPSHOT, &png).unwrap();
+        }
+
+        let expected = std::fs::read(SNAPSHOT).expect("missing graph snapshot");
+        assert!(decode(&png) == decode(&expected), "graph differs from {}", SNAPSHOT);
+    }
+
+    #[test]
+    fn test_plot_range_snaps_to_divisions() {
+        let plot = Plot::fit(&sample_points());
+        assert_eq!((plot.low, plot.high), (1_400, 1_700));
+        assert_eq!(plot.y(1_700), MARGIN_TOP);
+        assert_eq!(plot.y(1_400), HEIGHT as i32 - MARGIN_BOTTOM);
+
+        let flat = Plot::fit(&[LpPoint { time: START, points: 0, marker: None }]);
+        assert_eq!((flat.low, flat.high), (0, 200));
+        assert_eq!(flat.x(START), MARGIN_LEFT + Plot::width() / 2);
+    }
+
+    #[test]
+    fn test_png_output_is_decodable() {
+        let points = sample_points();
+        let (width, height, pixels) = decode(&render_png(&LpChart { title: "", points: &points }).unwrap());
+        assert_eq!((width, height), (WIDTH, HEIGHT));
+        assert_eq!(&pixels[..3], &BACKGROUND);
+    }
+}
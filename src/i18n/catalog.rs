@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+// The subset of Fluent our catalogs use: `key = text` messages, indented
+// continuation lines for multi-line text, `#` comments, and `{ $name }`
+// placeholders.
+#[derive(Debug, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn parse(source: &str) -> Self {
+        let mut messages = HashMap::new();
+        let mut current: Option<(String, String)> = None;
+
+        for line in source.lines() {
+            if line.starts_with(' ') && !line.trim().is_empty() {
+                if let Some((_, value)) = current.as_mut() {
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+                    value.push_str(line.trim());
+                }
+                continue;
+            }
+
+            if let Some((key, value)) = current.take() {
+                messages.insert(key, value);
+            }
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, value)) = line.split_once('=') {
+                current = Some((key.trim().to_string(), value.trim().to_string()));
+            }
+        }
+        if let Some((key, value)) = current {
+            messages.insert(key, value);
+        }
+
+        Catalog { messages }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.messages.len()
+    }
+
+    #[cfg(test)]
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+}
+
+// Unknown placeholders are left as written.
+pub fn format(pattern: &str, args: &[(&str, String)]) -> String {
+    let mut formatted = String::with_capacity(pattern.len());
+    let mut rest = pattern;
+
+    while let Some(start) = rest.find('{') {
+        formatted.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            break;
+        };
+
+        let placeholder = &rest[start..start + end + 1];
+        let name = placeholder[1..placeholder.len() - 1].trim().trim_start_matches('$');
+        match args.iter().find(|(arg, _)| *arg == name) {
+            Some((_, value)) => formatted.push_str(value),
+            None => formatted.push_str(placeholder),
+        }
+        rest = &rest[start + end + 1..];
+    }
+    formatted.push_str(rest);
+
+    formatted
+}
+
+#[cfg(test)]
+pub fn placeholders(pattern: &str) -> impl Iterator<Item = &str> {
+    pattern
+        .split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .map(|(name, _)| name.trim().trim_start_matches('$'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_multiline_and_comments() {
+        let catalog = Catalog::parse(
+            "# Greetings\n\
+             hello = Hello, { $name }!\n\
+             \n\
+             usage = Usage: `help`\n    Second line\n\
+             empty =\n    Only continuation\n",
+        );
+
+        assert_eq!(catalog.get("hello"), Some("Hello, { $name }!"));
+        assert_eq!(catalog.get("usage"), Some("Usage: `help`\nSecond line"));
+        assert_eq!(catalog.get("empty"), Some("Only continuation"));
+        assert_eq!(catalog.len(), 3);
+    }
+
+    #[test]
+    fn test_format() {
+        let args = [("name", "Faker".to_string()), ("tag", "KR1".to_string())];
+        assert_eq!(format("**{ $name }#{$tag}**", &args), "**Faker#KR1**");
+        assert_eq!(format("{ $missing } stays", &args), "{ $missing } stays");
+        assert_eq!(format("no placeholders", &args), "no placeholders");
+    }
+}
@@ -0,0 +1,300 @@
+# Shared
+
+server-only = This command only works in servers!
+config-save-failed = ❌ Failed to save the settings, nothing was changed. Please try again later.
+riot-id-invalid = Invalid Riot ID format. Use `Name#TAG` (e.g., `Faker#KR1`)
+region-invalid = Invalid region: `{ $region }`. Valid regions: { $regions }
+rate-limited = Rate limited by Riot API. Please try again in a moment.
+api-unauthorized = ❌ API authentication error. Please contact the bot administrator.
+no-linked-account = You don't have a linked LoL account.
+no-linked-account-hint =
+    You don't have a linked LoL account.
+    Use `{ $prefix }link <Name#TAG> <region>` to link one.
+
+member-no-linked-account = That user doesn't have a linked LoL account.
+member-link-unverified = That user's linked account is not verified.
+riot-not-found = ❌ No League of Legends data found for that account.
+riot-request-failed = ❌ Riot API request failed: { $error }. Please try again later.
+cache-updated-minute = Updated 1 minute ago
+cache-updated-minutes = Updated { $count } minutes ago
+cache-updated-hours = Updated { $count } hours ago
+
+duration-days = { $count }d
+duration-hours = { $count }h
+duration-minutes = { $count }m
+duration-seconds = { $count }s
+
+# Accounts
+
+links-load-failed = Failed to retrieve your links. Please try again later.
+link-load-failed = Failed to retrieve your link. Please try again later.
+link-limit = ❌ You can link at most { $max } accounts. Use `{ $prefix }unlink <Name#TAG>` to remove one first.
+link-verifying = Verifying summoner with Riot API...
+link-not-found = ❌ Summoner **{ $riot_id }** not found in region **{ $region }**. Please check the name and tag.
+link-verify-failed = ❌ Failed to verify summoner: { $error }. Please try again later
+link-save-failed = ❌ Failed to save your link. Please try again later.
+link-primary = ✅ Linked your account to **{ $riot_id }** in region **{ $region }**
+link-added = ✅ Added **{ $riot_id }** in region **{ $region }**. Use `{ $prefix }main { $riot_id }` to make it your default account.
+unlink-check-failed = Failed to check your link. Please try again later.
+unlink-choose =
+    You have several linked accounts. Use `{ $prefix }unlink <Name#TAG>` to pick one:
+    { $accounts }
+unlink-done = ✅ **{ $riot_id }** has been unlinked.
+unlink-not-linked = **{ $riot_id }** is not one of your linked accounts.
+unlink-failed = Failed to unlink your account. Please try again later.
+main-done = ✅ **{ $riot_id }** is now your main account.
+main-not-linked = **{ $riot_id }** is not one of your linked accounts. Use `{ $prefix }link <Name#TAG> <region>` to add it.
+main-failed = Failed to update your main account. Please try again later.
+linked-accounts-one = Your linked account
+linked-accounts-many = Your linked accounts
+verify-not-linked = That is not one of your linked accounts. Use `{ $prefix }link <Name#TAG> <region>` first.
+verify-already = ✅ **{ $riot_id }** is already verified.
+verify-checking = Checking your profile icon with Riot API...
+verify-check-failed = ❌ Failed to check your profile: { $error }. Please try again later
+verify-load-failed = Failed to check your verification. Please try again later.
+verify-icon-mismatch =
+    Your profile icon is still **#{ $current }**. Change it to **#{ $icon }** and run `{ $prefix }verify` again within { $minutes } minutes.
+    { $url }
+verify-save-failed = ❌ Failed to save your verification. Please try again later.
+verify-done = ✅ **{ $riot_id }** is now verified. You can change your icon back.
+verify-start-failed = ❌ Failed to start verification. Please try again later.
+verify-challenge =
+    To prove you own **{ $riot_id }**, change its profile icon to **#{ $icon }** in the League client, then run `{ $prefix }verify` again within { $minutes } minutes.
+    { $url }
+
+# Languages
+
+language-current = This server's language is **{ $language }**.
+language-unset = No server language is set. Replies follow each member's Discord language, or English.
+language-invalid = Unknown language `{ $value }`. Available: { $languages }
+language-set = ✅ This server's language is now **{ $language }**.
+language-reset = ✅ Server language removed. Replies follow each member's Discord language.
+my-language-current = Your language is **{ $language }**.
+my-language-unset = You have no language of your own set. Replies use this server's language.
+my-language-set = ✅ Your language is now **{ $language }**.
+my-language-reset = ✅ Your own language was removed.
+my-language-failed = Failed to save your language. Please try again later.
+
+# Server settings
+
+prefix-current = Current prefix: `{ $prefix }`
+prefix-changed = Prefix changed to: `{ $prefix }`
+admin-role-current = Bot admin role: <@&{ $role }>
+admin-role-none = No bot admin role set. Only members with **Manage Server** can change settings.
+admin-role-invalid = Invalid role: `{ $value }`. Mention a role or use `off`.
+admin-role-set = ✅ Members with <@&{ $role }> can now use admin commands.
+admin-role-removed = ✅ Bot admin role removed.
+verification-required-status = Ranked commands only accept verified accounts in this server.
+verification-optional-status = Ranked commands accept any linked account in this server.
+verification-invalid = Invalid mode: `{ $value }`. Use `required` or `optional`.
+verification-required-set = ✅ Ranked commands now require a verified account. Members can use `verify` to verify theirs.
+verification-optional-set = ✅ Ranked commands now accept any linked account.
+feed-usage = Usage: `{ $prefix }feed set #channel` or `{ $prefix }feed off`
+feed-status = Finished games of linked members are posted in <#{ $channel }>.
+feed-off-status = The match feed is off.
+feed-foreign-channel = That channel is not in this server.
+feed-set = ✅ Finished games of linked members will be posted in <#{ $channel }>.
+feed-disabled = ✅ Match feed turned off.
+rank-roles-checking = Checking members' ranks...
+rank-roles-sync-failed = ❌ Failed to sync rank roles. Make sure the bot can see the member list.
+rank-roles-usage =
+    Usage: `{ $prefix }rankroles [set <tier> <@role> | remove <tier> | sync | dryrun]`
+    Tiers: { $tiers }
+rank-roles-none = No rank roles configured.
+rank-roles-title = Rank roles (Solo/Duo)
+rank-roles-set = ✅ Members ranked **{ $tier }** will get <@&{ $role }>. Run `{ $prefix }rankroles sync` to apply it now.
+rank-roles-not-mapped = No role is mapped to **{ $tier }**.
+rank-roles-removed = ✅ **{ $tier }** no longer has a rank role.
+rank-roles-report = **Rank role sync:** checked { $checked } members, { $changes } changed.
+rank-roles-report-dry-run = **Rank role dry run:** checked { $checked } members, { $changes } would change.
+rank-roles-report-more = …and { $count } more
+rank-roles-report-errors = ⚠️ { $count } members could not be checked or updated. Check that the bot has **Manage Roles** and its role is above the rank roles.
+cooldown-unknown-command = Unknown command `{ $command }`.
+cooldown-none = No command cooldowns are active in this server.
+cooldown-title = Command cooldowns
+cooldown-invalid-scope = Invalid scope: `{ $value }`. Use `user`, `guild` or `reset`.
+cooldown-usage = Usage: `{ $prefix }cooldown <command> <user | guild> <seconds> [burst]` (seconds 0-{ $max })
+cooldown-burst-invalid = Burst must be a positive number.
+cooldown-limit = { $burst } per { $window } per { $scope }
+cooldown-no-limit = no { $scope } limit
+cooldown-scope-user = user
+cooldown-scope-guild = server
+
+# Commands
+
+unknown-command-suggestion = Unknown command `{ $command }`. Did you mean `{ $suggestion }`?
+usage-example = Example: `{ $example }`
+cooldown-user-wait = ⏳ Please wait { $seconds }s before using `{ $command }` again.
+cooldown-guild-wait = ⏳ `{ $command }` is on cooldown in this server. Try again in { $seconds }s.
+permission-denied = ❌ You need the **{ $permissions }** permission to use `{ $command }`.
+permission-denied-role = ❌ You need the **{ $permissions }** permission or the <@&{ $role }> role to use `{ $command }`.
+verified-account-required = This server only accepts verified accounts. Use `{ $prefix }verify` to verify **{ $riot_id }**.
+link-outdated = Your link is outdated. Please run `{ $prefix }link` again.
+unranked = Unranked
+queue-solo = Ranked Solo/Duo
+queue-flex = Ranked Flex
+
+# Leaderboard
+
+leaderboard-queue-invalid = Invalid queue: `{ $value }`. Use `solo` or `flex`.
+leaderboard-building = ⏳ The leaderboard is being built. Please try again in a minute.
+leaderboard-title = **🏆 { $queue } leaderboard** (page { $page }/{ $pages })
+leaderboard-empty = Nobody here has a ranked linked account yet. Use `link` to join!
+leaderboard-unranked = _{ $count } linked members are unranked_
+leaderboard-updated = _Updated { $minutes } min ago_
+
+# Progress
+
+progress-invalid-argument =
+    Invalid argument: `{ $value }`.
+    Usage: `{ $prefix }progress [day | week | season] [solo | flex]`
+progress-history-failed = Failed to load your rank history. Please try again later.
+progress-no-history = No { $queue } history for **{ $riot_id }** yet. Ranks are recorded every hour, check back after a few games.
+progress-summary =
+    **{ $trend } { $riot_id } — { $queue }, { $window }**
+    { $start } → { $end } (**{ $lp_change } LP**)
+    ⬆️ { $promotions } promotions · ⬇️ { $demotions } demotions
+    🎮 { $games } games: { $wins }W { $losses }L ({ $win_rate }%)
+window-day = last 24 hours
+window-week = last 7 days
+window-season = this season
+
+# Rank
+
+rank-usage =
+    Usage: `{ $prefix }rank [@user | Name#TAG region]`
+    Example: `{ $prefix }rank Faker#KR1 kr`
+profile-level = Level { $level }
+league-entry =
+    { $standing } — { $lp } LP
+    { $wins }W { $losses }L ({ $win_rate }%)
+hot-streak = 🔥 Hot streak
+
+# Match history
+
+history-invalid-argument =
+    Invalid argument: `{ $value }`.
+    Usage: `{ $prefix }history [count] [queue]` (count 1-{ $max }, queue: { $queues })
+history-empty = No recent matches found.
+history-load-failed = Failed to load match history. Please try again later.
+history-title = Recent matches for { $riot_id }
+
+# Match feed
+
+feed-victory = ✅ Victory — { $riot_id }
+feed-defeat = ❌ Defeat — { $riot_id }
+feed-played = <@{ $user }> played **{ $champion }**
+feed-queue = Queue
+feed-lp = LP
+
+# Live games
+
+live-usage = Usage: `{ $prefix }live [@user]`
+live-not-in-game = **{ $riot_id }** is not in a game right now.
+live-looking-up = 🔎 **{ $riot_id }** is in game, looking up ranks...
+live-header = 🔴 **{ $riot_id }** is in game — { $queue } · { $time }
+live-custom-game = Custom
+live-blue-team = 🔵 **Blue team**
+live-red-team = 🔴 **Red team**
+live-bot = Bot
+live-unknown-player = Unknown
+
+# Mastery
+
+mastery-unknown-champion = Unknown champion: `{ $champion }`.
+mastery-champion-title = **{ $champion } mastery for { $riot_id }**
+mastery-never-played = **{ $riot_id }** has never played **{ $champion }**.
+mastery-none = **{ $riot_id }** has no champion mastery yet.
+mastery-top-title = **Top champions for { $riot_id }**
+mastery-line = **{ $champion }** — Level { $level } · { $points } pts · last played <t:{ $last_played }:R>
+
+# Graph
+
+graph-invalid-argument =
+    Invalid argument: `{ $value }`.
+    Usage: `{ $prefix }graph [@user] [days]` (1-{ $max } days)
+graph-history-failed = Failed to load the rank history. Please try again later.
+graph-no-history = Not enough { $queue } history for **{ $riot_id }** yet. Ranks are recorded every hour, check back after a few games.
+graph-draw-failed = Failed to draw the graph. Please try again later.
+graph-summary =
+    📈 **{ $riot_id }** — { $queue }, last { $days } days
+    { $summary }
+
+# Help
+
+pong = Pong!
+help-unknown = Unknown command `{ $command }`. Use `{ $prefix }help` to see all commands.
+help-header = Available commands:
+help-footer =
+    Regions: { $regions }
+    Use `{ $prefix }help <command>` for details on a command.
+    Tip: Use quotes for multi-word arguments: `{ $prefix }command "multi word arg"`
+    All commands are also available as slash commands, e.g. `/rank`
+help-usage = Usage: `{ $usage }`
+help-aliases = Aliases: { $aliases }
+help-requires = Requires: **{ $permissions }** or the bot admin role
+help-arguments = Arguments:
+help-optional = (optional)
+help-examples = Examples: { $examples }
+
+category-general = General
+category-config = Server settings
+category-account = Account
+category-stats = Stats
+
+command-ping = Responds with Pong!
+command-help = Shows the list of commands, or details about one command
+command-prefix = View or set the command prefix for this server
+command-adminrole = View or set the role allowed to change bot settings
+command-cooldown = View or override command cooldowns for this server
+command-verification = View or set whether ranked commands need verified accounts
+command-feed = Post linked members' finished games in a channel
+command-rankroles = Map Solo/Duo tiers to roles that the bot keeps in sync
+command-language = View or set the language the bot replies in on this server
+command-link = Link a LoL account to your Discord account
+command-unlink = Remove one of your linked LoL accounts
+command-main = Choose which linked account other commands use
+command-verify = Prove you own a linked account by changing its profile icon
+command-me = Show your linked LoL accounts
+command-mylanguage = View or set the language the bot replies to you in
+command-rank = Show Solo/Duo and Flex ranks
+command-history = Show your recent matches
+command-live = Show a linked player's current game
+command-mastery = Show champion mastery, or one champion's
+command-progress = Show LP gained or lost, promotions and win rate over time
+command-graph = Draw a Solo/Duo LP graph from recorded ranks
+command-leaderboard = Rank this server's linked members
+
+arg-help-command = A command to explain
+arg-prefix-new-prefix = The new prefix
+arg-adminrole-role = The bot admin role, or off
+arg-cooldown-command = The command to configure
+arg-cooldown-scope = Which limit to change
+arg-cooldown-seconds = Window length, 0 disables the limit
+arg-cooldown-burst = Uses allowed per window (default 1)
+arg-verification-mode = Whether linked accounts must be verified
+arg-feed-action = Turn the feed on or off
+arg-feed-channel = Where to post games
+arg-rankroles-action = What to do
+arg-rankroles-tier = The ranked tier
+arg-rankroles-role = The role for that tier
+arg-language-language = The server language, or reset
+arg-link-riot-id = Your Riot ID, e.g. Faker#KR1
+arg-link-region = Your server
+arg-unlink-riot-id = The account to remove
+arg-main-riot-id = One of your linked accounts
+arg-verify-riot-id = The account to verify (default: your main)
+arg-mylanguage-language = Your language, or reset
+arg-rank-user = A member with a linked account
+arg-rank-riot-id = A Riot ID, e.g. Faker#KR1
+arg-rank-region = Server of the Riot ID
+arg-history-count = Number of matches
+arg-history-queue = Only show this queue
+arg-live-user = A member with a linked account
+arg-mastery-user = A member with a linked account
+arg-mastery-champion = Only show this champion
+arg-progress-window = Time range (default: week)
+arg-progress-queue = Which ranked queue
+arg-graph-user = A member with a linked account
+arg-graph-days = How far back to go (default: 30)
+arg-leaderboard-queue = Which ranked queue
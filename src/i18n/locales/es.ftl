@@ -0,0 +1,300 @@
+# Shared
+
+server-only = ¡Este comando solo funciona en servidores!
+config-save-failed = ❌ No se pudo guardar la configuración, no se cambió nada. Inténtalo de nuevo más tarde.
+riot-id-invalid = Formato de Riot ID no válido. Usa `Nombre#TAG` (p. ej., `Faker#KR1`)
+region-invalid = Región no válida: `{ $region }`. Regiones válidas: { $regions }
+rate-limited = La API de Riot nos está limitando. Inténtalo de nuevo en un momento.
+api-unauthorized = ❌ Error de autenticación con la API. Contacta con el administrador del bot.
+no-linked-account = No tienes ninguna cuenta de LoL vinculada.
+no-linked-account-hint =
+    No tienes ninguna cuenta de LoL vinculada.
+    Usa `{ $prefix }link <Nombre#TAG> <región>` para vincular una.
+
+member-no-linked-account = Ese usuario no tiene una cuenta de LoL vinculada.
+member-link-unverified = La cuenta vinculada de ese usuario no está verificada.
+riot-not-found = ❌ No se encontraron datos de League of Legends para esa cuenta.
+riot-request-failed = ❌ Falló la solicitud a la API de Riot: { $error }. Inténtalo de nuevo más tarde.
+cache-updated-minute = Actualizado hace 1 minuto
+cache-updated-minutes = Actualizado hace { $count } minutos
+cache-updated-hours = Actualizado hace { $count } horas
+
+duration-days = { $count } d
+duration-hours = { $count } h
+duration-minutes = { $count } min
+duration-seconds = { $count } s
+
+# Accounts
+
+links-load-failed = No se pudieron obtener tus cuentas. Inténtalo de nuevo más tarde.
+link-load-failed = No se pudo obtener tu cuenta. Inténtalo de nuevo más tarde.
+link-limit = ❌ Puedes vincular como máximo { $max } cuentas. Usa `{ $prefix }unlink <Nombre#TAG>` para quitar una primero.
+link-verifying = Verificando el invocador con la API de Riot...
+link-not-found = ❌ No se encontró al invocador **{ $riot_id }** en la región **{ $region }**. Revisa el nombre y el tag.
+link-verify-failed = ❌ No se pudo verificar al invocador: { $error }. Inténtalo de nuevo más tarde
+link-save-failed = ❌ No se pudo guardar tu cuenta. Inténtalo de nuevo más tarde.
+link-primary = ✅ Tu cuenta quedó vinculada a **{ $riot_id }** en la región **{ $region }**
+link-added = ✅ Se añadió **{ $riot_id }** en la región **{ $region }**. Usa `{ $prefix }main { $riot_id }` para que sea tu cuenta predeterminada.
+unlink-check-failed = No se pudo comprobar tu cuenta. Inténtalo de nuevo más tarde.
+unlink-choose =
+    Tienes varias cuentas vinculadas. Usa `{ $prefix }unlink <Nombre#TAG>` para elegir una:
+    { $accounts }
+unlink-done = ✅ Se desvinculó **{ $riot_id }**.
+unlink-not-linked = **{ $riot_id }** no es una de tus cuentas vinculadas.
+unlink-failed = No se pudo desvincular tu cuenta. Inténtalo de nuevo más tarde.
+main-done = ✅ **{ $riot_id }** es ahora tu cuenta principal.
+main-not-linked = **{ $riot_id }** no es una de tus cuentas vinculadas. Usa `{ $prefix }link <Nombre#TAG> <región>` para añadirla.
+main-failed = No se pudo cambiar tu cuenta principal. Inténtalo de nuevo más tarde.
+linked-accounts-one = Tu cuenta vinculada
+linked-accounts-many = Tus cuentas vinculadas
+verify-not-linked = Esa no es una de tus cuentas vinculadas. Usa `{ $prefix }link <Nombre#TAG> <región>` primero.
+verify-already = ✅ **{ $riot_id }** ya está verificada.
+verify-checking = Comprobando tu icono de perfil con la API de Riot...
+verify-check-failed = ❌ No se pudo comprobar tu perfil: { $error }. Inténtalo de nuevo más tarde
+verify-load-failed = No se pudo comprobar tu verificación. Inténtalo de nuevo más tarde.
+verify-icon-mismatch =
+    Tu icono de perfil sigue siendo el **#{ $current }**. Cámbialo al **#{ $icon }** y vuelve a usar `{ $prefix }verify` en menos de { $minutes } minutos.
+    { $url }
+verify-save-failed = ❌ No se pudo guardar tu verificación. Inténtalo de nuevo más tarde.
+verify-done = ✅ **{ $riot_id }** ya está verificada. Puedes volver a poner tu icono.
+verify-start-failed = ❌ No se pudo iniciar la verificación. Inténtalo de nuevo más tarde.
+verify-challenge =
+    Para demostrar que **{ $riot_id }** es tuya, cambia su icono de perfil al **#{ $icon }** en el cliente de League y vuelve a usar `{ $prefix }verify` en menos de { $minutes } minutos.
+    { $url }
+
+# Languages
+
+language-current = El idioma de este servidor es **{ $language }**.
+language-unset = Este servidor no tiene idioma. Las respuestas siguen el idioma de Discord de cada miembro, o inglés.
+language-invalid = Idioma desconocido `{ $value }`. Disponibles: { $languages }
+language-set = ✅ El idioma de este servidor es ahora **{ $language }**.
+language-reset = ✅ Se quitó el idioma del servidor. Las respuestas siguen el idioma de Discord de cada miembro.
+my-language-current = Tu idioma es **{ $language }**.
+my-language-unset = No tienes un idioma propio. Las respuestas usan el idioma de este servidor.
+my-language-set = ✅ Tu idioma es ahora **{ $language }**.
+my-language-reset = ✅ Se quitó tu idioma propio.
+my-language-failed = No se pudo guardar tu idioma. Inténtalo de nuevo más tarde.
+
+# Server settings
+
+prefix-current = Prefijo actual: `{ $prefix }`
+prefix-changed = Prefijo cambiado a: `{ $prefix }`
+admin-role-current = Rol de administración del bot: <@&{ $role }>
+admin-role-none = No hay rol de administración del bot. Solo quienes tengan **Gestionar servidor** pueden cambiar la configuración.
+admin-role-invalid = Rol no válido: `{ $value }`. Menciona un rol o usa `off`.
+admin-role-set = ✅ Quienes tengan <@&{ $role }> ya pueden usar los comandos de administración.
+admin-role-removed = ✅ Se quitó el rol de administración del bot.
+verification-required-status = En este servidor los comandos de clasificatoria solo aceptan cuentas verificadas.
+verification-optional-status = En este servidor los comandos de clasificatoria aceptan cualquier cuenta vinculada.
+verification-invalid = Modo no válido: `{ $value }`. Usa `required` u `optional`.
+verification-required-set = ✅ Los comandos de clasificatoria ahora requieren una cuenta verificada. Cada miembro puede usar `verify` para verificar la suya.
+verification-optional-set = ✅ Los comandos de clasificatoria ahora aceptan cualquier cuenta vinculada.
+feed-usage = Uso: `{ $prefix }feed set #canal` o `{ $prefix }feed off`
+feed-status = Las partidas terminadas de los miembros vinculados se publican en <#{ $channel }>.
+feed-off-status = El feed de partidas está desactivado.
+feed-foreign-channel = Ese canal no pertenece a este servidor.
+feed-set = ✅ Las partidas terminadas de los miembros vinculados se publicarán en <#{ $channel }>.
+feed-disabled = ✅ Feed de partidas desactivado.
+rank-roles-checking = Comprobando las ligas de los miembros...
+rank-roles-sync-failed = ❌ No se pudieron sincronizar los roles de liga. Asegúrate de que el bot puede ver la lista de miembros.
+rank-roles-usage =
+    Uso: `{ $prefix }rankroles [set <liga> <@rol> | remove <liga> | sync | dryrun]`
+    Ligas: { $tiers }
+rank-roles-none = No hay roles de liga configurados.
+rank-roles-title = Roles de liga (Solo/Dúo)
+rank-roles-set = ✅ Quienes estén en **{ $tier }** recibirán <@&{ $role }>. Usa `{ $prefix }rankroles sync` para aplicarlo ahora.
+rank-roles-not-mapped = **{ $tier }** no tiene ningún rol asignado.
+rank-roles-removed = ✅ **{ $tier }** ya no tiene rol de liga.
+rank-roles-report = **Sincronización de roles de liga:** { $checked } miembros revisados, { $changes } cambios.
+rank-roles-report-dry-run = **Simulación de roles de liga:** { $checked } miembros revisados, { $changes } cambiarían.
+rank-roles-report-more = …y { $count } más
+rank-roles-report-errors = ⚠️ No se pudo revisar o actualizar a { $count } miembros. Comprueba que el bot tiene **Gestionar roles** y que su rol está por encima de los roles de liga.
+cooldown-unknown-command = Comando desconocido `{ $command }`.
+cooldown-none = No hay tiempos de espera activos en este servidor.
+cooldown-title = Tiempos de espera de comandos
+cooldown-invalid-scope = Ámbito no válido: `{ $value }`. Usa `user`, `guild` o `reset`.
+cooldown-usage = Uso: `{ $prefix }cooldown <comando> <user | guild> <segundos> [ráfaga]` (segundos 0-{ $max })
+cooldown-burst-invalid = La ráfaga debe ser un número positivo.
+cooldown-limit = { $burst } cada { $window } por { $scope }
+cooldown-no-limit = sin límite por { $scope }
+cooldown-scope-user = usuario
+cooldown-scope-guild = servidor
+
+# Commands
+
+unknown-command-suggestion = Comando desconocido `{ $command }`. ¿Quisiste decir `{ $suggestion }`?
+usage-example = Ejemplo: `{ $example }`
+cooldown-user-wait = ⏳ Espera { $seconds } s antes de volver a usar `{ $command }`.
+cooldown-guild-wait = ⏳ `{ $command }` está en enfriamiento en este servidor. Inténtalo de nuevo en { $seconds } s.
+permission-denied = ❌ Necesitas el permiso **{ $permissions }** para usar `{ $command }`.
+permission-denied-role = ❌ Necesitas el permiso **{ $permissions }** o el rol <@&{ $role }> para usar `{ $command }`.
+verified-account-required = Este servidor solo acepta cuentas verificadas. Usa `{ $prefix }verify` para verificar **{ $riot_id }**.
+link-outdated = Tu vinculación está desactualizada. Vuelve a usar `{ $prefix }link`.
+unranked = Sin clasificar
+queue-solo = Clasificatoria Solo/Dúo
+queue-flex = Clasificatoria Flexible
+
+# Leaderboard
+
+leaderboard-queue-invalid = Cola no válida: `{ $value }`. Usa `solo` o `flex`.
+leaderboard-building = ⏳ Se está generando la clasificación. Inténtalo de nuevo en un minuto.
+leaderboard-title = **🏆 Tabla de { $queue }** (página { $page }/{ $pages })
+leaderboard-empty = Nadie aquí tiene todavía una cuenta vinculada con clasificatoria. ¡Usa `link` para unirte!
+leaderboard-unranked = _{ $count } miembros vinculados no están clasificados_
+leaderboard-updated = _Actualizado hace { $minutes } min_
+
+# Progress
+
+progress-invalid-argument =
+    Argumento no válido: `{ $value }`.
+    Uso: `{ $prefix }progress [day | week | season] [solo | flex]`
+progress-history-failed = No se pudo cargar tu historial de rango. Inténtalo de nuevo más tarde.
+progress-no-history = Aún no hay historial de { $queue } para **{ $riot_id }**. Los rangos se registran cada hora, vuelve después de unas partidas.
+progress-summary =
+    **{ $trend } { $riot_id } — { $queue }, { $window }**
+    { $start } → { $end } (**{ $lp_change } LP**)
+    ⬆️ { $promotions } ascensos · ⬇️ { $demotions } descensos
+    🎮 { $games } partidas: { $wins }V { $losses }D ({ $win_rate } %)
+window-day = últimas 24 horas
+window-week = últimos 7 días
+window-season = esta temporada
+
+# Rank
+
+rank-usage =
+    Uso: `{ $prefix }rank [@usuario | Nombre#TAG región]`
+    Ejemplo: `{ $prefix }rank Faker#KR1 kr`
+profile-level = Nivel { $level }
+league-entry =
+    { $standing } — { $lp } LP
+    { $wins }V { $losses }D ({ $win_rate } %)
+hot-streak = 🔥 En racha
+
+# Match history
+
+history-invalid-argument =
+    Argumento no válido: `{ $value }`.
+    Uso: `{ $prefix }history [cantidad] [cola]` (cantidad 1-{ $max }, cola: { $queues })
+history-empty = No se encontraron partidas recientes.
+history-load-failed = No se pudo cargar el historial de partidas. Inténtalo de nuevo más tarde.
+history-title = Partidas recientes de { $riot_id }
+
+# Match feed
+
+feed-victory = ✅ Victoria — { $riot_id }
+feed-defeat = ❌ Derrota — { $riot_id }
+feed-played = <@{ $user }> jugó con **{ $champion }**
+feed-queue = Cola
+feed-lp = LP
+
+# Live games
+
+live-usage = Uso: `{ $prefix }live [@usuario]`
+live-not-in-game = **{ $riot_id }** no está en partida ahora mismo.
+live-looking-up = 🔎 **{ $riot_id }** está en partida, buscando las clasificaciones...
+live-header = 🔴 **{ $riot_id }** está en partida — { $queue } · { $time }
+live-custom-game = Personalizada
+live-blue-team = 🔵 **Equipo azul**
+live-red-team = 🔴 **Equipo rojo**
+live-bot = Bot
+live-unknown-player = Desconocido
+
+# Mastery
+
+mastery-unknown-champion = Campeón desconocido: `{ $champion }`.
+mastery-champion-title = **Maestría con { $champion } de { $riot_id }**
+mastery-never-played = **{ $riot_id }** nunca ha jugado con **{ $champion }**.
+mastery-none = **{ $riot_id }** todavía no tiene maestría con ningún campeón.
+mastery-top-title = **Mejores campeones de { $riot_id }**
+mastery-line = **{ $champion }** — Nivel { $level } · { $points } pts · jugado por última vez <t:{ $last_played }:R>
+
+# Graph
+
+graph-invalid-argument =
+    Argumento no válido: `{ $value }`.
+    Uso: `{ $prefix }graph [@usuario] [días]` (1-{ $max } días)
+graph-history-failed = No se pudo cargar el historial de clasificación. Inténtalo de nuevo más tarde.
+graph-no-history = Todavía no hay suficiente historial de { $queue } para **{ $riot_id }**. Las clasificaciones se registran cada hora, vuelve después de unas partidas.
+graph-draw-failed = No se pudo dibujar la gráfica. Inténtalo de nuevo más tarde.
+graph-summary =
+    📈 **{ $riot_id }** — { $queue }, últimos { $days } días
+    { $summary }
+
+# Help
+
+pong = ¡Pong!
+help-unknown = Comando desconocido `{ $command }`. Usa `{ $prefix }help` para ver todos los comandos.
+help-header = Comandos disponibles:
+help-footer =
+    Regiones: { $regions }
+    Usa `{ $prefix }help <comando>` para ver los detalles de un comando.
+    Consejo: usa comillas para argumentos de varias palabras: `{ $prefix }comando "varias palabras"`
+    Todos los comandos también están disponibles como comandos de barra, p. ej. `/rank`
+help-usage = Uso: `{ $usage }`
+help-aliases = Alias: { $aliases }
+help-requires = Requiere: **{ $permissions }** o el rol de administración del bot
+help-arguments = Argumentos:
+help-optional = (opcional)
+help-examples = Ejemplos: { $examples }
+
+category-general = General
+category-config = Configuración del servidor
+category-account = Cuenta
+category-stats = Estadísticas
+
+command-ping = Responde con ¡Pong!
+command-help = Muestra la lista de comandos o los detalles de uno
+command-prefix = Muestra o cambia el prefijo de comandos de este servidor
+command-adminrole = Muestra o cambia el rol que puede modificar la configuración del bot
+command-cooldown = Muestra o cambia los tiempos de espera de comandos de este servidor
+command-verification = Muestra o cambia si los comandos de clasificatoria exigen cuentas verificadas
+command-feed = Publica en un canal las partidas terminadas de los miembros vinculados
+command-rankroles = Asocia ligas de Solo/Dúo a roles que el bot mantiene sincronizados
+command-language = Muestra o cambia el idioma en que responde el bot en este servidor
+command-link = Vincula una cuenta de LoL a tu cuenta de Discord
+command-unlink = Quita una de tus cuentas de LoL vinculadas
+command-main = Elige qué cuenta vinculada usan los demás comandos
+command-verify = Demuestra que una cuenta vinculada es tuya cambiando su icono de perfil
+command-me = Muestra tus cuentas de LoL vinculadas
+command-mylanguage = Muestra o cambia el idioma en que el bot te responde
+command-rank = Muestra las ligas de Solo/Dúo y Flexible
+command-history = Muestra tus partidas recientes
+command-live = Muestra la partida en curso de un jugador vinculado
+command-mastery = Muestra la maestría de campeones, o la de uno
+command-progress = Muestra los PL ganados o perdidos, ascensos y porcentaje de victorias a lo largo del tiempo
+command-graph = Dibuja una gráfica de PL de Solo/Dúo a partir de las ligas registradas
+command-leaderboard = Clasifica a los miembros vinculados de este servidor
+
+arg-help-command = Un comando que explicar
+arg-prefix-new-prefix = El nuevo prefijo
+arg-adminrole-role = El rol de administración del bot, u off
+arg-cooldown-command = El comando que configurar
+arg-cooldown-scope = Qué límite cambiar
+arg-cooldown-seconds = Duración del intervalo; 0 quita el límite
+arg-cooldown-burst = Usos permitidos por intervalo (por defecto 1)
+arg-verification-mode = Si las cuentas vinculadas deben estar verificadas
+arg-feed-action = Activa o desactiva el feed
+arg-feed-channel = Dónde publicar las partidas
+arg-rankroles-action = Qué hacer
+arg-rankroles-tier = La liga de clasificatoria
+arg-rankroles-role = El rol para esa liga
+arg-language-language = El idioma del servidor, o reset
+arg-link-riot-id = Tu Riot ID, p. ej. Faker#KR1
+arg-link-region = Tu servidor
+arg-unlink-riot-id = La cuenta que quitar
+arg-main-riot-id = Una de tus cuentas vinculadas
+arg-verify-riot-id = La cuenta que verificar (por defecto: la principal)
+arg-mylanguage-language = Tu idioma, o reset
+arg-rank-user = Un miembro con una cuenta vinculada
+arg-rank-riot-id = Un Riot ID, p. ej. Faker#KR1
+arg-rank-region = Servidor del Riot ID
+arg-history-count = Número de partidas
+arg-history-queue = Mostrar solo esta cola
+arg-live-user = Un miembro con una cuenta vinculada
+arg-mastery-user = Un miembro con una cuenta vinculada
+arg-mastery-champion = Mostrar solo este campeón
+arg-progress-window = Periodo (por defecto: semana)
+arg-progress-queue = Qué cola de clasificatoria
+arg-graph-user = Un miembro con una cuenta vinculada
+arg-graph-days = Cuántos días atrás (por defecto: 30)
+arg-leaderboard-queue = Qué cola de clasificatoria
@@ -0,0 +1,300 @@
+# Shared
+
+server-only = 이 명령어는 서버에서만 사용할 수 있습니다!
+config-save-failed = ❌ 설정을 저장하지 못해 아무것도 변경되지 않았습니다. 나중에 다시 시도해 주세요.
+riot-id-invalid = 잘못된 Riot ID 형식입니다. `이름#TAG` 형식을 사용하세요 (예: `Faker#KR1`)
+region-invalid = 잘못된 지역: `{ $region }`. 사용 가능한 지역: { $regions }
+rate-limited = Riot API 요청 한도에 걸렸습니다. 잠시 후 다시 시도해 주세요.
+api-unauthorized = ❌ API 인증 오류입니다. 봇 관리자에게 문의해 주세요.
+no-linked-account = 연결된 LoL 계정이 없습니다.
+no-linked-account-hint =
+    연결된 LoL 계정이 없습니다.
+    `{ $prefix }link <이름#TAG> <지역>`으로 계정을 연결하세요.
+
+member-no-linked-account = 해당 사용자는 연결된 LoL 계정이 없습니다.
+member-link-unverified = 해당 사용자의 연결된 계정은 인증되지 않았습니다.
+riot-not-found = ❌ 해당 계정의 League of Legends 데이터를 찾을 수 없습니다.
+riot-request-failed = ❌ Riot API 요청에 실패했습니다: { $error }. 나중에 다시 시도해 주세요.
+cache-updated-minute = 1분 전 업데이트됨
+cache-updated-minutes = { $count }분 전 업데이트됨
+cache-updated-hours = { $count }시간 전 업데이트됨
+
+duration-days = { $count }일
+duration-hours = { $count }시간
+duration-minutes = { $count }분
+duration-seconds = { $count }초
+
+# Accounts
+
+links-load-failed = 연결된 계정을 불러오지 못했습니다. 나중에 다시 시도해 주세요.
+link-load-failed = 연결된 계정을 불러오지 못했습니다. 나중에 다시 시도해 주세요.
+link-limit = ❌ 계정은 최대 { $max }개까지 연결할 수 있습니다. 먼저 `{ $prefix }unlink <이름#TAG>`로 하나를 해제하세요.
+link-verifying = Riot API로 소환사를 확인하는 중...
+link-not-found = ❌ **{ $region }** 지역에서 소환사 **{ $riot_id }**을(를) 찾을 수 없습니다. 이름과 태그를 확인해 주세요.
+link-verify-failed = ❌ 소환사를 확인하지 못했습니다: { $error }. 나중에 다시 시도해 주세요
+link-save-failed = ❌ 계정 연결을 저장하지 못했습니다. 나중에 다시 시도해 주세요.
+link-primary = ✅ **{ $region }** 지역의 **{ $riot_id }** 계정을 연결했습니다
+link-added = ✅ **{ $region }** 지역의 **{ $riot_id }** 계정을 추가했습니다. 기본 계정으로 쓰려면 `{ $prefix }main { $riot_id }`를 사용하세요.
+unlink-check-failed = 연결된 계정을 확인하지 못했습니다. 나중에 다시 시도해 주세요.
+unlink-choose =
+    연결된 계정이 여러 개입니다. `{ $prefix }unlink <이름#TAG>`로 하나를 골라 주세요:
+    { $accounts }
+unlink-done = ✅ **{ $riot_id }** 계정 연결을 해제했습니다.
+unlink-not-linked = **{ $riot_id }**은(는) 연결된 계정이 아닙니다.
+unlink-failed = 계정 연결을 해제하지 못했습니다. 나중에 다시 시도해 주세요.
+main-done = ✅ 이제 **{ $riot_id }**이(가) 기본 계정입니다.
+main-not-linked = **{ $riot_id }**은(는) 연결된 계정이 아닙니다. `{ $prefix }link <이름#TAG> <지역>`으로 추가하세요.
+main-failed = 기본 계정을 변경하지 못했습니다. 나중에 다시 시도해 주세요.
+linked-accounts-one = 연결된 계정
+linked-accounts-many = 연결된 계정 목록
+verify-not-linked = 연결된 계정이 아닙니다. 먼저 `{ $prefix }link <이름#TAG> <지역>`으로 연결하세요.
+verify-already = ✅ **{ $riot_id }**은(는) 이미 인증되었습니다.
+verify-checking = Riot API로 프로필 아이콘을 확인하는 중...
+verify-check-failed = ❌ 프로필을 확인하지 못했습니다: { $error }. 나중에 다시 시도해 주세요
+verify-load-failed = 인증 상태를 확인하지 못했습니다. 나중에 다시 시도해 주세요.
+verify-icon-mismatch =
+    프로필 아이콘이 아직 **#{ $current }**입니다. **#{ $icon }**(으)로 바꾼 뒤 { $minutes }분 안에 `{ $prefix }verify`를 다시 실행하세요.
+    { $url }
+verify-save-failed = ❌ 인증 정보를 저장하지 못했습니다. 나중에 다시 시도해 주세요.
+verify-done = ✅ **{ $riot_id }** 인증이 완료되었습니다. 이제 아이콘을 원래대로 바꿔도 됩니다.
+verify-start-failed = ❌ 인증을 시작하지 못했습니다. 나중에 다시 시도해 주세요.
+verify-challenge =
+    **{ $riot_id }**의 소유자임을 증명하려면 League 클라이언트에서 프로필 아이콘을 **#{ $icon }**(으)로 바꾼 뒤 { $minutes }분 안에 `{ $prefix }verify`를 다시 실행하세요.
+    { $url }
+
+# Languages
+
+language-current = 이 서버의 언어는 **{ $language }**입니다.
+language-unset = 서버 언어가 설정되지 않았습니다. 각 멤버의 Discord 언어나 영어로 응답합니다.
+language-invalid = 알 수 없는 언어 `{ $value }`. 사용 가능: { $languages }
+language-set = ✅ 이제 이 서버의 언어는 **{ $language }**입니다.
+language-reset = ✅ 서버 언어를 삭제했습니다. 각 멤버의 Discord 언어로 응답합니다.
+my-language-current = 내 언어는 **{ $language }**입니다.
+my-language-unset = 개인 언어가 설정되지 않았습니다. 이 서버의 언어로 응답합니다.
+my-language-set = ✅ 이제 내 언어는 **{ $language }**입니다.
+my-language-reset = ✅ 개인 언어를 삭제했습니다.
+my-language-failed = 언어를 저장하지 못했습니다. 나중에 다시 시도해 주세요.
+
+# Server settings
+
+prefix-current = 현재 접두사: `{ $prefix }`
+prefix-changed = 접두사를 다음으로 변경했습니다: `{ $prefix }`
+admin-role-current = 봇 관리자 역할: <@&{ $role }>
+admin-role-none = 봇 관리자 역할이 없습니다. **서버 관리** 권한이 있는 멤버만 설정을 바꿀 수 있습니다.
+admin-role-invalid = 잘못된 역할: `{ $value }`. 역할을 멘션하거나 `off`를 사용하세요.
+admin-role-set = ✅ 이제 <@&{ $role }> 역할이 있는 멤버가 관리자 명령어를 사용할 수 있습니다.
+admin-role-removed = ✅ 봇 관리자 역할을 삭제했습니다.
+verification-required-status = 이 서버의 랭크 명령어는 인증된 계정만 허용합니다.
+verification-optional-status = 이 서버의 랭크 명령어는 연결된 모든 계정을 허용합니다.
+verification-invalid = 잘못된 모드: `{ $value }`. `required` 또는 `optional`을 사용하세요.
+verification-required-set = ✅ 이제 랭크 명령어에 인증된 계정이 필요합니다. 멤버는 `verify`로 계정을 인증할 수 있습니다.
+verification-optional-set = ✅ 이제 랭크 명령어가 연결된 모든 계정을 허용합니다.
+feed-usage = 사용법: `{ $prefix }feed set #채널` 또는 `{ $prefix }feed off`
+feed-status = 연결된 멤버의 종료된 게임이 <#{ $channel }>에 게시됩니다.
+feed-off-status = 매치 피드가 꺼져 있습니다.
+feed-foreign-channel = 이 서버의 채널이 아닙니다.
+feed-set = ✅ 연결된 멤버의 종료된 게임을 <#{ $channel }>에 게시합니다.
+feed-disabled = ✅ 매치 피드를 껐습니다.
+rank-roles-checking = 멤버들의 티어를 확인하는 중...
+rank-roles-sync-failed = ❌ 티어 역할을 동기화하지 못했습니다. 봇이 멤버 목록을 볼 수 있는지 확인해 주세요.
+rank-roles-usage =
+    사용법: `{ $prefix }rankroles [set <티어> <@역할> | remove <티어> | sync | dryrun]`
+    티어: { $tiers }
+rank-roles-none = 설정된 티어 역할이 없습니다.
+rank-roles-title = 티어 역할 (솔로/듀오)
+rank-roles-set = ✅ **{ $tier }** 티어 멤버에게 <@&{ $role }> 역할을 부여합니다. 지금 적용하려면 `{ $prefix }rankroles sync`를 사용하세요.
+rank-roles-not-mapped = **{ $tier }** 티어에 연결된 역할이 없습니다.
+rank-roles-removed = ✅ **{ $tier }** 티어의 역할을 삭제했습니다.
+rank-roles-report = **티어 역할 동기화:** 멤버 { $checked }명 확인, { $changes }건 변경.
+rank-roles-report-dry-run = **티어 역할 시험 실행:** 멤버 { $checked }명 확인, { $changes }건 변경 예정.
+rank-roles-report-more = …외 { $count }건
+rank-roles-report-errors = ⚠️ 멤버 { $count }명을 확인하거나 갱신하지 못했습니다. 봇에 **역할 관리** 권한이 있고 봇 역할이 티어 역할보다 위에 있는지 확인해 주세요.
+cooldown-unknown-command = 알 수 없는 명령어 `{ $command }`.
+cooldown-none = 이 서버에 적용 중인 명령어 쿨다운이 없습니다.
+cooldown-title = 명령어 쿨다운
+cooldown-invalid-scope = 잘못된 범위: `{ $value }`. `user`, `guild` 또는 `reset`을 사용하세요.
+cooldown-usage = 사용법: `{ $prefix }cooldown <명령어> <user | guild> <초> [횟수]` (초 0-{ $max })
+cooldown-burst-invalid = 횟수는 양수여야 합니다.
+cooldown-limit = { $scope }당 { $window }에 { $burst }회
+cooldown-no-limit = { $scope }별 제한 없음
+cooldown-scope-user = 사용자
+cooldown-scope-guild = 서버
+
+# Commands
+
+unknown-command-suggestion = 알 수 없는 명령어 `{ $command }`입니다. `{ $suggestion }`을(를) 찾으셨나요?
+usage-example = 예시: `{ $example }`
+cooldown-user-wait = ⏳ `{ $command }`은(는) { $seconds }초 후에 다시 사용할 수 있습니다.
+cooldown-guild-wait = ⏳ 이 서버에서 `{ $command }`은(는) 재사용 대기 중입니다. { $seconds }초 후에 다시 시도해 주세요.
+permission-denied = ❌ `{ $command }`을(를) 사용하려면 **{ $permissions }** 권한이 필요합니다.
+permission-denied-role = ❌ `{ $command }`을(를) 사용하려면 **{ $permissions }** 권한 또는 <@&{ $role }> 역할이 필요합니다.
+verified-account-required = 이 서버는 인증된 계정만 허용합니다. `{ $prefix }verify`로 **{ $riot_id }**을(를) 인증해 주세요.
+link-outdated = 연결 정보가 오래되었습니다. `{ $prefix }link`를 다시 실행해 주세요.
+unranked = 언랭크
+queue-solo = 솔로/듀오 랭크
+queue-flex = 자유 랭크
+
+# Leaderboard
+
+leaderboard-queue-invalid = 잘못된 큐: `{ $value }`. `solo` 또는 `flex`를 사용하세요.
+leaderboard-building = ⏳ 리더보드를 만드는 중입니다. 1분 후에 다시 시도해 주세요.
+leaderboard-title = **🏆 { $queue } 리더보드** ({ $page }/{ $pages } 페이지)
+leaderboard-empty = 아직 랭크가 있는 연결된 계정이 없습니다. `link`로 참여하세요!
+leaderboard-unranked = _연결된 멤버 { $count }명은 언랭크입니다_
+leaderboard-updated = _{ $minutes }분 전 업데이트됨_
+
+# Progress
+
+progress-invalid-argument =
+    잘못된 인수: `{ $value }`.
+    사용법: `{ $prefix }progress [day | week | season] [solo | flex]`
+progress-history-failed = 랭크 기록을 불러오지 못했습니다. 나중에 다시 시도해 주세요.
+progress-no-history = **{ $riot_id }**의 { $queue } 기록이 아직 없습니다. 랭크는 매시간 기록되니 몇 게임 후에 다시 확인해 주세요.
+progress-summary =
+    **{ $trend } { $riot_id } — { $queue }, { $window }**
+    { $start } → { $end } (**{ $lp_change } LP**)
+    ⬆️ 승급 { $promotions }회 · ⬇️ 강등 { $demotions }회
+    🎮 { $games }게임: { $wins }승 { $losses }패 ({ $win_rate }%)
+window-day = 최근 24시간
+window-week = 최근 7일
+window-season = 이번 시즌
+
+# Rank
+
+rank-usage =
+    사용법: `{ $prefix }rank [@사용자 | 이름#TAG 지역]`
+    예시: `{ $prefix }rank Faker#KR1 kr`
+profile-level = 레벨 { $level }
+league-entry =
+    { $standing } — { $lp } LP
+    { $wins }승 { $losses }패 ({ $win_rate }%)
+hot-streak = 🔥 연승 중
+
+# Match history
+
+history-invalid-argument =
+    잘못된 인수: `{ $value }`.
+    사용법: `{ $prefix }history [개수] [큐]` (개수 1-{ $max }, 큐: { $queues })
+history-empty = 최근 경기가 없습니다.
+history-load-failed = 경기 기록을 불러오지 못했습니다. 나중에 다시 시도해 주세요.
+history-title = { $riot_id }의 최근 경기
+
+# Match feed
+
+feed-victory = ✅ 승리 — { $riot_id }
+feed-defeat = ❌ 패배 — { $riot_id }
+feed-played = <@{ $user }> 님이 **{ $champion }**(으)로 플레이했습니다
+feed-queue = 큐
+feed-lp = LP
+
+# Live games
+
+live-usage = 사용법: `{ $prefix }live [@사용자]`
+live-not-in-game = **{ $riot_id }**은(는) 지금 게임 중이 아닙니다.
+live-looking-up = 🔎 **{ $riot_id }** 게임 중, 랭크를 확인하는 중...
+live-header = 🔴 **{ $riot_id }** 게임 중 — { $queue } · { $time }
+live-custom-game = 사용자 설정
+live-blue-team = 🔵 **블루 팀**
+live-red-team = 🔴 **레드 팀**
+live-bot = 봇
+live-unknown-player = 알 수 없음
+
+# Mastery
+
+mastery-unknown-champion = 알 수 없는 챔피언: `{ $champion }`.
+mastery-champion-title = **{ $riot_id }의 { $champion } 숙련도**
+mastery-never-played = **{ $riot_id }**은(는) **{ $champion }**을(를) 플레이한 적이 없습니다.
+mastery-none = **{ $riot_id }**은(는) 아직 챔피언 숙련도가 없습니다.
+mastery-top-title = **{ $riot_id }의 상위 챔피언**
+mastery-line = **{ $champion }** — 레벨 { $level } · { $points }점 · 마지막 플레이 <t:{ $last_played }:R>
+
+# Graph
+
+graph-invalid-argument =
+    잘못된 인수: `{ $value }`.
+    사용법: `{ $prefix }graph [@사용자] [일수]` (1-{ $max }일)
+graph-history-failed = 랭크 기록을 불러오지 못했습니다. 나중에 다시 시도해 주세요.
+graph-no-history = 아직 **{ $riot_id }**의 { $queue } 기록이 충분하지 않습니다. 랭크는 매시간 기록되니 몇 게임 후에 다시 확인해 주세요.
+graph-draw-failed = 그래프를 그리지 못했습니다. 나중에 다시 시도해 주세요.
+graph-summary =
+    📈 **{ $riot_id }** — { $queue }, 최근 { $days }일
+    { $summary }
+
+# Help
+
+pong = 퐁!
+help-unknown = 알 수 없는 명령어 `{ $command }`. 모든 명령어를 보려면 `{ $prefix }help`를 사용하세요.
+help-header = 사용 가능한 명령어:
+help-footer =
+    지역: { $regions }
+    명령어의 자세한 내용은 `{ $prefix }help <명령어>`로 확인하세요.
+    팁: 여러 단어로 된 인자는 따옴표로 감싸세요: `{ $prefix }command "여러 단어"`
+    모든 명령어는 슬래시 명령어로도 사용할 수 있습니다. 예: `/rank`
+help-usage = 사용법: `{ $usage }`
+help-aliases = 별칭: { $aliases }
+help-requires = 필요 권한: **{ $permissions }** 또는 봇 관리자 역할
+help-arguments = 인자:
+help-optional = (선택)
+help-examples = 예시: { $examples }
+
+category-general = 일반
+category-config = 서버 설정
+category-account = 계정
+category-stats = 전적
+
+command-ping = 퐁!으로 응답합니다
+command-help = 명령어 목록이나 명령어 하나의 자세한 내용을 보여줍니다
+command-prefix = 이 서버의 명령어 접두사를 확인하거나 변경합니다
+command-adminrole = 봇 설정을 바꿀 수 있는 역할을 확인하거나 변경합니다
+command-cooldown = 이 서버의 명령어 쿨다운을 확인하거나 변경합니다
+command-verification = 랭크 명령어에 인증된 계정이 필요한지 확인하거나 변경합니다
+command-feed = 연결된 멤버의 종료된 게임을 채널에 게시합니다
+command-rankroles = 솔로/듀오 티어를 봇이 동기화하는 역할에 연결합니다
+command-language = 이 서버에서 봇이 응답하는 언어를 확인하거나 변경합니다
+command-link = LoL 계정을 Discord 계정에 연결합니다
+command-unlink = 연결된 LoL 계정 하나를 해제합니다
+command-main = 다른 명령어가 사용할 연결 계정을 고릅니다
+command-verify = 프로필 아이콘을 바꿔 연결된 계정의 소유를 인증합니다
+command-me = 연결된 LoL 계정을 보여줍니다
+command-mylanguage = 봇이 나에게 응답하는 언어를 확인하거나 변경합니다
+command-rank = 솔로/듀오와 자유 랭크 티어를 보여줍니다
+command-history = 최근 매치를 보여줍니다
+command-live = 연결된 플레이어의 현재 게임을 보여줍니다
+command-mastery = 챔피언 숙련도나 특정 챔피언의 숙련도를 보여줍니다
+command-progress = 기간별 LP 변동, 승급과 승률을 보여줍니다
+command-graph = 기록된 티어로 솔로/듀오 LP 그래프를 그립니다
+command-leaderboard = 이 서버의 연결된 멤버 순위를 보여줍니다
+
+arg-help-command = 설명할 명령어
+arg-prefix-new-prefix = 새 접두사
+arg-adminrole-role = 봇 관리자 역할, 또는 off
+arg-cooldown-command = 설정할 명령어
+arg-cooldown-scope = 바꿀 제한
+arg-cooldown-seconds = 시간 범위 (0이면 제한 해제)
+arg-cooldown-burst = 범위당 허용 횟수 (기본값 1)
+arg-verification-mode = 연결된 계정의 인증 필요 여부
+arg-feed-action = 피드 켜기 또는 끄기
+arg-feed-channel = 게임을 게시할 채널
+arg-rankroles-action = 할 작업
+arg-rankroles-tier = 랭크 티어
+arg-rankroles-role = 해당 티어의 역할
+arg-language-language = 서버 언어, 또는 reset
+arg-link-riot-id = Riot ID (예: Faker#KR1)
+arg-link-region = 내 서버
+arg-unlink-riot-id = 해제할 계정
+arg-main-riot-id = 연결된 계정 중 하나
+arg-verify-riot-id = 인증할 계정 (기본값: 기본 계정)
+arg-mylanguage-language = 내 언어, 또는 reset
+arg-rank-user = 계정이 연결된 멤버
+arg-rank-riot-id = Riot ID (예: Faker#KR1)
+arg-rank-region = Riot ID의 서버
+arg-history-count = 매치 수
+arg-history-queue = 이 큐만 보기
+arg-live-user = 계정이 연결된 멤버
+arg-mastery-user = 계정이 연결된 멤버
+arg-mastery-champion = 이 챔피언만 보기
+arg-progress-window = 기간 (기본값: 주간)
+arg-progress-queue = 랭크 큐
+arg-graph-user = 계정이 연결된 멤버
+arg-graph-days = 조회할 기간 (기본값: 30일)
+arg-leaderboard-queue = 랭크 큐
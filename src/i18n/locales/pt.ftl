@@ -0,0 +1,300 @@
+# Shared
+
+server-only = Este comando só funciona em servidores!
+config-save-failed = ❌ Não foi possível salvar a configuração, nada foi alterado. Tente novamente mais tarde.
+riot-id-invalid = Formato de Riot ID inválido. Use `Nome#TAG` (ex.: `Faker#KR1`)
+region-invalid = Região inválida: `{ $region }`. Regiões válidas: { $regions }
+rate-limited = A API da Riot está limitando as requisições. Tente novamente em instantes.
+api-unauthorized = ❌ Erro de autenticação com a API. Fale com o administrador do bot.
+no-linked-account = Você não tem nenhuma conta de LoL vinculada.
+no-linked-account-hint =
+    Você não tem nenhuma conta de LoL vinculada.
+    Use `{ $prefix }link <Nome#TAG> <região>` para vincular uma.
+
+member-no-linked-account = Esse usuário não tem uma conta de LoL vinculada.
+member-link-unverified = A conta vinculada desse usuário não está verificada.
+riot-not-found = ❌ Nenhum dado de League of Legends encontrado para essa conta.
+riot-request-failed = ❌ A requisição à API da Riot falhou: { $error }. Tente novamente mais tarde.
+cache-updated-minute = Atualizado há 1 minuto
+cache-updated-minutes = Atualizado há { $count } minutos
+cache-updated-hours = Atualizado há { $count } horas
+
+duration-days = { $count } d
+duration-hours = { $count } h
+duration-minutes = { $count } min
+duration-seconds = { $count } s
+
+# Accounts
+
+links-load-failed = Não foi possível carregar suas contas. Tente novamente mais tarde.
+link-load-failed = Não foi possível carregar sua conta. Tente novamente mais tarde.
+link-limit = ❌ Você pode vincular no máximo { $max } contas. Use `{ $prefix }unlink <Nome#TAG>` para remover uma antes.
+link-verifying = Verificando o invocador com a API da Riot...
+link-not-found = ❌ Invocador **{ $riot_id }** não encontrado na região **{ $region }**. Confira o nome e a tag.
+link-verify-failed = ❌ Não foi possível verificar o invocador: { $error }. Tente novamente mais tarde
+link-save-failed = ❌ Não foi possível salvar sua conta. Tente novamente mais tarde.
+link-primary = ✅ Sua conta foi vinculada a **{ $riot_id }** na região **{ $region }**
+link-added = ✅ **{ $riot_id }** adicionada na região **{ $region }**. Use `{ $prefix }main { $riot_id }` para torná-la sua conta padrão.
+unlink-check-failed = Não foi possível verificar sua conta. Tente novamente mais tarde.
+unlink-choose =
+    Você tem várias contas vinculadas. Use `{ $prefix }unlink <Nome#TAG>` para escolher uma:
+    { $accounts }
+unlink-done = ✅ **{ $riot_id }** foi desvinculada.
+unlink-not-linked = **{ $riot_id }** não é uma das suas contas vinculadas.
+unlink-failed = Não foi possível desvincular sua conta. Tente novamente mais tarde.
+main-done = ✅ **{ $riot_id }** agora é sua conta principal.
+main-not-linked = **{ $riot_id }** não é uma das suas contas vinculadas. Use `{ $prefix }link <Nome#TAG> <região>` para adicioná-la.
+main-failed = Não foi possível alterar sua conta principal. Tente novamente mais tarde.
+linked-accounts-one = Sua conta vinculada
+linked-accounts-many = Suas contas vinculadas
+verify-not-linked = Essa não é uma das suas contas vinculadas. Use `{ $prefix }link <Nome#TAG> <região>` primeiro.
+verify-already = ✅ **{ $riot_id }** já está verificada.
+verify-checking = Conferindo seu ícone de perfil com a API da Riot...
+verify-check-failed = ❌ Não foi possível conferir seu perfil: { $error }. Tente novamente mais tarde
+verify-load-failed = Não foi possível conferir sua verificação. Tente novamente mais tarde.
+verify-icon-mismatch =
+    Seu ícone de perfil ainda é o **#{ $current }**. Troque para o **#{ $icon }** e use `{ $prefix }verify` novamente em até { $minutes } minutos.
+    { $url }
+verify-save-failed = ❌ Não foi possível salvar sua verificação. Tente novamente mais tarde.
+verify-done = ✅ **{ $riot_id }** agora está verificada. Você já pode voltar ao seu ícone.
+verify-start-failed = ❌ Não foi possível iniciar a verificação. Tente novamente mais tarde.
+verify-challenge =
+    Para provar que **{ $riot_id }** é sua, troque o ícone de perfil dela para o **#{ $icon }** no cliente do League e use `{ $prefix }verify` novamente em até { $minutes } minutos.
+    { $url }
+
+# Languages
+
+language-current = O idioma deste servidor é **{ $language }**.
+language-unset = Nenhum idioma definido para o servidor. As respostas seguem o idioma do Discord de cada membro, ou inglês.
+language-invalid = Idioma desconhecido `{ $value }`. Disponíveis: { $languages }
+language-set = ✅ O idioma deste servidor agora é **{ $language }**.
+language-reset = ✅ Idioma do servidor removido. As respostas seguem o idioma do Discord de cada membro.
+my-language-current = Seu idioma é **{ $language }**.
+my-language-unset = Você não definiu um idioma próprio. As respostas usam o idioma deste servidor.
+my-language-set = ✅ Seu idioma agora é **{ $language }**.
+my-language-reset = ✅ Seu idioma próprio foi removido.
+my-language-failed = Não foi possível salvar seu idioma. Tente novamente mais tarde.
+
+# Server settings
+
+prefix-current = Prefixo atual: `{ $prefix }`
+prefix-changed = Prefixo alterado para: `{ $prefix }`
+admin-role-current = Cargo de administração do bot: <@&{ $role }>
+admin-role-none = Nenhum cargo de administração do bot. Só quem tem **Gerenciar servidor** pode alterar as configurações.
+admin-role-invalid = Cargo inválido: `{ $value }`. Mencione um cargo ou use `off`.
+admin-role-set = ✅ Quem tem <@&{ $role }> agora pode usar os comandos de administração.
+admin-role-removed = ✅ Cargo de administração do bot removido.
+verification-required-status = Neste servidor os comandos ranqueados só aceitam contas verificadas.
+verification-optional-status = Neste servidor os comandos ranqueados aceitam qualquer conta vinculada.
+verification-invalid = Modo inválido: `{ $value }`. Use `required` ou `optional`.
+verification-required-set = ✅ Os comandos ranqueados agora exigem uma conta verificada. Cada membro pode usar `verify` para verificar a sua.
+verification-optional-set = ✅ Os comandos ranqueados agora aceitam qualquer conta vinculada.
+feed-usage = Uso: `{ $prefix }feed set #canal` ou `{ $prefix }feed off`
+feed-status = As partidas encerradas dos membros vinculados são publicadas em <#{ $channel }>.
+feed-off-status = O feed de partidas está desativado.
+feed-foreign-channel = Esse canal não é deste servidor.
+feed-set = ✅ As partidas encerradas dos membros vinculados serão publicadas em <#{ $channel }>.
+feed-disabled = ✅ Feed de partidas desativado.
+rank-roles-checking = Verificando os elos dos membros...
+rank-roles-sync-failed = ❌ Não foi possível sincronizar os cargos de elo. Confira se o bot consegue ver a lista de membros.
+rank-roles-usage =
+    Uso: `{ $prefix }rankroles [set <elo> <@cargo> | remove <elo> | sync | dryrun]`
+    Elos: { $tiers }
+rank-roles-none = Nenhum cargo de elo configurado.
+rank-roles-title = Cargos de elo (Solo/Duo)
+rank-roles-set = ✅ Quem estiver em **{ $tier }** vai receber <@&{ $role }>. Use `{ $prefix }rankroles sync` para aplicar agora.
+rank-roles-not-mapped = Nenhum cargo está associado a **{ $tier }**.
+rank-roles-removed = ✅ **{ $tier }** não tem mais cargo de elo.
+rank-roles-report = **Sincronização de cargos de elo:** { $checked } membros verificados, { $changes } alterados.
+rank-roles-report-dry-run = **Simulação de cargos de elo:** { $checked } membros verificados, { $changes } seriam alterados.
+rank-roles-report-more = …e mais { $count }
+rank-roles-report-errors = ⚠️ Não foi possível verificar ou atualizar { $count } membros. Confira se o bot tem **Gerenciar cargos** e se o cargo dele está acima dos cargos de elo.
+cooldown-unknown-command = Comando desconhecido `{ $command }`.
+cooldown-none = Nenhum tempo de espera ativo neste servidor.
+cooldown-title = Tempos de espera dos comandos
+cooldown-invalid-scope = Escopo inválido: `{ $value }`. Use `user`, `guild` ou `reset`.
+cooldown-usage = Uso: `{ $prefix }cooldown <comando> <user | guild> <segundos> [rajada]` (segundos 0-{ $max })
+cooldown-burst-invalid = A rajada precisa ser um número positivo.
+cooldown-limit = { $burst } a cada { $window } por { $scope }
+cooldown-no-limit = sem limite por { $scope }
+cooldown-scope-user = usuário
+cooldown-scope-guild = servidor
+
+# Commands
+
+unknown-command-suggestion = Comando desconhecido `{ $command }`. Você quis dizer `{ $suggestion }`?
+usage-example = Exemplo: `{ $example }`
+cooldown-user-wait = ⏳ Aguarde { $seconds }s antes de usar `{ $command }` novamente.
+cooldown-guild-wait = ⏳ `{ $command }` está em tempo de espera neste servidor. Tente novamente em { $seconds }s.
+permission-denied = ❌ Você precisa da permissão **{ $permissions }** para usar `{ $command }`.
+permission-denied-role = ❌ Você precisa da permissão **{ $permissions }** ou do cargo <@&{ $role }> para usar `{ $command }`.
+verified-account-required = Este servidor só aceita contas verificadas. Use `{ $prefix }verify` para verificar **{ $riot_id }**.
+link-outdated = Sua vinculação está desatualizada. Use `{ $prefix }link` novamente.
+unranked = Sem elo
+queue-solo = Ranqueada Solo/Duo
+queue-flex = Ranqueada Flexível
+
+# Leaderboard
+
+leaderboard-queue-invalid = Fila inválida: `{ $value }`. Use `solo` ou `flex`.
+leaderboard-building = ⏳ O ranking está sendo montado. Tente novamente em um minuto.
+leaderboard-title = **🏆 Ranking de { $queue }** (página { $page }/{ $pages })
+leaderboard-empty = Ninguém aqui tem uma conta vinculada com elo ainda. Use `link` para participar!
+leaderboard-unranked = _{ $count } membros vinculados estão sem elo_
+leaderboard-updated = _Atualizado há { $minutes } min_
+
+# Progress
+
+progress-invalid-argument =
+    Argumento inválido: `{ $value }`.
+    Uso: `{ $prefix }progress [day | week | season] [solo | flex]`
+progress-history-failed = Não foi possível carregar seu histórico de elo. Tente novamente mais tarde.
+progress-no-history = Ainda não há histórico de { $queue } para **{ $riot_id }**. Os elos são registrados a cada hora, volte depois de algumas partidas.
+progress-summary =
+    **{ $trend } { $riot_id } — { $queue }, { $window }**
+    { $start } → { $end } (**{ $lp_change } PDL**)
+    ⬆️ { $promotions } promoções · ⬇️ { $demotions } rebaixamentos
+    🎮 { $games } partidas: { $wins }V { $losses }D ({ $win_rate }%)
+window-day = últimas 24 horas
+window-week = últimos 7 dias
+window-season = esta temporada
+
+# Rank
+
+rank-usage =
+    Uso: `{ $prefix }rank [@usuário | Nome#TAG região]`
+    Exemplo: `{ $prefix }rank Faker#KR1 kr`
+profile-level = Nível { $level }
+league-entry =
+    { $standing } — { $lp } PDL
+    { $wins }V { $losses }D ({ $win_rate }%)
+hot-streak = 🔥 Sequência de vitórias
+
+# Match history
+
+history-invalid-argument =
+    Argumento inválido: `{ $value }`.
+    Uso: `{ $prefix }history [quantidade] [fila]` (quantidade 1-{ $max }, fila: { $queues })
+history-empty = Nenhuma partida recente encontrada.
+history-load-failed = Não foi possível carregar o histórico de partidas. Tente novamente mais tarde.
+history-title = Partidas recentes de { $riot_id }
+
+# Match feed
+
+feed-victory = ✅ Vitória — { $riot_id }
+feed-defeat = ❌ Derrota — { $riot_id }
+feed-played = <@{ $user }> jogou de **{ $champion }**
+feed-queue = Fila
+feed-lp = PDL
+
+# Live games
+
+live-usage = Uso: `{ $prefix }live [@usuário]`
+live-not-in-game = **{ $riot_id }** não está em partida agora.
+live-looking-up = 🔎 **{ $riot_id }** está em partida, buscando os elos...
+live-header = 🔴 **{ $riot_id }** está em partida — { $queue } · { $time }
+live-custom-game = Personalizada
+live-blue-team = 🔵 **Time azul**
+live-red-team = 🔴 **Time vermelho**
+live-bot = Bot
+live-unknown-player = Desconhecido
+
+# Mastery
+
+mastery-unknown-champion = Campeão desconhecido: `{ $champion }`.
+mastery-champion-title = **Maestria de { $riot_id } com { $champion }**
+mastery-never-played = **{ $riot_id }** nunca jogou de **{ $champion }**.
+mastery-none = **{ $riot_id }** ainda não tem maestria com nenhum campeão.
+mastery-top-title = **Melhores campeões de { $riot_id }**
+mastery-line = **{ $champion }** — Nível { $level } · { $points } pts · última partida <t:{ $last_played }:R>
+
+# Graph
+
+graph-invalid-argument =
+    Argumento inválido: `{ $value }`.
+    Uso: `{ $prefix }graph [@usuário] [dias]` (1-{ $max } dias)
+graph-history-failed = Não foi possível carregar o histórico de elo. Tente novamente mais tarde.
+graph-no-history = Ainda não há histórico suficiente de { $queue } para **{ $riot_id }**. Os elos são registrados a cada hora, volte depois de algumas partidas.
+graph-draw-failed = Não foi possível desenhar o gráfico. Tente novamente mais tarde.
+graph-summary =
+    📈 **{ $riot_id }** — { $queue }, últimos { $days } dias
+    { $summary }
+
+# Help
+
+pong = Pong!
+help-unknown = Comando desconhecido `{ $command }`. Use `{ $prefix }help` para ver todos os comandos.
+help-header = Comandos disponíveis:
+help-footer =
+    Regiões: { $regions }
+    Use `{ $prefix }help <comando>` para ver os detalhes de um comando.
+    Dica: use aspas para argumentos com várias palavras: `{ $prefix }comando "várias palavras"`
+    Todos os comandos também estão disponíveis como comandos de barra, ex.: `/rank`
+help-usage = Uso: `{ $usage }`
+help-aliases = Apelidos: { $aliases }
+help-requires = Requer: **{ $permissions }** ou o cargo de administração do bot
+help-arguments = Argumentos:
+help-optional = (opcional)
+help-examples = Exemplos: { $examples }
+
+category-general = Geral
+category-config = Configurações do servidor
+category-account = Conta
+category-stats = Estatísticas
+
+command-ping = Responde com Pong!
+command-help = Mostra a lista de comandos ou os detalhes de um deles
+command-prefix = Mostra ou altera o prefixo de comandos deste servidor
+command-adminrole = Mostra ou altera o cargo que pode mudar as configurações do bot
+command-cooldown = Mostra ou altera os tempos de espera dos comandos deste servidor
+command-verification = Mostra ou altera se os comandos ranqueados exigem contas verificadas
+command-feed = Publica num canal as partidas encerradas dos membros vinculados
+command-rankroles = Associa elos de Solo/Duo a cargos que o bot mantém sincronizados
+command-language = Mostra ou altera o idioma em que o bot responde neste servidor
+command-link = Vincula uma conta de LoL à sua conta do Discord
+command-unlink = Remove uma das suas contas de LoL vinculadas
+command-main = Escolhe qual conta vinculada os outros comandos usam
+command-verify = Prova que uma conta vinculada é sua trocando o ícone de perfil
+command-me = Mostra suas contas de LoL vinculadas
+command-mylanguage = Mostra ou altera o idioma em que o bot responde a você
+command-rank = Mostra os elos de Solo/Duo e Flex
+command-history = Mostra suas partidas recentes
+command-live = Mostra a partida em andamento de um jogador vinculado
+command-mastery = Mostra a maestria de campeões, ou a de um campeão
+command-progress = Mostra PDL ganhos ou perdidos, promoções e taxa de vitória ao longo do tempo
+command-graph = Desenha um gráfico de PDL de Solo/Duo a partir dos elos registrados
+command-leaderboard = Classifica os membros vinculados deste servidor
+
+arg-help-command = Um comando para explicar
+arg-prefix-new-prefix = O novo prefixo
+arg-adminrole-role = O cargo de administração do bot, ou off
+arg-cooldown-command = O comando a configurar
+arg-cooldown-scope = Qual limite alterar
+arg-cooldown-seconds = Duração do intervalo; 0 remove o limite
+arg-cooldown-burst = Usos permitidos por intervalo (padrão 1)
+arg-verification-mode = Se as contas vinculadas precisam ser verificadas
+arg-feed-action = Liga ou desliga o feed
+arg-feed-channel = Onde publicar as partidas
+arg-rankroles-action = O que fazer
+arg-rankroles-tier = O elo ranqueado
+arg-rankroles-role = O cargo para esse elo
+arg-language-language = O idioma do servidor, ou reset
+arg-link-riot-id = Seu Riot ID, ex.: Faker#KR1
+arg-link-region = Seu servidor
+arg-unlink-riot-id = A conta a remover
+arg-main-riot-id = Uma das suas contas vinculadas
+arg-verify-riot-id = A conta a verificar (padrão: a principal)
+arg-mylanguage-language = Seu idioma, ou reset
+arg-rank-user = Um membro com conta vinculada
+arg-rank-riot-id = Um Riot ID, ex.: Faker#KR1
+arg-rank-region = Servidor do Riot ID
+arg-history-count = Número de partidas
+arg-history-queue = Mostrar só esta fila
+arg-live-user = Um membro com conta vinculada
+arg-mastery-user = Um membro com conta vinculada
+arg-mastery-champion = Mostrar só este campeão
+arg-progress-window = Período (padrão: semana)
+arg-progress-queue = Qual fila ranqueada
+arg-graph-user = Um membro com conta vinculada
+arg-graph-days = Quantos dias para trás (padrão: 30)
+arg-leaderboard-queue = Qual fila ranqueada
@@ -0,0 +1,204 @@
+mod catalog;
+
+use catalog::Catalog;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    Pt,
+    Ko,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 4] = [Locale::En, Locale::Es, Locale::Pt, Locale::Ko];
+
+    // Accepts our codes as well as Discord's (`es-ES`, `pt-BR`, `en-US`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let language = value.split(['-', '_']).next()?.to_lowercase();
+        Locale::ALL.into_iter().find(|locale| locale.code() == language)
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::Es => "es",
+            Locale::Pt => "pt",
+            Locale::Ko => "ko",
+        }
+    }
+
+    // In the language itself, so people can find theirs.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::En => "English",
+            Locale::Es => "Español",
+            Locale::Pt => "Português",
+            Locale::Ko => "한국어",
+        }
+    }
+
+    pub fn codes() -> Vec<&'static str> {
+        Locale::ALL.iter().map(|locale| locale.code()).collect()
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Locale::En => include_str!("locales/en.ftl"),
+            Locale::Es => include_str!("locales/es.ftl"),
+            Locale::Pt => include_str!("locales/pt.ftl"),
+            Locale::Ko => include_str!("locales/ko.ftl"),
+        }
+    }
+
+    fn group_separator(&self) -> char {
+        match self {
+            Locale::En | Locale::Ko => ',',
+            Locale::Es | Locale::Pt => '.',
+        }
+    }
+}
+
+static CATALOGS: LazyLock<HashMap<Locale, Catalog>> = LazyLock::new(|| {
+    Locale::ALL
+        .into_iter()
+        .map(|locale| (locale, Catalog::parse(locale.source())))
+        .collect()
+});
+
+// Missing translations fall back to English, and missing keys to the key
+// itself so a typo shows up in the reply instead of a blank message.
+pub fn message(locale: Locale, key: &str, args: &[(&str, String)]) -> String {
+    let pattern = CATALOGS[&locale]
+        .get(key)
+        .or_else(|| CATALOGS[&Locale::En].get(key));
+
+    match pattern {
+        Some(pattern) => catalog::format(pattern, args),
+        None => key.to_string(),
+    }
+}
+
+#[cfg(test)]
+pub fn has_message(key: &str) -> bool {
+    CATALOGS[&Locale::En].get(key).is_some()
+}
+
+// `t!(locale, "key", name = value, ...)` looks up a message and fills in
+// its `{ $name }` placeholders.
+macro_rules! t {
+    ($locale:expr, $key:expr) => {
+        $crate::i18n::message($locale, $key, &[])
+    };
+    ($locale:expr, $key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::i18n::message($locale, $key, &[$((stringify!($name), $value.to_string())),+])
+    };
+}
+
+pub(crate) use t;
+
+pub fn format_number(locale: Locale, value: i64) -> String {
+    let group = locale.group_separator();
+    let digits = value.unsigned_abs().to_string();
+
+    let mut formatted = String::with_capacity(digits.len() + digits.len() / 3 + 1);
+    if value < 0 {
+        formatted.push('-');
+    }
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            formatted.push(group);
+        }
+        formatted.push(c);
+    }
+    formatted
+}
+
+// The two largest units, e.g. `1h 30m`; zero parts are left out.
+pub fn format_duration(locale: Locale, duration: Duration) -> String {
+    const UNITS: [(&str, u64); 4] = [
+        ("duration-days", 86_400),
+        ("duration-hours", 3_600),
+        ("duration-minutes", 60),
+        ("duration-seconds", 1),
+    ];
+
+    let secs = duration.as_secs();
+    let largest = UNITS.iter().position(|(_, size)| secs >= *size).unwrap_or(UNITS.len() - 1);
+
+    let mut remaining = secs;
+    let mut parts = Vec::with_capacity(2);
+    for (i, (key, size)) in UNITS.iter().enumerate().skip(largest).take(2) {
+        let count = remaining / size;
+        remaining %= size;
+        if count > 0 || i == largest {
+            parts.push(t!(locale, key, count = count));
+        }
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn test_every_key_exists_in_every_locale() {
+        let english = &CATALOGS[&Locale::En];
+        assert!(english.len() > 0);
+
+        for locale in Locale::ALL {
+            let catalog = &CATALOGS[&locale];
+            let missing: Vec<&str> = english.keys().filter(|key| catalog.get(key).is_none()).collect();
+            let extra: Vec<&str> = catalog.keys().filter(|key| english.get(key).is_none()).collect();
+            assert!(missing.is_empty(), "{} is missing {:?}", locale.code(), missing);
+            assert!(extra.is_empty(), "{} has keys English lacks: {:?}", locale.code(), extra);
+
+            // A translation can reorder placeholders but not drop or rename them.
+            for key in english.keys() {
+                let expected: BTreeSet<&str> = catalog::placeholders(english.get(key).unwrap()).collect();
+                let actual: BTreeSet<&str> = catalog::placeholders(catalog.get(key).unwrap()).collect();
+                assert_eq!(expected, actual, "{} `{}` has different placeholders", locale.code(), key);
+            }
+        }
+    }
+
+    #[test]
+    fn test_message_fills_placeholders() {
+        assert_eq!(t!(Locale::En, "prefix-changed", prefix = "?"), "Prefix changed to: `?`");
+        assert_eq!(t!(Locale::Es, "prefix-changed", prefix = "?"), "Prefijo cambiado a: `?`");
+        assert_eq!(t!(Locale::Ko, "no-such-key"), "no-such-key");
+    }
+
+    #[test]
+    fn test_locale_parse() {
+        assert_eq!(Locale::parse("pt-BR"), Some(Locale::Pt));
+        assert_eq!(Locale::parse("es-419"), Some(Locale::Es));
+        assert_eq!(Locale::parse("KO"), Some(Locale::Ko));
+        assert_eq!(Locale::parse("en-US"), Some(Locale::En));
+        assert_eq!(Locale::parse("fr"), None);
+    }
+
+    #[test]
+    fn test_number_formatting() {
+        assert_eq!(format_number(Locale::En, 1_043_952), "1,043,952");
+        assert_eq!(format_number(Locale::Pt, 1_043_952), "1.043.952");
+        assert_eq!(format_number(Locale::Es, -1_000), "-1.000");
+        assert_eq!(format_number(Locale::Ko, 999), "999");
+    }
+
+    #[test]
+    fn test_duration_formatting() {
+        assert_eq!(format_duration(Locale::En, Duration::from_secs(30)), "30s");
+        assert_eq!(format_duration(Locale::En, Duration::from_secs(90)), "1m 30s");
+        assert_eq!(format_duration(Locale::En, Duration::from_secs(7_200)), "2h");
+        assert_eq!(format_duration(Locale::Es, Duration::from_secs(3_660)), "1 h 1 min");
+        assert_eq!(format_duration(Locale::Ko, Duration::from_secs(90_000)), "1일 1시간");
+        assert_eq!(format_duration(Locale::En, Duration::ZERO), "0s");
+    }
+}
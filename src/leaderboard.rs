@@ -0,0 +1,310 @@
+use serenity::model::id::{GuildId, UserId};
+use serenity::prelude::*;
+use std::cmp::Reverse;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::cache::{CachedData, TtlCache, ttl};
+use crate::config::{ConfigMap, DatabaseContainer, RiotClientContainer};
+use crate::members;
+use crate::profile_cache::ProfileCache;
+use crate::riot::{LeagueEntry, Platform, RiotApiError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RankedQueue {
+    Solo,
+    Flex,
+}
+
+impl RankedQueue {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "solo" | "soloq" | "duo" => Some(RankedQueue::Solo),
+            "flex" => Some(RankedQueue::Flex),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RankedQueue::Solo => "solo",
+            RankedQueue::Flex => "flex",
+        }
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            RankedQueue::Solo => "Ranked Solo/Duo",
+            RankedQueue::Flex => "Ranked Flex",
+        }
+    }
+
+    // Message key of `title`, for localized replies.
+    pub fn key(&self) -> &'static str {
+        match self {
+            RankedQueue::Solo => "queue-solo",
+            RankedQueue::Flex => "queue-flex",
+        }
+    }
+
+    pub fn queue_type(&self) -> &'static str {
+        match self {
+            RankedQueue::Solo => LeagueEntry::RANKED_SOLO,
+            RankedQueue::Flex => LeagueEntry::RANKED_FLEX,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LeaderboardEntry {
+    pub user_id: UserId,
+    pub name: String,
+    pub riot_id: String,
+    pub entry: LeagueEntry,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Leaderboard {
+    pub entries: Vec<LeaderboardEntry>,
+    pub unranked: usize,
+}
+
+impl Leaderboard {
+    // Best first; ties keep the order members were added in.
+    fn sorted(mut entries: Vec<LeaderboardEntry>, unranked: usize) -> Self {
+        entries.sort_by_key(|entry| Reverse(entry.entry.standing()));
+        Leaderboard { entries, unranked }
+    }
+}
+
+// One league-v4 call per member gives both queues, so they are built and
+// cached together.
+#[derive(Debug, Clone, Default)]
+pub struct GuildLeaderboard {
+    pub solo: Leaderboard,
+    pub flex: Leaderboard,
+}
+
+impl GuildLeaderboard {
+    pub fn queue(&self, queue: RankedQueue) -> &Leaderboard {
+        match queue {
+            RankedQueue::Solo => &self.solo,
+            RankedQueue::Flex => &self.flex,
+        }
+    }
+}
+
+const CAPACITY: usize = 1_000;
+
+pub struct LeaderboardCache;
+
+impl TypeMapKey for LeaderboardCache {
+    type Value = Arc<TtlCache<GuildId, CachedData<GuildLeaderboard>>>;
+}
+
+pub fn create_leaderboard_cache() -> Arc<TtlCache<GuildId, CachedData<GuildLeaderboard>>> {
+    Arc::new(TtlCache::new("Leaderboard", CAPACITY, ttl::LEADERBOARD_KEPT))
+}
+
+#[derive(Debug)]
+pub enum LeaderboardError {
+    Database(sqlx::Error),
+    Discord(serenity::Error),
+}
+
+impl fmt::Display for LeaderboardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LeaderboardError::Database(e) => write!(f, "database error: {}", e),
+            LeaderboardError::Discord(e) => write!(f, "Discord error: {}", e),
+        }
+    }
+}
+
+// Serves the last board built, however old, and rebuilds a stale one in the
+// background. `None` means there is no board yet; one is being built.
+pub async fn get(ctx: &Context, guild_id: GuildId) -> Option<(GuildLeaderboard, Option<Duration>)> {
+    let cache = {
+        let data = ctx.data.read().await;
+        data.get::<LeaderboardCache>().expect("LeaderboardCache not found").clone()
+    };
+
+    let cached = cache.get(&guild_id);
+    if cached.as_ref().is_none_or(is_stale) {
+        let ctx = ctx.clone();
+        tokio::spawn(async move { refresh(&ctx, &cache, guild_id).await });
+    }
+
+    cached.map(|cached| {
+        let age = cached.age();
+        (cached.data, age)
+    })
+}
+
+fn is_stale(board: &CachedData<GuildLeaderboard>) -> bool {
+    board.age().is_none_or(|age| age >= ttl::LEADERBOARD)
+}
+
+// Builds under the guild's load lock and skips the build if one finished
+// while waiting, so a guild never has two builds running.
+async fn refresh(ctx: &Context, cache: &TtlCache<GuildId, CachedData<GuildLeaderboard>>, guild_id: GuildId) {
+    cache.locked(guild_id, || async {
+        if cache.get(&guild_id).is_some_and(|cached| !is_stale(&cached)) {
+            return;
+        }
+
+        match build(ctx, guild_id).await {
+            Ok(board) => cache.insert(guild_id, CachedData::new(board)),
+            Err(e) => eprintln!("Failed to build leaderboard for {}: {}", guild_id, e),
+        }
+    }).await
+}
+
+pub async fn build(ctx: &Context, guild_id: GuildId) -> Result<GuildLeaderboard, LeaderboardError> {
+    let (db, config_map, profile_cache, riot_client) = {
+        let data = ctx.data.read().await;
+        (
+            data.get::<DatabaseContainer>().expect("Database not found").clone(),
+            data.get::<ConfigMap>().expect("ConfigMap not found").clone(),
+            data.get::<ProfileCache>().expect("ProfileCache not found").clone(),
+            data.get::<RiotClientContainer>().expect("RiotClient not found").clone(),
+        )
+    };
+
+    let require_verified = config_map
+        .get(&guild_id)
+        .map(|config| config.require_verified)
+        .unwrap_or(false);
+
+    let mut links: HashMap<UserId, _> = db
+        .get_primary_links()
+        .await
+        .map_err(LeaderboardError::Database)?
+        .into_iter()
+        .filter(|link| link.verified || !require_verified)
+        .map(|link| (link.discord_user_id, link))
+        .collect();
+
+    let members = members::fetch_all(&ctx.http, guild_id)
+        .await
+        .map_err(LeaderboardError::Discord)?;
+
+    let mut solo = Vec::new();
+    let mut flex = Vec::new();
+    let mut unranked = (0, 0);
+
+    for member in members {
+        let link = match links.remove(&member.user.id) {
+            Some(link) => link,
+            None => continue,
+        };
+
+        let (puuid, platform) = match (&link.riot_puuid, Platform::from_game_region(&link.region)) {
+            (Some(puuid), Some(platform)) => (puuid.clone(), platform),
+            _ => continue,
+        };
+
+        // A fresh `rank` lookup already has both queues.
+        let cached = profile_cache
+            .get(&puuid)
+            .map(|cached| cached.data.solo.iter().chain(&cached.data.flex).cloned().collect::<Vec<_>>());
+
+        let entries = match cached {
+            Some(entries) => entries,
+            None => match riot_client.get_league_entries_by_puuid(&puuid, platform).await {
+                Ok(entries) => entries,
+                Err(RiotApiError::NotFound) => Vec::new(),
+                Err(e) => {
+                    eprintln!("Failed to fetch league entries for {}: {}", member.user.id, e);
+                    continue;
+                }
+            },
+        };
+
+        for (queue, board, unranked) in [
+            (RankedQueue::Solo, &mut solo, &mut unranked.0),
+            (RankedQueue::Flex, &mut flex, &mut unranked.1),
+        ] {
+            match entries.iter().find(|entry| entry.queue_type == queue.queue_type() && entry.standing().is_some()) {
+                Some(entry) => board.push(LeaderboardEntry {
+                    user_id: member.user.id,
+                    name: member.display_name().to_string(),
+                    riot_id: link.riot_id(),
+                    entry: entry.clone(),
+                }),
+                None => *unranked += 1,
+            }
+        }
+    }
+
+    Ok(GuildLeaderboard {
+        solo: Leaderboard::sorted(solo, unranked.0),
+        flex: Leaderboard::sorted(flex, unranked.1),
+    })
+}
+
+pub fn spawn_refresher(ctx: Context) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ttl::LEADERBOARD);
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let cache = {
+                let data = ctx.data.read().await;
+                data.get::<LeaderboardCache>().expect("LeaderboardCache not found").clone()
+            };
+            for guild_id in cache.keys() {
+                refresh(&ctx, &cache, guild_id).await;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(user: u64, tier: &str, rank: &str, lp: i32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            user_id: UserId::new(user),
+            name: format!("user{}", user),
+            riot_id: format!("user{}#EUW", user),
+            entry: LeagueEntry {
+                queue_type: LeagueEntry::RANKED_SOLO.to_string(),
+                tier: tier.to_string(),
+                rank: rank.to_string(),
+                league_points: lp,
+                wins: 10,
+                losses: 10,
+                hot_streak: false,
+                veteran: false,
+                fresh_blood: false,
+                inactive: false,
+            },
+        }
+    }
+
+    #[test]
+    fn test_sorted_best_first() {
+        let board = Leaderboard::sorted(vec![
+            entry(1, "GOLD", "II", 50),
+            entry(2, "MASTER", "I", 20),
+            entry(3, "GOLD", "I", 0),
+            entry(4, "GOLD", "II", 80),
+        ], 2);
+
+        let order: Vec<u64> = board.entries.iter().map(|e| e.user_id.get()).collect();
+        assert_eq!(order, vec![2, 3, 4, 1]);
+        assert_eq!(board.unranked, 2);
+    }
+
+    #[test]
+    fn test_queue_parse() {
+        assert_eq!(RankedQueue::parse("SOLO"), Some(RankedQueue::Solo));
+        assert_eq!(RankedQueue::parse("flex"), Some(RankedQueue::Flex));
+        assert_eq!(RankedQueue::parse("aram"), None);
+    }
+}
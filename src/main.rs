@@ -2,30 +2,63 @@ use serenity::async_trait;
 use serenity::prelude::*;
 use serenity::model::gateway::Ready;
 use serenity::model::channel::Message;
+use serenity::model::application::Interaction;
 use std::env;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 
 mod commands;
 mod config;
 mod utils;
 mod database;
-mod user_cache;
+mod user_links;
+mod profile_cache;
+mod match_cache;
+mod cooldown;
+mod rank_roles;
+mod members;
+mod leaderboard;
+mod rank_history;
+mod feed;
+mod graph;
+mod presentation;
+mod i18n;
 mod riot;
 mod cache;
 
-use config::{ConfigMap, DatabaseContainer, create_config_map, get_prefix};
-use user_cache::{UserLinkCache, create_user_cache};
+use config::{BotOwners, ConfigMap, DatabaseContainer, StaticDataContainer, UserLocales, create_config_map, create_user_locales, get_prefix, parse_bot_owners};
+use user_links::UserLinkRepository;
+use profile_cache::{ProfileCache, create_profile_cache};
+use match_cache::{MatchHistoryCache, create_match_history_cache};
+use cooldown::{Cooldowns, create_cooldown_tracker};
+use leaderboard::{LeaderboardCache, create_leaderboard_cache};
 use database::Database;
-use riot::RiotClient;
+use riot::{ResponseCache, RiotClient};
+use riot::static_data::{self, DataSource, StaticDataStore};
 
 use crate::config::RiotClientContainer;
 
-struct Handler;
+struct Handler {
+    jobs_started: AtomicBool,
+}
 
 #[async_trait]
 impl EventHandler for Handler {
-    async fn ready(&self, _: Context, ready: Ready) {
+    async fn ready(&self, ctx: Context, ready: Ready) {
         println!("Bot is ready! Logged in as {}", ready.user.name);
+        commands::slash::register(&ctx).await;
+
+        // `ready` fires again after reconnects; only start background jobs once.
+        if !self.jobs_started.swap(true, Ordering::SeqCst) {
+            rank_roles::spawn_sweeper(ctx.clone());
+            leaderboard::spawn_refresher(ctx.clone());
+            rank_history::spawn_snapshotter(ctx.clone());
+            feed::spawn_poller(ctx.clone());
+        }
+    }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        commands::slash::handle_interaction(&ctx, interaction).await;
     }
 
     async fn message(&self, ctx: Context, msg: Message) {
@@ -50,7 +83,7 @@ impl EventHandler for Handler {
 
         let command = parsed.remove(0);
         let args = parsed;
-        commands::handle_command(&ctx, &msg, &command, args).await;
+        commands::handle_command(&ctx, &msg, &prefix, &command, args).await;
     }
 }
 
@@ -58,20 +91,35 @@ impl EventHandler for Handler {
 async fn main() {
     dotenv::dotenv().ok();
 
-    let token = env::var("DISCORD_TOKEN")
-        .expect("Missing DISCORD_TOKEN in environment");
-
     let database_url = env::var("DATABASE_URL")
         .unwrap_or_else(|_| "sqlite:bot.db?mode=rwc".to_string());
 
+    // `rollback [version]` reverts migrations for development and exits.
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("rollback") {
+        rollback(&database_url, args.get(1)).await;
+        return;
+    }
+
+    let token = env::var("DISCORD_TOKEN")
+        .expect("Missing DISCORD_TOKEN in environment");
+
     let riot_api_key = env::var("RIOT_API_KEY")
         .expect("Expected RIOT_API_KEY in environment");
 
+    let bot_owners = env::var("BOT_OWNERS")
+        .map(|owners| parse_bot_owners(&owners))
+        .unwrap_or_default();
+
     let db = Database::new(&database_url)
         .await
         .expect("Failed to initialize database");
 
-    let riot_client = RiotClient::new(riot_api_key);
+    let mut riot_client = RiotClient::new(riot_api_key);
+    if let Ok(base_url) = env::var("RIOT_API_BASE_URL") {
+        println!("Using Riot API base URL {}", base_url);
+        riot_client = riot_client.with_base_url(base_url);
+    }
 
     let config_map = create_config_map();
     match db.load_all_configs().await {
@@ -86,23 +134,81 @@ async fn main() {
         }
     }
 
-    let user_cache = create_user_cache();
+    let user_locales = create_user_locales();
+    match db.load_user_languages().await {
+        Ok(languages) => {
+            for (user_id, locale) in languages {
+                user_locales.insert(user_id, locale);
+            }
+            println!("Loaded {} user languages from database", user_locales.len());
+        }
+        Err(e) => {
+            eprintln!("Failed to load user languages from database: {}", e);
+        }
+    }
+
+    let db = Arc::new(db);
+
+    // `RIOT_CACHE=sqlite` keeps Riot responses across restarts; otherwise
+    // they're only held in memory. Unless `RIOT_CACHE_STALE=off`, old
+    // responses are served while Riot is failing or rate limiting us.
+    let persist_responses = env::var("RIOT_CACHE").is_ok_and(|mode| mode == "sqlite");
+    let mut response_cache = ResponseCache::new(persist_responses.then(|| db.clone()));
+    if env::var("RIOT_CACHE_STALE").map_or(true, |mode| mode != "off") {
+        response_cache = response_cache.with_stale_fallback();
+    }
+    response_cache.prune().await;
+    response_cache.spawn_sweeper();
+    let riot_client = riot_client.with_cache(response_cache);
+
+    let user_links = Arc::new(UserLinkRepository::new(db.clone()));
+    user_links.spawn_sweeper();
+
+    let profile_cache = create_profile_cache();
+    profile_cache.spawn_sweeper(cache::SWEEP_INTERVAL);
+    let match_history_cache = create_match_history_cache();
+    match_history_cache.spawn_sweeper(cache::SWEEP_INTERVAL);
+    let cooldowns = create_cooldown_tracker();
+    let leaderboard_cache = create_leaderboard_cache();
+    leaderboard_cache.spawn_sweeper(cache::SWEEP_INTERVAL);
+
+    // A Data Dragon URL, or a local dragontail directory or .tgz.
+    let static_data_source = env::var("DDRAGON_SOURCE").unwrap_or_else(|_| static_data::DDRAGON_BASE_URL.to_string());
+    let static_data = StaticDataStore::default();
+    static_data::spawn_refresher(static_data.clone(), DataSource::parse(&static_data_source));
+
+    let sweeper = cooldowns.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(cooldown::MAX_WINDOW);
+        loop {
+            interval.tick().await;
+            sweeper.sweep(cooldown::MAX_WINDOW, std::time::Instant::now());
+        }
+    });
 
     let intents = GatewayIntents::GUILD_MESSAGES 
         | GatewayIntents::MESSAGE_CONTENT
-        | GatewayIntents::GUILDS;
+        | GatewayIntents::GUILDS
+        | GatewayIntents::GUILD_MEMBERS;
 
     let mut client = Client::builder(token, intents)
-        .event_handler(Handler)
+        .event_handler(Handler { jobs_started: AtomicBool::new(false) })
         .await
         .expect("Error creating client");
 
     {
         let mut data = client.data.write().await;
         data.insert::<ConfigMap>(config_map);
-        data.insert::<DatabaseContainer>(Arc::new(db));
-        data.insert::<UserLinkCache>(user_cache);
+        data.insert::<DatabaseContainer>(db);
+        data.insert::<BotOwners>(Arc::new(bot_owners));
+        data.insert::<UserLocales>(user_locales);
+        data.insert::<UserLinkRepository>(user_links);
+        data.insert::<ProfileCache>(profile_cache);
+        data.insert::<MatchHistoryCache>(match_history_cache);
+        data.insert::<Cooldowns>(cooldowns);
+        data.insert::<LeaderboardCache>(leaderboard_cache);
         data.insert::<RiotClientContainer>(Arc::new(riot_client));
+        data.insert::<StaticDataContainer>(static_data);
     }
 
     if let Err(why) = client.start().await {
@@ -110,3 +216,24 @@ async fn main() {
     }
 }
 
+
+async fn rollback(database_url: &str, target: Option<&String>) {
+    let db = Database::connect(database_url)
+        .await
+        .expect("Failed to connect to database");
+
+    let current = db.schema_version()
+        .await
+        .expect("Failed to read schema version");
+
+    let target = match target {
+        Some(version) => version.parse().expect("Rollback target must be a migration number"),
+        None => (current - 1).max(0),
+    };
+
+    match db.rollback(target).await {
+        Ok(reverted) if reverted.is_empty() => println!("Nothing to roll back (schema version {})", current),
+        Ok(reverted) => println!("Rolled back migrations {:?}, schema version is now {}", reverted, target),
+        Err(e) => eprintln!("Rollback failed: {}", e),
+    }
+}
@@ -0,0 +1,22 @@
+use serenity::prelude::TypeMapKey;
+use std::sync::Arc;
+use crate::cache::{CachedData, TtlCache, ttl};
+
+const CAPACITY: usize = 5_000;
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct MatchHistoryKey {
+    pub puuid: String,
+    pub count: u32,
+    pub queue: Option<i32>,
+}
+
+pub struct MatchHistoryCache;
+
+impl TypeMapKey for MatchHistoryCache {
+    type Value = Arc<TtlCache<MatchHistoryKey, CachedData<Vec<String>>>>;
+}
+
+pub fn create_match_history_cache() -> Arc<TtlCache<MatchHistoryKey, CachedData<Vec<String>>>> {
+    Arc::new(TtlCache::new("Match history", CAPACITY, ttl::MATCH_HISTORY))
+}
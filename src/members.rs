@@ -0,0 +1,23 @@
+use serenity::http::Http;
+use serenity::model::guild::Member;
+use serenity::model::id::GuildId;
+
+const PAGE_SIZE: u64 = 1000;
+
+// Every non-bot member of the guild. Needs the GUILD_MEMBERS intent.
+pub async fn fetch_all(http: &Http, guild_id: GuildId) -> serenity::Result<Vec<Member>> {
+    let mut members = Vec::new();
+    let mut after = None;
+
+    loop {
+        let page = guild_id.members(http, Some(PAGE_SIZE), after).await?;
+        let full = page.len() as u64 == PAGE_SIZE;
+        after = page.last().map(|member| member.user.id);
+
+        members.extend(page.into_iter().filter(|member| !member.user.bot));
+
+        if !full {
+            return Ok(members);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+use serenity::builder::{CreateEmbed, CreateEmbedFooter};
+use std::time::Duration;
+use crate::database::UserLink;
+use crate::i18n::{Locale, t};
+use crate::riot::static_data::DDRAGON_BASE_URL;
+use crate::riot::{LeagueEntry, MatchDto, Platform, RankedProfile, Tier, queue_name};
+
+pub const BRAND_COLOR: u32 = 0x5865F2;
+pub const UNRANKED_COLOR: u32 = 0x99AAB5;
+pub const WIN_COLOR: u32 = 0x57F287;
+pub const LOSS_COLOR: u32 = 0xED4245;
+pub const ERROR_COLOR: u32 = 0xED4245;
+
+// A reply that renders either as an embed or, where the bot can't embed
+// links, as the same content in plain text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Card {
+    title: Option<String>,
+    description: Option<String>,
+    color: u32,
+    thumbnail: Option<String>,
+    fields: Vec<(String, String, bool)>,
+    footer: Option<String>,
+}
+
+impl Card {
+    pub fn new(color: u32) -> Self {
+        Card { title: None, description: None, color, thumbnail: None, fields: Vec::new(), footer: None }
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn thumbnail(mut self, url: Option<String>) -> Self {
+        self.thumbnail = url;
+        self
+    }
+
+    pub fn field(mut self, name: impl Into<String>, value: impl Into<String>, inline: bool) -> Self {
+        self.fields.push((name.into(), value.into(), inline));
+        self
+    }
+
+    pub fn footer(mut self, footer: Option<String>) -> Self {
+        self.footer = footer;
+        self
+    }
+
+    pub fn embed(&self) -> CreateEmbed {
+        let mut embed = CreateEmbed::new().color(self.color);
+        if let Some(title) = &self.title {
+            embed = embed.title(title);
+        }
+        if let Some(description) = &self.description {
+            embed = embed.description(description);
+        }
+        if let Some(url) = &self.thumbnail {
+            embed = embed.thumbnail(url);
+        }
+        for (name, value, inline) in &self.fields {
+            embed = embed.field(name, value, *inline);
+        }
+        if let Some(footer) = &self.footer {
+            embed = embed.footer(CreateEmbedFooter::new(footer));
+        }
+        embed
+    }
+
+    // Thumbnails are dropped; everything else keeps its order.
+    pub fn plain_text(&self) -> String {
+        let mut lines = Vec::new();
+        if let Some(title) = &self.title {
+            lines.push(format!("**{}**", title));
+        }
+        if let Some(description) = &self.description {
+            lines.push(description.clone());
+        }
+        for (name, value, _) in &self.fields {
+            lines.push(format!("**{}:** {}", name, value));
+        }
+        if let Some(footer) = &self.footer {
+            lines.push(format!("_{}_", footer));
+        }
+        lines.join("\n")
+    }
+}
+
+pub fn error(message: impl Into<String>) -> Card {
+    Card::new(ERROR_COLOR).description(message)
+}
+
+pub fn tier_color(tier: Option<Tier>) -> u32 {
+    tier.map_or(UNRANKED_COLOR, |tier| tier.color())
+}
+
+pub fn profile_icon_url(version: Option<&str>, icon_id: i64) -> Option<String> {
+    Some(format!("{}/cdn/{}/img/profileicon/{}.png", DDRAGON_BASE_URL, version?, icon_id))
+}
+
+// Fresh data gets no footer; anything served from a cache says how old it is.
+pub fn cache_footer(locale: Locale, age: Option<Duration>) -> Option<String> {
+    let minutes = age?.as_secs() / 60;
+    match minutes {
+        0 => None,
+        1 => Some(t!(locale, "cache-updated-minute")),
+        minutes if minutes < 120 => Some(t!(locale, "cache-updated-minutes", count = minutes)),
+        minutes => Some(t!(locale, "cache-updated-hours", count = minutes / 60)),
+    }
+}
+
+pub fn link_lines(links: &[UserLink]) -> String {
+    links
+        .iter()
+        .map(|link| {
+            let marker = if link.is_primary { "⭐" } else { "🎮" };
+            let verified = if link.verified { " ✅" } else { "" };
+            format!("{} **{}** - {}{}", marker, link.riot_id(), link.region.to_uppercase(), verified)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn linked_accounts(locale: Locale, links: &[UserLink]) -> Card {
+    let title = if links.len() == 1 { "linked-accounts-one" } else { "linked-accounts-many" };
+    Card::new(BRAND_COLOR).title(t!(locale, title)).description(link_lines(links))
+}
+
+// Colored by the better of the two ranked queues.
+pub fn profile(
+    locale: Locale,
+    display_name: &str,
+    platform: Platform,
+    profile: &RankedProfile,
+    icon_url: Option<String>,
+    age: Option<Duration>,
+) -> Card {
+    let best_tier = [&profile.solo, &profile.flex]
+        .into_iter()
+        .flatten()
+        .filter_map(LeagueEntry::ranked_tier)
+        .max();
+
+    Card::new(tier_color(best_tier))
+        .title(format!("{} ({})", display_name, platform.game_region().to_uppercase()))
+        .description(t!(locale, "profile-level", level = profile.summoner.summoner_level))
+        .thumbnail(icon_url)
+        .field(format!("🏆 {}", t!(locale, "queue-solo")), league_entry(locale, profile.solo.as_ref()), true)
+        .field(format!("🏆 {}", t!(locale, "queue-flex")), league_entry(locale, profile.flex.as_ref()), true)
+        .footer(cache_footer(locale, age))
+}
+
+pub fn league_entry(locale: Locale, entry: Option<&LeagueEntry>) -> String {
+    let Some(entry) = entry else {
+        return t!(locale, "unranked");
+    };
+
+    let standing = match entry.ranked_tier() {
+        Some(tier) if tier.is_apex() => tier.title().to_string(),
+        Some(tier) => format!("{} {}", tier.title(), entry.rank),
+        None => entry.tier.clone(),
+    };
+
+    let mut value = t!(
+        locale,
+        "league-entry",
+        standing = standing,
+        lp = entry.league_points,
+        wins = entry.wins,
+        losses = entry.losses,
+        win_rate = format!("{:.1}", entry.win_rate()),
+    );
+    if entry.hot_streak {
+        value.push_str(&format!(" {}", t!(locale, "hot-streak")));
+    }
+    value
+}
+
+pub fn match_history(locale: Locale, riot_id: &str, lines: &[String], age: Option<Duration>) -> Card {
+    Card::new(BRAND_COLOR)
+        .title(t!(locale, "history-title", riot_id = riot_id))
+        .description(lines.join("\n"))
+        .footer(cache_footer(locale, age))
+}
+
+pub fn match_line(match_dto: &MatchDto, puuid: &str) -> Option<String> {
+    let participant = match_dto.participant(puuid)?;
+    let result = if participant.win { "✅" } else { "❌" };
+
+    Some(format!(
+        "{} **{}** {}/{}/{} ({:.2} KDA) · {} CS · {} · {}",
+        result,
+        participant.champion_name,
+        participant.kills,
+        participant.deaths,
+        participant.assists,
+        participant.kda(),
+        participant.creep_score(),
+        duration(match_dto.info.game_duration),
+        queue_name(match_dto.info.queue_id),
+    ))
+}
+
+pub fn duration(seconds: i64) -> String {
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUMMONER: &str = include_str!("../tests/fixtures/riot/summoner.json");
+    const LEAGUE_ENTRIES: &str = include_str!("../tests/fixtures/riot/league_entries.json");
+    const MATCH: &str = include_str!("../tests/fixtures/riot/match.json");
+
+    fn sample_profile() -> RankedProfile {
+        let entries: Vec<LeagueEntry> = serde_json::from_str(LEAGUE_ENTRIES).unwrap();
+        RankedProfile {
+            summoner: serde_json::from_str(SUMMONER).unwrap(),
+            solo: entries.iter().find(|e| e.queue_type == LeagueEntry::RANKED_SOLO).cloned(),
+            flex: entries.iter().find(|e| e.queue_type == LeagueEntry::RANKED_FLEX).cloned(),
+        }
+    }
+
+    #[test]
+    fn test_profile_card() {
+        let profile = sample_profile();
+        let icon = profile_icon_url(Some("15.20.1"), profile.summoner.profile_icon_id);
+        let card = super::profile(Locale::En, "Hide on bush#KR1", Platform::Kr, &profile, icon, Some(Duration::from_secs(300)));
+
+        let best = profile.solo.iter().chain(&profile.flex).filter_map(LeagueEntry::ranked_tier).max();
+        assert_eq!(card.color, tier_color(best));
+        assert_eq!(
+            card.thumbnail.as_deref(),
+            Some(format!("{}/cdn/15.20.1/img/profileicon/{}.png", DDRAGON_BASE_URL, profile.summoner.profile_icon_id).as_str())
+        );
+
+        let text = card.plain_text();
+        assert!(text.starts_with("**Hide on bush#KR1 (KR)**\nLevel "));
+        assert!(text.contains("**🏆 Ranked Solo/Duo:** "));
+        assert!(text.ends_with("_Updated 5 minutes ago_"));
+    }
+
+    #[test]
+    fn test_unranked_profile_is_grey() {
+        let mut profile = sample_profile();
+        profile.solo = None;
+        profile.flex = None;
+
+        let card = super::profile(Locale::En, "x#y", Platform::Euw1, &profile, None, None);
+        assert_eq!(card.color, UNRANKED_COLOR);
+        assert!(card.plain_text().contains("**🏆 Ranked Flex:** Unranked"));
+        assert_eq!(card.footer, None);
+    }
+
+    #[test]
+    fn test_profile_card_is_localized() {
+        let mut profile = sample_profile();
+        profile.flex = None;
+
+        let card = super::profile(Locale::Ko, "Hide on bush#KR1", Platform::Kr, &profile, None, Some(Duration::from_secs(120)));
+        let text = card.plain_text();
+        assert!(text.contains("\n레벨 "));
+        assert!(text.contains("승 "));
+        assert!(text.contains("**🏆 자유 랭크:** 언랭크"));
+        assert!(text.ends_with("_2분 전 업데이트됨_"));
+    }
+
+    #[test]
+    fn test_cache_footer() {
+        assert_eq!(cache_footer(Locale::En, None), None);
+        assert_eq!(cache_footer(Locale::En, Some(Duration::from_secs(59))), None);
+        assert_eq!(cache_footer(Locale::En, Some(Duration::from_secs(60))).as_deref(), Some("Updated 1 minute ago"));
+        assert_eq!(cache_footer(Locale::En, Some(Duration::from_secs(3 * 3600))).as_deref(), Some("Updated 3 hours ago"));
+        assert_eq!(cache_footer(Locale::Es, Some(Duration::from_secs(300))).as_deref(), Some("Actualizado hace 5 minutos"));
+    }
+
+    #[test]
+    fn test_plain_text_matches_the_old_replies() {
+        assert_eq!(error("❌ Something broke").plain_text(), "❌ Something broke");
+
+        let match_dto: MatchDto = serde_json::from_str(MATCH).unwrap();
+        let lines = vec![match_line(&match_dto, "test-puuid-hide-on-bush").unwrap()];
+        let card = match_history(Locale::En, "Hide on bush#KR1", &lines, None);
+        assert_eq!(card.plain_text(), format!("**Recent matches for Hide on bush#KR1**\n{}", lines[0]));
+    }
+}
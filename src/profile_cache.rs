@@ -0,0 +1,35 @@
+use serenity::prelude::TypeMapKey;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::riot::{Platform, RankedProfile, RiotApiError, RiotClient};
+use crate::cache::{CachedData, TtlCache, ttl};
+
+const CAPACITY: usize = 10_000;
+
+pub struct ProfileCache;
+
+impl TypeMapKey for ProfileCache {
+    type Value = Arc<TtlCache<String, CachedData<RankedProfile>>>;
+}
+
+pub fn create_profile_cache() -> Arc<TtlCache<String, CachedData<RankedProfile>>> {
+    Arc::new(TtlCache::new("Profile", CAPACITY, ttl::SUMMONER_PROFILE))
+}
+
+// Returns the profile and how old it is. Concurrent lookups of the same
+// account share one request.
+pub async fn get_ranked_profile(
+    cache: &TtlCache<String, CachedData<RankedProfile>>,
+    riot_client: &RiotClient,
+    puuid: &str,
+    platform: Platform,
+) -> Result<(RankedProfile, Option<Duration>), RiotApiError> {
+    let cached = cache
+        .get_or_load(puuid.to_string(), || async {
+            riot_client.get_ranked_profile(puuid, platform).await.map(CachedData::new)
+        })
+        .await?;
+
+    let age = cached.age();
+    Ok((cached.data, age))
+}
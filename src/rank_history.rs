@@ -0,0 +1,385 @@
+use chrono::{DateTime, Datelike, TimeZone, Utc};
+use serenity::prelude::*;
+use std::time::Duration;
+use crate::config::{DatabaseContainer, RiotClientContainer};
+use crate::database::{Database, RankSnapshot};
+use crate::graph::{LpPoint, Marker};
+use crate::riot::{LeagueEntry, Platform, RiotApiError, RiotClient, Tier};
+
+pub const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Window {
+    Day,
+    Week,
+    Season,
+}
+
+impl Window {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "day" | "today" => Some(Window::Day),
+            "week" => Some(Window::Week),
+            "season" | "year" => Some(Window::Season),
+            _ => None,
+        }
+    }
+
+    // Message key of the window's name, e.g. "last 7 days".
+    pub fn key(&self) -> &'static str {
+        match self {
+            Window::Day => "window-day",
+            Window::Week => "window-week",
+            Window::Season => "window-season",
+        }
+    }
+
+    // Ranked seasons start in January, so the season is the calendar year.
+    pub fn since(&self, now: DateTime<Utc>) -> i64 {
+        match self {
+            Window::Day => now.timestamp() - 24 * 60 * 60,
+            Window::Week => now.timestamp() - 7 * 24 * 60 * 60,
+            Window::Season => Utc
+                .with_ymd_and_hms(now.year(), 1, 1, 0, 0, 0)
+                .single()
+                .map(|start| start.timestamp())
+                .unwrap_or(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Progress {
+    pub start: RankSnapshot,
+    pub end: RankSnapshot,
+    pub lp_change: i32,
+    pub promotions: u32,
+    pub demotions: u32,
+    pub wins: i32,
+    pub losses: i32,
+}
+
+impl Progress {
+    pub fn win_rate(&self) -> f64 {
+        let games = self.wins + self.losses;
+        if games == 0 {
+            return 0.0;
+        }
+        self.wins as f64 / games as f64 * 100.0
+    }
+}
+
+// Works on snapshots in capture order. Win/loss totals reset between
+// seasons, so games are added up step by step instead of end minus start.
+pub fn summarize(snapshots: &[RankSnapshot]) -> Option<Progress> {
+    let start = snapshots.first()?;
+    let end = snapshots.last()?;
+
+    let mut progress = Progress {
+        start: start.clone(),
+        end: end.clone(),
+        lp_change: ladder_points(end)? - ladder_points(start)?,
+        promotions: 0,
+        demotions: 0,
+        wins: 0,
+        losses: 0,
+    };
+
+    for pair in snapshots.windows(2) {
+        let (before, after) = (&pair[0], &pair[1]);
+
+        if after.wins >= before.wins && after.losses >= before.losses {
+            progress.wins += after.wins - before.wins;
+            progress.losses += after.losses - before.losses;
+        } else {
+            progress.wins += after.wins;
+            progress.losses += after.losses;
+        }
+
+        match (division_step(before), division_step(after)) {
+            (Some(a), Some(b)) if b > a => progress.promotions += 1,
+            (Some(a), Some(b)) if b < a => progress.demotions += 1,
+            _ => {}
+        }
+    }
+
+    Some(progress)
+}
+
+// Whether `snapshots` say anything about the window starting at `since`.
+// Unchanged ranks aren't recorded again, so a lone snapshot from before the
+// window means nothing changed during it; one from inside it is just the
+// first record.
+pub fn has_history(snapshots: &[RankSnapshot], since: i64) -> bool {
+    match snapshots {
+        [] => false,
+        [only] => only.captured_at < since,
+        _ => true,
+    }
+}
+
+// The latest snapshot holds until `now`, so charts run up to the present
+// even when the last change was long ago.
+pub fn extend_to(snapshots: &mut Vec<RankSnapshot>, now: i64) {
+    if let Some(last) = snapshots.last().filter(|last| last.captured_at < now) {
+        let current = RankSnapshot { captured_at: now, ..last.clone() };
+        snapshots.push(current);
+    }
+}
+
+// Chart points on the same ladder as `summarize`, with division changes
+// marked. Snapshots with an unknown tier are skipped.
+pub fn lp_points(snapshots: &[RankSnapshot]) -> Vec<LpPoint> {
+    let mut points = Vec::with_capacity(snapshots.len());
+    let mut previous: Option<(Tier, u8)> = None;
+
+    for snapshot in snapshots {
+        let (Some(step), Some(ladder)) = (division_step(snapshot), ladder_points(snapshot)) else {
+            continue;
+        };
+
+        let marker = match previous {
+            Some(before) if step > before => Some(Marker::Promotion),
+            Some(before) if step < before => Some(Marker::Demotion),
+            _ => None,
+        };
+        previous = Some(step);
+
+        points.push(LpPoint { time: snapshot.captured_at, points: ladder, marker });
+    }
+
+    points
+}
+
+// LP on a single ladder: 400 per tier and 100 per division below Master,
+// where everyone shares one LP pool.
+fn ladder_points(snapshot: &RankSnapshot) -> Option<i32> {
+    let (tier, division) = division_step(snapshot)?;
+    let tier_index = Tier::ALL.iter().position(|t| *t == tier)? as i32;
+
+    if tier.is_apex() {
+        let master = Tier::ALL.iter().position(|t| *t == Tier::Master)? as i32;
+        Some(master * 400 + snapshot.league_points)
+    } else {
+        Some(tier_index * 400 + division as i32 * 100 + snapshot.league_points)
+    }
+}
+
+fn division_step(snapshot: &RankSnapshot) -> Option<(Tier, u8)> {
+    let tier = Tier::parse(&snapshot.tier)?;
+    if tier.is_apex() {
+        return Some((tier, 0));
+    }
+
+    let division = match snapshot.rank.as_str() {
+        "I" => 3,
+        "II" => 2,
+        "III" => 1,
+        _ => 0,
+    };
+    Some((tier, division))
+}
+
+pub fn format_rank(snapshot: &RankSnapshot) -> String {
+    match Tier::parse(&snapshot.tier) {
+        Some(tier) if tier.is_apex() => format!("{} {} LP", tier.title(), snapshot.league_points),
+        Some(tier) => format!("{} {} {} LP", tier.title(), snapshot.rank, snapshot.league_points),
+        None => "Unranked".to_string(),
+    }
+}
+
+// Records the account's current ranked entries. They come straight from
+// Riot, never from a cache, so LP from a game that just ended isn't missed.
+// Returns them so callers can show the latest state without a second
+// request.
+pub async fn capture(
+    db: &Database,
+    riot_client: &RiotClient,
+    puuid: &str,
+    platform: Platform,
+) -> Result<Vec<LeagueEntry>, RiotApiError> {
+    let entries = riot_client.get_league_entries_by_puuid(puuid, platform).await?;
+    let now = Utc::now().timestamp();
+
+    for entry in entries.iter().filter(|entry| entry.ranked_tier().is_some()) {
+        if let Err(e) = db.record_snapshot(puuid, entry, now).await {
+            eprintln!("Failed to record rank snapshot: {}", e);
+        }
+    }
+
+    Ok(entries)
+}
+
+pub fn spawn_snapshotter(ctx: Context) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SNAPSHOT_INTERVAL);
+        // Like the rank role sweeper, wait a full interval before the first
+        // run so restarts don't snapshot every account at once.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let (db, riot_client) = {
+                let data = ctx.data.read().await;
+                (
+                    data.get::<DatabaseContainer>().expect("Database not found").clone(),
+                    data.get::<RiotClientContainer>().expect("RiotClient not found").clone(),
+                )
+            };
+
+            let accounts = match db.get_tracked_accounts().await {
+                Ok(accounts) => accounts,
+                Err(e) => {
+                    eprintln!("Failed to load accounts for rank snapshots: {}", e);
+                    continue;
+                }
+            };
+
+            for (puuid, region) in accounts {
+                let platform = match Platform::from_game_region(&region) {
+                    Some(platform) => platform,
+                    None => continue,
+                };
+
+                match capture(&db, &riot_client, &puuid, platform).await {
+                    Ok(_) | Err(RiotApiError::NotFound) => {}
+                    Err(e) => eprintln!("Failed to snapshot rank for {}: {}", puuid, e),
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::riot::ResponseCache;
+    use crate::riot::mock_server::{MockResponse, MockServer};
+
+    const LEAGUE_ENTRIES: &str = include_str!("../tests/fixtures/riot/league_entries.json");
+
+    fn snapshot(tier: &str, rank: &str, lp: i32, wins: i32, losses: i32) -> RankSnapshot {
+        RankSnapshot {
+            tier: tier.to_string(),
+            rank: rank.to_string(),
+            league_points: lp,
+            wins,
+            losses,
+            captured_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_promotion_across_tiers() {
+        let progress = summarize(&[
+            snapshot("GOLD", "I", 80, 10, 10),
+            snapshot("PLATINUM", "IV", 0, 11, 10),
+            snapshot("PLATINUM", "IV", 20, 12, 10),
+        ]).unwrap();
+
+        assert_eq!(progress.lp_change, 40);
+        assert_eq!(progress.promotions, 1);
+        assert_eq!(progress.demotions, 0);
+        assert_eq!((progress.wins, progress.losses), (2, 0));
+    }
+
+    #[test]
+    fn test_demotion_and_losses() {
+        let progress = summarize(&[
+            snapshot("SILVER", "III", 10, 5, 5),
+            snapshot("SILVER", "IV", 75, 5, 6),
+        ]).unwrap();
+
+        assert_eq!(progress.lp_change, -35);
+        assert_eq!(progress.demotions, 1);
+        assert_eq!(progress.win_rate(), 0.0);
+    }
+
+    #[test]
+    fn test_lp_points_mark_division_changes() {
+        let points = lp_points(&[
+            snapshot("GOLD", "I", 80, 10, 10),
+            snapshot("PLATINUM", "IV", 0, 11, 10),
+            snapshot("", "", 0, 0, 0),
+            snapshot("GOLD", "I", 75, 11, 11),
+        ]);
+
+        let markers: Vec<_> = points.iter().map(|p| p.marker).collect();
+        assert_eq!(markers, vec![None, Some(Marker::Promotion), Some(Marker::Demotion)]);
+        assert_eq!(points[0].points, 3 * 400 + 300 + 80);
+        assert_eq!(points[1].points, 4 * 400);
+    }
+
+    #[test]
+    fn test_apex_lp_is_continuous() {
+        let progress = summarize(&[
+            snapshot("MASTER", "I", 250, 100, 80),
+            snapshot("GRANDMASTER", "I", 520, 110, 82),
+        ]).unwrap();
+
+        assert_eq!(progress.lp_change, 270);
+        assert_eq!(progress.promotions, 1);
+    }
+
+    #[test]
+    fn test_season_reset_counts_new_games() {
+        let progress = summarize(&[
+            snapshot("GOLD", "II", 0, 200, 190),
+            snapshot("SILVER", "I", 0, 3, 2),
+        ]).unwrap();
+
+        assert_eq!((progress.wins, progress.losses), (3, 2));
+    }
+
+    #[test]
+    fn test_baseline_alone_is_a_flat_result() {
+        let baseline = RankSnapshot { captured_at: 50, ..snapshot("GOLD", "II", 40, 10, 10) };
+        assert!(has_history(std::slice::from_ref(&baseline), 100));
+
+        let progress = summarize(std::slice::from_ref(&baseline)).unwrap();
+        assert_eq!(progress.lp_change, 0);
+        assert_eq!((progress.wins, progress.losses), (0, 0));
+
+        let first_record = RankSnapshot { captured_at: 150, ..baseline.clone() };
+        assert!(!has_history(&[first_record], 100));
+        assert!(!has_history(&[], 100));
+    }
+
+    #[test]
+    fn test_extend_to_now() {
+        let mut snapshots = vec![RankSnapshot { captured_at: 50, ..snapshot("GOLD", "II", 40, 10, 10) }];
+        extend_to(&mut snapshots, 200);
+        extend_to(&mut snapshots, 200);
+
+        let times: Vec<i64> = snapshots.iter().map(|s| s.captured_at).collect();
+        assert_eq!(times, vec![50, 200]);
+        assert_eq!(lp_points(&snapshots).len(), 2);
+    }
+
+    #[test]
+    fn test_season_starts_in_january() {
+        let now = Utc.with_ymd_and_hms(2026, 10, 18, 12, 0, 0).unwrap();
+        let start = Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(Window::Season.since(now), start.timestamp());
+    }
+
+    #[tokio::test]
+    async fn test_capture_ignores_cached_entries() {
+        let path = "/kr/lol/league/v4/entries/by-puuid/p";
+        let server = MockServer::start(vec![(path, vec![MockResponse::json(LEAGUE_ENTRIES)])]).await;
+        let client = server.client().with_cache(ResponseCache::new(None));
+        let db = Database::in_memory().await.unwrap();
+
+        // Looked up earlier, e.g. by `live` before the game ended; even with
+        // a response cache, capture asks Riot again.
+        client.get_league_entries_by_puuid("p", Platform::Kr).await.unwrap();
+
+        let entries = capture(&db, &client, "p", Platform::Kr).await.unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(server.requests(), vec![path, path]);
+
+        let solo = db.get_snapshots_since("p", "RANKED_SOLO_5x5", 0).await.unwrap();
+        assert_eq!(solo.len(), 1);
+    }
+}
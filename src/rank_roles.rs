@@ -0,0 +1,319 @@
+use dashmap::DashMap;
+use serenity::http::Http;
+use serenity::model::id::{GuildId, RoleId, UserId};
+use serenity::prelude::*;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::cache::{CachedData, TtlCache};
+use crate::config::{ConfigMap, DatabaseContainer, RiotClientContainer};
+use crate::database::{Database, ServerConfig, UserLink};
+use crate::members;
+use crate::profile_cache::{ProfileCache, get_ranked_profile};
+use crate::user_links::UserLinkRepository;
+use crate::riot::{Platform, RankedProfile, RiotApiError, RiotClient, Tier};
+
+pub const SWEEP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+const AUDIT_REASON: &str = "Rank role sync";
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct RoleChanges {
+    pub add: Vec<RoleId>,
+    pub remove: Vec<RoleId>,
+}
+
+impl RoleChanges {
+    pub fn is_empty(&self) -> bool {
+        self.add.is_empty() && self.remove.is_empty()
+    }
+}
+
+pub struct MemberChange {
+    pub name: String,
+    pub changes: RoleChanges,
+}
+
+#[derive(Default)]
+pub struct SyncReport {
+    pub checked: usize,
+    pub changes: Vec<MemberChange>,
+    pub errors: usize,
+}
+
+#[derive(Debug)]
+pub enum SyncError {
+    Database(sqlx::Error),
+    Discord(serenity::Error),
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncError::Database(e) => write!(f, "database error: {}", e),
+            SyncError::Discord(e) => write!(f, "Discord error: {}", e),
+        }
+    }
+}
+
+// The roles a member should gain and lose so that they hold exactly the
+// role mapped to `tier`, or none of the mapped roles when unranked.
+pub fn plan(rank_roles: &BTreeMap<Tier, RoleId>, current: &[RoleId], tier: Option<Tier>) -> RoleChanges {
+    let wanted = tier.and_then(|tier| rank_roles.get(&tier)).copied();
+
+    let add = wanted.filter(|role| !current.contains(role)).into_iter().collect();
+
+    let mut remove: Vec<RoleId> = rank_roles
+        .values()
+        .copied()
+        .filter(|role| Some(*role) != wanted && current.contains(role))
+        .collect();
+    remove.sort();
+    remove.dedup();
+
+    RoleChanges { add, remove }
+}
+
+// Shared handles copied out of the TypeMap so long sweeps don't hold the
+// data lock.
+struct Services {
+    db: Arc<Database>,
+    user_links: Arc<UserLinkRepository>,
+    config_map: Arc<DashMap<GuildId, ServerConfig>>,
+    profile_cache: Arc<TtlCache<String, CachedData<RankedProfile>>>,
+    riot_client: Arc<RiotClient>,
+}
+
+impl Services {
+    async fn from_context(ctx: &Context) -> Self {
+        let data = ctx.data.read().await;
+        Services {
+            db: data.get::<DatabaseContainer>().expect("Database not found").clone(),
+            user_links: data.get::<UserLinkRepository>().expect("UserLinkRepository not found").clone(),
+            config_map: data.get::<ConfigMap>().expect("ConfigMap not found").clone(),
+            profile_cache: data.get::<ProfileCache>().expect("ProfileCache not found").clone(),
+            riot_client: data.get::<RiotClientContainer>().expect("RiotClient not found").clone(),
+        }
+    }
+
+    fn rank_roles(&self, guild_id: GuildId) -> Option<(BTreeMap<Tier, RoleId>, bool)> {
+        let config = self.config_map.get(&guild_id)?;
+        if config.rank_roles.is_empty() {
+            return None;
+        }
+        Some((config.rank_roles.clone(), config.require_verified))
+    }
+
+    // Solo/Duo tier of the member's primary account. Links the guild does
+    // not accept count as unranked.
+    async fn tier(&self, link: Option<&UserLink>, require_verified: bool) -> Result<Option<Tier>, RiotApiError> {
+        let link = match link {
+            Some(link) if link.verified || !require_verified => link,
+            _ => return Ok(None),
+        };
+
+        let (puuid, platform) = match (&link.riot_puuid, Platform::from_game_region(&link.region)) {
+            (Some(puuid), Some(platform)) => (puuid, platform),
+            _ => return Ok(None),
+        };
+
+        let (profile, _) = get_ranked_profile(&self.profile_cache, &self.riot_client, puuid, platform).await?;
+        Ok(profile.solo.and_then(|entry| entry.ranked_tier()))
+    }
+}
+
+// Brings one member's rank role up to date, e.g. right after they link or
+// unlink an account. Failures are only logged; the sweep retries later.
+pub async fn sync_member(ctx: &Context, guild_id: GuildId, user_id: UserId) {
+    let services = Services::from_context(ctx).await;
+    let (rank_roles, require_verified) = match services.rank_roles(guild_id) {
+        Some(settings) => settings,
+        None => return,
+    };
+
+    let member = match guild_id.member(ctx, user_id).await {
+        Ok(member) => member,
+        Err(e) => {
+            eprintln!("Failed to fetch member {} for rank roles: {}", user_id, e);
+            return;
+        }
+    };
+
+    let link = match services.user_links.get_primary(user_id).await {
+        Ok(link) => link,
+        Err(e) => {
+            eprintln!("Failed to get user links for rank roles: {}", e);
+            return;
+        }
+    };
+
+    let tier = match services.tier(link.as_ref(), require_verified).await {
+        Ok(tier) => tier,
+        Err(e) => {
+            eprintln!("Failed to fetch rank for {}: {}", user_id, e);
+            return;
+        }
+    };
+
+    let changes = plan(&rank_roles, &member.roles, tier);
+    apply(&ctx.http, guild_id, user_id, &changes).await;
+}
+
+// Checks every member of the guild. With `dry_run` nothing is changed and the
+// report lists what would have been.
+pub async fn sync_guild(ctx: &Context, guild_id: GuildId, dry_run: bool) -> Result<SyncReport, SyncError> {
+    let services = Services::from_context(ctx).await;
+    let mut report = SyncReport::default();
+
+    let (rank_roles, require_verified) = match services.rank_roles(guild_id) {
+        Some(settings) => settings,
+        None => return Ok(report),
+    };
+
+    let links: HashMap<UserId, UserLink> = services
+        .db
+        .get_primary_links()
+        .await
+        .map_err(SyncError::Database)?
+        .into_iter()
+        .map(|link| (link.discord_user_id, link))
+        .collect();
+
+    let members = members::fetch_all(&ctx.http, guild_id)
+        .await
+        .map_err(SyncError::Discord)?;
+
+    for member in &members {
+        let link = links.get(&member.user.id);
+        if link.is_none() && !member.roles.iter().any(|role| rank_roles.values().any(|r| r == role)) {
+            continue;
+        }
+        report.checked += 1;
+
+        let tier = match services.tier(link, require_verified).await {
+            Ok(tier) => tier,
+            Err(e) => {
+                eprintln!("Failed to fetch rank for {}: {}", member.user.id, e);
+                report.errors += 1;
+                continue;
+            }
+        };
+
+        let changes = plan(&rank_roles, &member.roles, tier);
+        if changes.is_empty() {
+            continue;
+        }
+
+        if !dry_run {
+            report.errors += apply(&ctx.http, guild_id, member.user.id, &changes).await;
+        }
+        report.changes.push(MemberChange {
+            name: member.display_name().to_string(),
+            changes,
+        });
+    }
+
+    Ok(report)
+}
+
+pub fn spawn_sweeper(ctx: Context) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SWEEP_INTERVAL);
+        // The first tick fires immediately; wait a full interval instead so
+        // restarts don't hammer the Riot API.
+        interval.tick().await;
+
+        loop {
+            interval.tick().await;
+
+            let guild_ids: Vec<GuildId> = {
+                let data = ctx.data.read().await;
+                let config_map = data.get::<ConfigMap>().expect("ConfigMap not found");
+                config_map
+                    .iter()
+                    .filter(|entry| !entry.rank_roles.is_empty())
+                    .map(|entry| *entry.key())
+                    .collect()
+            };
+
+            for guild_id in guild_ids {
+                match sync_guild(&ctx, guild_id, false).await {
+                    Ok(report) if !report.changes.is_empty() || report.errors > 0 => println!(
+                        "Rank roles in {}: {} members updated, {} errors",
+                        guild_id,
+                        report.changes.len(),
+                        report.errors
+                    ),
+                    Ok(_) => {}
+                    Err(e) => eprintln!("Failed to sync rank roles in {}: {}", guild_id, e),
+                }
+            }
+        }
+    });
+}
+
+// Returns the number of role changes Discord rejected.
+async fn apply(http: &Http, guild_id: GuildId, user_id: UserId, changes: &RoleChanges) -> usize {
+    let mut errors = 0;
+
+    for role_id in &changes.remove {
+        if let Err(e) = http.remove_member_role(guild_id, user_id, *role_id, Some(AUDIT_REASON)).await {
+            eprintln!("Failed to remove role {} from {}: {}", role_id, user_id, e);
+            errors += 1;
+        }
+    }
+
+    for role_id in &changes.add {
+        if let Err(e) = http.add_member_role(guild_id, user_id, *role_id, Some(AUDIT_REASON)).await {
+            eprintln!("Failed to add role {} to {}: {}", role_id, user_id, e);
+            errors += 1;
+        }
+    }
+
+    errors
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GOLD: RoleId = RoleId::new(1);
+    const PLAT: RoleId = RoleId::new(2);
+    const APEX: RoleId = RoleId::new(3);
+    const OTHER: RoleId = RoleId::new(9);
+
+    fn mapping() -> BTreeMap<Tier, RoleId> {
+        BTreeMap::from([
+            (Tier::Gold, GOLD),
+            (Tier::Platinum, PLAT),
+            (Tier::Master, APEX),
+            (Tier::Challenger, APEX),
+        ])
+    }
+
+    #[test]
+    fn test_plan_promotion() {
+        let changes = plan(&mapping(), &[GOLD, OTHER], Some(Tier::Platinum));
+        assert_eq!(changes, RoleChanges { add: vec![PLAT], remove: vec![GOLD] });
+    }
+
+    #[test]
+    fn test_plan_already_correct() {
+        assert!(plan(&mapping(), &[PLAT, OTHER], Some(Tier::Platinum)).is_empty());
+    }
+
+    #[test]
+    fn test_plan_unranked_or_unmapped_tier_strips_roles() {
+        let changes = plan(&mapping(), &[GOLD, PLAT, OTHER], None);
+        assert_eq!(changes, RoleChanges { add: vec![], remove: vec![GOLD, PLAT] });
+
+        let changes = plan(&mapping(), &[GOLD], Some(Tier::Iron));
+        assert_eq!(changes, RoleChanges { add: vec![], remove: vec![GOLD] });
+    }
+
+    #[test]
+    fn test_plan_shared_role_is_kept() {
+        assert!(plan(&mapping(), &[APEX], Some(Tier::Challenger)).is_empty());
+    }
+}
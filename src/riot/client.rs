@@ -1,10 +1,27 @@
+mod rate_limit;
+
 use reqwest::{Client, StatusCode};
+use reqwest::header::HeaderMap;
+use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
 use std::time::Duration;
-use super::types::{RiotAccount, Region};
+use serde::de::DeserializeOwned;
+use rate_limit::{LimitScope, RateLimitHeaders, RateLimiter};
+use super::response_cache::ResponseCache;
+use super::types::{RiotAccount, Platform, ApiFamily, Route, Summoner, LeagueEntry, RankedProfile, MatchDto, ChampionMastery, CurrentGameInfo};
+
+// `{routing}` is replaced with the platform or regional host, e.g. `euw1`
+// or `europe`.
+pub const DEFAULT_BASE_URL: &str = "https://{routing}.api.riotgames.com";
+
+const MAX_ATTEMPTS: u32 = 3;
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(1);
 
 pub struct RiotClient {
     api_key: String,
+    base_url: String,
     http_client: Client,
+    rate_limiter: RateLimiter,
+    cache: Option<ResponseCache>,
 }
 
 #[derive(Debug)]
@@ -41,54 +58,205 @@ impl RiotClient {
 
         RiotClient {
             api_key,
-            http_client
+            base_url: DEFAULT_BASE_URL.to_string(),
+            http_client,
+            rate_limiter: RateLimiter::new(),
+            cache: None,
         }
     }
 
-    pub async fn get_account_by_riot_id(&self, game_name: &str, tag_line: &str, game_region: &str) -> Result<RiotAccount, RiotApiError> {
-        let region = Region::from_game_region(game_region);
-        let base_url = region.api_base_url();
-
-        let url = format!(
-            "{}/riot/account/v1/accounts/by-riot-id/{}/{}",
-            base_url, game_name, tag_line
-        );
-
-        let response = self.http_client
-            .get(&url)
-            .header("X-Riot-Token", &self.api_key)
-            .send()
-            .await
-            .map_err(|e| RiotApiError::NetworkError(e.to_string()))?;
-            
-        match response.status() {
-            StatusCode::OK => {
-                let account = response
-                    .json::<RiotAccount>()
-                    .await
-                    .map_err(|e| RiotApiError::ParseError(e.to_string()))?;
-                Ok(account)
-            }
-            StatusCode::NOT_FOUND => Err(RiotApiError::NotFound),
-            StatusCode::FORBIDDEN => Err(RiotApiError::Unauthorized),
-            StatusCode::TOO_MANY_REQUESTS => Err(RiotApiError::RateLimited),
-            status if status.is_server_error() => Err(RiotApiError::ServerError),
-            status => Err(RiotApiError::NetworkError(format!("Unexpected status: {}", status))),
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    pub fn with_cache(mut self, cache: ResponseCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    pub async fn get_account_by_riot_id(&self, game_name: &str, tag_line: &str, platform: Platform) -> Result<RiotAccount, RiotApiError> {
+        self.get_json(
+            platform.route(ApiFamily::Account),
+            "account-v1.by-riot-id",
+            &format!(
+                "/riot/account/v1/accounts/by-riot-id/{}/{}",
+                utf8_percent_encode(game_name, NON_ALPHANUMERIC),
+                utf8_percent_encode(tag_line, NON_ALPHANUMERIC),
+            ),
+        ).await
+    }
+
+    pub async fn get_summoner_by_puuid(&self, puuid: &str, platform: Platform) -> Result<Summoner, RiotApiError> {
+        self.get_json(
+            platform.route(ApiFamily::Summoner),
+            "summoner-v4.by-puuid",
+            &format!("/lol/summoner/v4/summoners/by-puuid/{}", puuid),
+        ).await
+    }
+
+    pub async fn get_league_entries_by_puuid(&self, puuid: &str, platform: Platform) -> Result<Vec<LeagueEntry>, RiotApiError> {
+        self.get_json(
+            platform.route(ApiFamily::League),
+            "league-v4.entries-by-puuid",
+            &format!("/lol/league/v4/entries/by-puuid/{}", puuid),
+        ).await
+    }
+
+    pub async fn get_ranked_profile(&self, puuid: &str, platform: Platform) -> Result<RankedProfile, RiotApiError> {
+        let summoner = self.get_summoner_by_puuid(puuid, platform).await?;
+        let entries = self.get_league_entries_by_puuid(&summoner.puuid, platform).await?;
+
+        Ok(RankedProfile::new(summoner, entries))
+    }
+
+    pub async fn get_match_ids_by_puuid(&self, puuid: &str, platform: Platform, count: u32, queue: Option<i32>) -> Result<Vec<String>, RiotApiError> {
+        let mut path = format!("/lol/match/v5/matches/by-puuid/{}/ids?start=0&count={}", puuid, count);
+        if let Some(queue) = queue {
+            path.push_str(&format!("&queue={}", queue));
         }
+
+        self.get_json(platform.route(ApiFamily::Match), "match-v5.ids-by-puuid", &path).await
+    }
+
+    pub async fn get_match(&self, match_id: &str, platform: Platform) -> Result<MatchDto, RiotApiError> {
+        self.get_json(
+            platform.route(ApiFamily::Match),
+            "match-v5.match",
+            &format!("/lol/match/v5/matches/{}", match_id),
+        ).await
     }
-}
 
+    pub async fn get_top_champion_masteries(&self, puuid: &str, platform: Platform, count: u32) -> Result<Vec<ChampionMastery>, RiotApiError> {
+        self.get_json(
+            platform.route(ApiFamily::ChampionMastery),
+            "champion-mastery-v4.top-by-puuid",
+            &format!("/lol/champion-mastery/v4/champion-masteries/by-puuid/{}/top?count={}", puuid, count),
+        ).await
+    }
 
+    // 404 means the champion was never played.
+    pub async fn get_champion_mastery(&self, puuid: &str, platform: Platform, champion_id: i64) -> Result<Option<ChampionMastery>, RiotApiError> {
+        let result = self.get_json(
+            platform.route(ApiFamily::ChampionMastery),
+            "champion-mastery-v4.by-puuid-by-champion",
+            &format!("/lol/champion-mastery/v4/champion-masteries/by-puuid/{}/by-champion/{}", puuid, champion_id),
+        ).await;
 
+        match result {
+            Ok(mastery) => Ok(Some(mastery)),
+            Err(RiotApiError::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 
+    // Riot answers 404 when the player isn't in a game, so that case is
+    // `Ok(None)` rather than an error.
+    pub async fn get_active_game(&self, puuid: &str, platform: Platform) -> Result<Option<CurrentGameInfo>, RiotApiError> {
+        let result = self.get_json(
+            platform.route(ApiFamily::Spectator),
+            "spectator-v5.active-game",
+            &format!("/lol/spectator/v5/active-games/by-summoner/{}", puuid),
+        ).await;
 
+        match result {
+            Ok(game) => Ok(Some(game)),
+            Err(RiotApiError::NotFound) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
 
+    async fn get_json<T: DeserializeOwned>(&self, route: Route, method: &str, path: &str) -> Result<T, RiotApiError> {
+        let Some(cache) = &self.cache else {
+            return parse_json(&self.fetch(route, method, path).await?);
+        };
 
+        let key = ResponseCache::key(route.host(), path);
+        let cached = cache.get(method, &key).await;
+        if let Some(cached) = cached.as_ref().filter(|cached| cached.is_fresh()) {
+            return parse_json(&cached.body);
+        }
 
+        match self.fetch(route, method, path).await {
+            Ok(body) => {
+                // Parsed before storing so a malformed body is never cached.
+                let value = parse_json(&body)?;
+                cache.insert(method, key, body).await;
+                Ok(value)
+            }
+            Err(e @ (RiotApiError::ServerError | RiotApiError::RateLimited)) if cache.serves_stale() => match cached {
+                Some(cached) => {
+                    eprintln!("{} for {}, serving response from {:?} ago", e, key, cached.age().unwrap_or_default());
+                    parse_json(&cached.body)
+                }
+                None => Err(e),
+            },
+            Err(e) => Err(e),
+        }
+    }
 
+    // The response body, after retrying rate limited requests.
+    async fn fetch(&self, route: Route, method: &str, path: &str) -> Result<String, RiotApiError> {
+        let routing = route.host();
+        let url = format!("{}{}", route.base_url(&self.base_url), path);
 
+        for _ in 0..MAX_ATTEMPTS {
+            self.rate_limiter.acquire(routing, method).await;
 
+            let response = self.http_client
+                .get(&url)
+                .header("X-Riot-Token", &self.api_key)
+                .send()
+                .await
+                .map_err(|e| RiotApiError::NetworkError(e.to_string()))?;
 
+            let headers = response.headers();
+            self.rate_limiter.update(routing, method, &rate_limit_headers(headers));
 
+            match response.status() {
+                StatusCode::OK => {
+                    return response
+                        .text()
+                        .await
+                        .map_err(|e| RiotApiError::NetworkError(e.to_string()));
+                }
+                StatusCode::TOO_MANY_REQUESTS => {
+                    let retry_after = header_str(headers, "Retry-After")
+                        .and_then(|value| value.parse().ok())
+                        .map(Duration::from_secs)
+                        .unwrap_or(DEFAULT_RETRY_AFTER);
+                    let scope = LimitScope::from_header(header_str(headers, "X-Rate-Limit-Type"));
 
+                    eprintln!("Rate limited on {} ({}), retrying in {:?}", routing, method, retry_after);
+                    self.rate_limiter.block(routing, method, scope, retry_after);
+                }
+                StatusCode::NOT_FOUND => return Err(RiotApiError::NotFound),
+                StatusCode::FORBIDDEN => return Err(RiotApiError::Unauthorized),
+                status if status.is_server_error() => return Err(RiotApiError::ServerError),
+                status => return Err(RiotApiError::NetworkError(format!("Unexpected status: {}", status))),
+            }
+        }
+
+        Err(RiotApiError::RateLimited)
+    }
+}
+
+fn parse_json<T: DeserializeOwned>(body: &str) -> Result<T, RiotApiError> {
+    serde_json::from_str(body).map_err(|e| RiotApiError::ParseError(e.to_string()))
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers.get(name).and_then(|value| value.to_str().ok())
+}
+
+fn rate_limit_headers(headers: &HeaderMap) -> RateLimitHeaders<'_> {
+    RateLimitHeaders {
+        app_limit: header_str(headers, "X-App-Rate-Limit"),
+        app_count: header_str(headers, "X-App-Rate-Limit-Count"),
+        method_limit: header_str(headers, "X-Method-Rate-Limit"),
+        method_count: header_str(headers, "X-Method-Rate-Limit-Count"),
+    }
+}
 
+#[cfg(test)]
+mod tests;
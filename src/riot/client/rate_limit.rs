@@ -0,0 +1,352 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+// Development keys start with these app limits; the first response's
+// headers replace them with whatever the key actually has.
+const DEFAULT_APP_LIMITS: [(u32, u64); 2] = [(20, 1), (100, 120)];
+
+pub trait Clock: Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LimitScope {
+    Application,
+    Method,
+}
+
+impl LimitScope {
+    // Anything other than an explicit application limit (method or
+    // service) only blocks the method that tripped it.
+    pub fn from_header(value: Option<&str>) -> Self {
+        match value.map(|v| v.to_lowercase()) {
+            Some(v) if v == "application" => LimitScope::Application,
+            _ => LimitScope::Method,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RateLimitHeaders<'a> {
+    pub app_limit: Option<&'a str>,
+    pub app_count: Option<&'a str>,
+    pub method_limit: Option<&'a str>,
+    pub method_count: Option<&'a str>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    limit: u32,
+    window: Duration,
+    count: u32,
+    started: Instant,
+}
+
+impl Bucket {
+    fn new(limit: u32, window: Duration, now: Instant) -> Self {
+        Bucket { limit, window, count: 0, started: now }
+    }
+
+    fn refresh(&mut self, now: Instant) {
+        if now.duration_since(self.started) >= self.window {
+            self.count = 0;
+            self.started = now;
+        }
+    }
+
+    fn wait_time(&self, now: Instant) -> Option<Duration> {
+        if self.count < self.limit {
+            return None;
+        }
+        Some((self.started + self.window).saturating_duration_since(now))
+    }
+}
+
+#[derive(Debug, Default)]
+struct Limits {
+    buckets: Vec<Bucket>,
+    blocked_until: Option<Instant>,
+}
+
+impl Limits {
+    fn with_defaults(now: Instant) -> Self {
+        Limits {
+            buckets: DEFAULT_APP_LIMITS
+                .iter()
+                .map(|&(limit, secs)| Bucket::new(limit, Duration::from_secs(secs), now))
+                .collect(),
+            blocked_until: None,
+        }
+    }
+
+    fn wait_time(&mut self, now: Instant) -> Option<Duration> {
+        let blocked = self.blocked_until
+            .filter(|until| *until > now)
+            .map(|until| until - now);
+
+        self.buckets
+            .iter_mut()
+            .filter_map(|bucket| {
+                bucket.refresh(now);
+                bucket.wait_time(now)
+            })
+            .chain(blocked)
+            .max()
+    }
+
+    fn record(&mut self) {
+        for bucket in &mut self.buckets {
+            bucket.count += 1;
+        }
+    }
+
+    fn sync(&mut self, limit_header: Option<&str>, count_header: Option<&str>, now: Instant) {
+        let limits = match limit_header.map(parse_limits) {
+            Some(limits) if !limits.is_empty() => limits,
+            _ => return,
+        };
+        let counts = count_header.map(parse_limits).unwrap_or_default();
+
+        let unchanged = limits.len() == self.buckets.len()
+            && limits.iter().zip(&self.buckets).all(|(&(limit, secs), bucket)| {
+                bucket.limit == limit && bucket.window == Duration::from_secs(secs)
+            });
+
+        if !unchanged {
+            self.buckets = limits
+                .iter()
+                .map(|&(limit, secs)| Bucket::new(limit, Duration::from_secs(secs), now))
+                .collect();
+        }
+
+        // Riot's count is authoritative when it is ahead of ours, e.g. when
+        // another process shares the key.
+        for (count, secs) in counts {
+            if let Some(bucket) = self.buckets.iter_mut().find(|b| b.window == Duration::from_secs(secs)) {
+                bucket.count = bucket.count.max(count);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+struct RoutingLimits {
+    app: Limits,
+    methods: HashMap<String, Limits>,
+}
+
+pub struct RateLimiter<C: Clock = SystemClock> {
+    clock: C,
+    routes: Mutex<HashMap<String, RoutingLimits>>,
+}
+
+impl RateLimiter<SystemClock> {
+    pub fn new() -> Self {
+        RateLimiter::with_clock(SystemClock)
+    }
+}
+
+impl<C: Clock> RateLimiter<C> {
+    pub fn with_clock(clock: C) -> Self {
+        RateLimiter {
+            clock,
+            routes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub async fn acquire(&self, routing: &str, method: &str) {
+        while let Err(wait) = self.try_acquire(routing, method) {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    pub fn try_acquire(&self, routing: &str, method: &str) -> Result<(), Duration> {
+        let now = self.clock.now();
+        let mut routes = self.routes.lock().unwrap();
+        let route = route_limits(&mut routes, routing, now);
+
+        let app_wait = route.app.wait_time(now);
+        let method_limits = route.methods.entry(method.to_string()).or_default();
+        let method_wait = method_limits.wait_time(now);
+
+        if let Some(wait) = app_wait.into_iter().chain(method_wait).max() {
+            return Err(wait);
+        }
+
+        route.app.record();
+        method_limits.record();
+        Ok(())
+    }
+
+    pub fn update(&self, routing: &str, method: &str, headers: &RateLimitHeaders<'_>) {
+        let now = self.clock.now();
+        let mut routes = self.routes.lock().unwrap();
+        let route = route_limits(&mut routes, routing, now);
+
+        route.app.sync(headers.app_limit, headers.app_count, now);
+        route
+            .methods
+            .entry(method.to_string())
+            .or_default()
+            .sync(headers.method_limit, headers.method_count, now);
+    }
+
+    pub fn block(&self, routing: &str, method: &str, scope: LimitScope, retry_after: Duration) {
+        let now = self.clock.now();
+        let until = now + retry_after;
+        let mut routes = self.routes.lock().unwrap();
+        let route = route_limits(&mut routes, routing, now);
+
+        let limits = match scope {
+            LimitScope::Application => &mut route.app,
+            LimitScope::Method => route.methods.entry(method.to_string()).or_default(),
+        };
+        limits.blocked_until = Some(limits.blocked_until.map_or(until, |current| current.max(until)));
+    }
+}
+
+fn route_limits<'a>(routes: &'a mut HashMap<String, RoutingLimits>, routing: &str, now: Instant) -> &'a mut RoutingLimits {
+    routes
+        .entry(routing.to_string())
+        .or_insert_with(|| RoutingLimits {
+            app: Limits::with_defaults(now),
+            methods: HashMap::new(),
+        })
+}
+
+pub fn parse_limits(header: &str) -> Vec<(u32, u64)> {
+    header
+        .split(',')
+        .filter_map(|pair| {
+            let (count, secs) = pair.trim().split_once(':')?;
+            Some((count.parse().ok()?, secs.parse().ok()?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[derive(Clone)]
+    struct FakeClock {
+        now: Arc<Mutex<Instant>>,
+    }
+
+    impl FakeClock {
+        fn new() -> Self {
+            FakeClock { now: Arc::new(Mutex::new(Instant::now())) }
+        }
+
+        fn advance(&self, by: Duration) {
+            *self.now.lock().unwrap() += by;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            *self.now.lock().unwrap()
+        }
+    }
+
+    fn limiter() -> (RateLimiter<FakeClock>, FakeClock) {
+        let clock = FakeClock::new();
+        (RateLimiter::with_clock(clock.clone()), clock)
+    }
+
+    #[test]
+    fn test_parse_limits() {
+        assert_eq!(parse_limits("20:1,100:120"), vec![(20, 1), (100, 120)]);
+        assert_eq!(parse_limits(" 5:10 , bogus, 3:x"), vec![(5, 10)]);
+        assert_eq!(parse_limits(""), Vec::<(u32, u64)>::new());
+    }
+
+    #[test]
+    fn test_default_app_limit_applies_before_headers() {
+        let (limiter, clock) = limiter();
+
+        for _ in 0..20 {
+            assert!(limiter.try_acquire("na1", "league").is_ok());
+        }
+        assert_eq!(limiter.try_acquire("na1", "league"), Err(Duration::from_secs(1)));
+
+        clock.advance(Duration::from_secs(1));
+        assert!(limiter.try_acquire("na1", "league").is_ok());
+    }
+
+    #[test]
+    fn test_method_limit_from_headers() {
+        let (limiter, clock) = limiter();
+        limiter.update("euw1", "summoner", &RateLimitHeaders {
+            method_limit: Some("2:10"),
+            method_count: Some("1:10"),
+            ..Default::default()
+        });
+
+        assert!(limiter.try_acquire("euw1", "summoner").is_ok());
+        assert_eq!(limiter.try_acquire("euw1", "summoner"), Err(Duration::from_secs(10)));
+        assert!(limiter.try_acquire("euw1", "league").is_ok());
+
+        clock.advance(Duration::from_secs(4));
+        assert_eq!(limiter.try_acquire("euw1", "summoner"), Err(Duration::from_secs(6)));
+    }
+
+    #[test]
+    fn test_buckets_are_per_routing_value() {
+        let (limiter, _clock) = limiter();
+        let headers = RateLimitHeaders {
+            app_limit: Some("1:5"),
+            app_count: Some("1:5"),
+            ..Default::default()
+        };
+        limiter.update("kr", "league", &headers);
+
+        assert_eq!(limiter.try_acquire("kr", "league"), Err(Duration::from_secs(5)));
+        assert!(limiter.try_acquire("asia", "account").is_ok());
+    }
+
+    #[test]
+    fn test_server_count_ahead_of_local() {
+        let (limiter, _clock) = limiter();
+        limiter.update("na1", "league", &RateLimitHeaders {
+            app_limit: Some("20:1,100:120"),
+            app_count: Some("1:1,100:120"),
+            ..Default::default()
+        });
+
+        assert_eq!(limiter.try_acquire("na1", "league"), Err(Duration::from_secs(120)));
+    }
+
+    #[test]
+    fn test_retry_after_blocks_scope() {
+        let (limiter, clock) = limiter();
+
+        limiter.block("na1", "match", LimitScope::Method, Duration::from_secs(3));
+        assert_eq!(limiter.try_acquire("na1", "match"), Err(Duration::from_secs(3)));
+        assert!(limiter.try_acquire("na1", "league").is_ok());
+
+        limiter.block("na1", "match", LimitScope::Application, Duration::from_secs(2));
+        assert_eq!(limiter.try_acquire("na1", "league"), Err(Duration::from_secs(2)));
+
+        clock.advance(Duration::from_secs(3));
+        assert!(limiter.try_acquire("na1", "match").is_ok());
+    }
+
+    #[test]
+    fn test_limit_scope_header() {
+        assert_eq!(LimitScope::from_header(Some("application")), LimitScope::Application);
+        assert_eq!(LimitScope::from_header(Some("method")), LimitScope::Method);
+        assert_eq!(LimitScope::from_header(Some("service")), LimitScope::Method);
+        assert_eq!(LimitScope::from_header(None), LimitScope::Method);
+    }
+}
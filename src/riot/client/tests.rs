@@ -0,0 +1,294 @@
+use super::*;
+use crate::riot::mock_server::{MockResponse, MockServer};
+use tokio::net::TcpListener;
+
+const ACCOUNT: &str = include_str!("../../../tests/fixtures/riot/account.json");
+const SUMMONER: &str = include_str!("../../../tests/fixtures/riot/summoner.json");
+const LEAGUE_ENTRIES: &str = include_str!("../../../tests/fixtures/riot/league_entries.json");
+const MATCH_IDS: &str = include_str!("../../../tests/fixtures/riot/match_ids.json");
+const MATCH: &str = include_str!("../../../tests/fixtures/riot/match.json");
+const ACTIVE_GAME: &str = include_str!("../../../tests/fixtures/riot/active_game.json");
+const MASTERIES: &str = include_str!("../../../tests/fixtures/riot/champion_masteries.json");
+const MASTERY: &str = include_str!("../../../tests/fixtures/riot/champion_mastery.json");
+const MALFORMED: &str = include_str!("../../../tests/fixtures/riot/malformed.json");
+
+const PUUID: &str = "test-puuid-hide-on-bush";
+
+#[tokio::test]
+async fn test_account_lookup_routes_to_regional_cluster() {
+    let server = MockServer::start(vec![
+        ("/americas/riot/account/v1/accounts/by-riot-id/Hide%20on%20bush/KR1", vec![MockResponse::json(ACCOUNT)]),
+        ("/asia/riot/account/v1/accounts/by-riot-id/Hide%20on%20bush/KR1", vec![MockResponse::json(ACCOUNT)]),
+    ]).await;
+    let client = server.client();
+
+    let account = client.get_account_by_riot_id("Hide on bush", "KR1", Platform::Na1).await.unwrap();
+    assert_eq!(account.puuid, PUUID);
+    assert_eq!(account.game_name, "Hide on bush");
+
+    client.get_account_by_riot_id("Hide on bush", "KR1", Platform::Sg2).await.unwrap();
+
+    assert_eq!(server.requests(), vec![
+        "/americas/riot/account/v1/accounts/by-riot-id/Hide%20on%20bush/KR1",
+        "/asia/riot/account/v1/accounts/by-riot-id/Hide%20on%20bush/KR1",
+    ]);
+}
+
+// `/`, `?` and `#` would otherwise end the path segment early.
+#[tokio::test]
+async fn test_riot_id_is_percent_encoded() {
+    let path = "/europe/riot/account/v1/accounts/by-riot-id/a%2Fb%3Fc%23d/%C3%89UW";
+    let server = MockServer::start(vec![(path, vec![MockResponse::json(ACCOUNT)])]).await;
+
+    server.client().get_account_by_riot_id("a/b?c#d", "ÉUW", Platform::Euw1).await.unwrap();
+
+    assert_eq!(server.requests(), vec![path]);
+}
+
+#[tokio::test]
+async fn test_ranked_profile_routes_to_platform() {
+    let server = MockServer::start(vec![
+        ("/kr/lol/summoner/v4/summoners/by-puuid/test-puuid-hide-on-bush", vec![MockResponse::json(SUMMONER)]),
+        ("/kr/lol/league/v4/entries/by-puuid/test-puuid-hide-on-bush", vec![MockResponse::json(LEAGUE_ENTRIES)]),
+    ]).await;
+
+    let profile = server.client().get_ranked_profile(PUUID, Platform::Kr).await.unwrap();
+
+    assert_eq!(profile.summoner.summoner_level, 842);
+    let solo = profile.solo.unwrap();
+    assert_eq!(solo.tier, "CHALLENGER");
+    assert_eq!(solo.league_points, 1432);
+    assert!(solo.hot_streak);
+    assert_eq!(profile.flex.unwrap().rank, "II");
+}
+
+#[tokio::test]
+async fn test_match_endpoints_route_oce_to_sea() {
+    let server = MockServer::start(vec![
+        ("/sea/lol/match/v5/matches/by-puuid/test-puuid-hide-on-bush/ids?start=0&count=2&queue=420", vec![MockResponse::json(MATCH_IDS)]),
+        ("/sea/lol/match/v5/matches/OC1_612345678", vec![MockResponse::json(MATCH)]),
+    ]).await;
+    let client = server.client();
+
+    let ids = client.get_match_ids_by_puuid(PUUID, Platform::Oc1, 2, Some(420)).await.unwrap();
+    assert_eq!(ids, vec!["OC1_612345678", "OC1_612345601"]);
+
+    let match_dto = client.get_match(&ids[0], Platform::Oc1).await.unwrap();
+    let participant = match_dto.participant(PUUID).unwrap();
+    assert_eq!(participant.champion_name, "Leblanc");
+    assert_eq!(participant.creep_score(), 243);
+    assert!(participant.win);
+}
+
+#[tokio::test]
+async fn test_active_game_routes_to_platform() {
+    let server = MockServer::start(vec![
+        ("/kr/lol/spectator/v5/active-games/by-summoner/test-puuid-hide-on-bush", vec![MockResponse::json(ACTIVE_GAME)]),
+    ]).await;
+
+    let game = server.client().get_active_game(PUUID, Platform::Kr).await.unwrap().unwrap();
+    assert_eq!(game.game_queue_config_id, Some(420));
+    assert_eq!(game.participants.iter().filter(|p| p.team_id == CurrentGameInfo::BLUE_TEAM).count(), 5);
+
+    let player = &game.participants[0];
+    assert_eq!(player.puuid.as_deref(), Some(PUUID));
+    assert_eq!((player.champion_id, player.spell1_id, player.spell2_id), (7, 4, 14));
+}
+
+#[tokio::test]
+async fn test_no_active_game_is_none() {
+    let server = MockServer::start(vec![]).await;
+
+    let game = server.client().get_active_game(PUUID, Platform::Kr).await.unwrap();
+    assert!(game.is_none());
+}
+
+#[tokio::test]
+async fn test_champion_mastery_routes_to_platform() {
+    let server = MockServer::start(vec![
+        ("/euw1/lol/champion-mastery/v4/champion-masteries/by-puuid/test-puuid-hide-on-bush/top?count=3", vec![MockResponse::json(MASTERIES)]),
+        ("/euw1/lol/champion-mastery/v4/champion-masteries/by-puuid/test-puuid-hide-on-bush/by-champion/7", vec![MockResponse::json(MASTERY)]),
+    ]).await;
+    let client = server.client();
+
+    let top = client.get_top_champion_masteries(PUUID, Platform::Euw1, 3).await.unwrap();
+    assert_eq!(top.iter().map(|m| m.champion_id).collect::<Vec<_>>(), vec![7, 112, 157]);
+    assert_eq!(top[0].champion_points, 1043952);
+
+    let mastery = client.get_champion_mastery(PUUID, Platform::Euw1, 7).await.unwrap().unwrap();
+    assert_eq!(mastery.champion_level, 62);
+
+    assert!(client.get_champion_mastery(PUUID, Platform::Euw1, 266).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn test_not_found() {
+    let server = MockServer::start(vec![]).await;
+
+    let result = server.client().get_summoner_by_puuid(PUUID, Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_forbidden_is_unauthorized() {
+    let server = MockServer::start(vec![
+        ("/euw1/lol/summoner/v4/summoners/by-puuid/test-puuid-hide-on-bush", vec![MockResponse::status(403)]),
+    ]).await;
+
+    let result = server.client().get_summoner_by_puuid(PUUID, Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_server_errors() {
+    for status in [500, 502, 503, 504] {
+        let server = MockServer::start(vec![
+            ("/euw1/lol/summoner/v4/summoners/by-puuid/test-puuid-hide-on-bush", vec![MockResponse::status(status)]),
+        ]).await;
+
+        let result = server.client().get_summoner_by_puuid(PUUID, Platform::Euw1).await;
+        assert!(matches!(result, Err(RiotApiError::ServerError)), "status {}", status);
+    }
+}
+
+#[tokio::test]
+async fn test_unexpected_status_is_network_error() {
+    let server = MockServer::start(vec![
+        ("/euw1/lol/summoner/v4/summoners/by-puuid/test-puuid-hide-on-bush", vec![MockResponse::status(418)]),
+    ]).await;
+
+    let result = server.client().get_summoner_by_puuid(PUUID, Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::NetworkError(_))));
+}
+
+#[tokio::test]
+async fn test_malformed_json_is_parse_error() {
+    let server = MockServer::start(vec![
+        ("/europe/riot/account/v1/accounts/by-riot-id/Faker/KR1", vec![MockResponse::json(MALFORMED)]),
+    ]).await;
+
+    let result = server.client().get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::ParseError(_))));
+}
+
+#[tokio::test]
+async fn test_connection_refused_is_network_error() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let client = RiotClient::new("test-key".to_string()).with_base_url(format!("http://{}/{{routing}}", addr));
+    let result = client.get_summoner_by_puuid(PUUID, Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::NetworkError(_))));
+}
+
+#[tokio::test]
+async fn test_rate_limited_request_is_retried() {
+    let path = "/na1/lol/league/v4/entries/by-puuid/test-puuid-hide-on-bush";
+    let server = MockServer::start(vec![
+        (path, vec![
+            MockResponse::status(429)
+                .header("Retry-After", "0")
+                .header("X-Rate-Limit-Type", "method"),
+            MockResponse::json(LEAGUE_ENTRIES),
+        ]),
+    ]).await;
+
+    let entries = server.client().get_league_entries_by_puuid(PUUID, Platform::Na1).await.unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(server.requests(), vec![path, path]);
+}
+
+#[tokio::test]
+async fn test_persistent_rate_limit_gives_up() {
+    let path = "/na1/lol/league/v4/entries/by-puuid/test-puuid-hide-on-bush";
+    let server = MockServer::start(vec![
+        (path, vec![MockResponse::status(429).header("Retry-After", "0")]),
+    ]).await;
+
+    let result = server.client().get_league_entries_by_puuid(PUUID, Platform::Na1).await;
+    assert!(matches!(result, Err(RiotApiError::RateLimited)));
+    assert_eq!(server.requests().len(), MAX_ATTEMPTS as usize);
+}
+
+const ACCOUNT_PATH: &str = "/europe/riot/account/v1/accounts/by-riot-id/Faker/KR1";
+
+// A cache holding an account response that is past its TTL.
+async fn stale_cache(cache: ResponseCache) -> ResponseCache {
+    let fetched_at = std::time::SystemTime::now() - crate::cache::ttl::ACCOUNT * 2;
+    let key = ResponseCache::key("europe", "/riot/account/v1/accounts/by-riot-id/Faker/KR1");
+    cache.insert_at("account-v1.by-riot-id", key, ACCOUNT.to_string(), fetched_at).await;
+    cache
+}
+
+#[tokio::test]
+async fn test_cached_responses_skip_the_network() {
+    let spectator = "/euw1/lol/spectator/v5/active-games/by-summoner/test-puuid-hide-on-bush";
+    let server = MockServer::start(vec![
+        (ACCOUNT_PATH, vec![MockResponse::json(ACCOUNT)]),
+        (spectator, vec![MockResponse::json(ACTIVE_GAME)]),
+    ]).await;
+    let client = server.client().with_cache(ResponseCache::new(None));
+
+    for _ in 0..2 {
+        client.get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await.unwrap();
+        client.get_active_game(PUUID, Platform::Euw1).await.unwrap();
+    }
+
+    // Spectator responses are never cached.
+    assert_eq!(server.requests(), vec![ACCOUNT_PATH, spectator, spectator]);
+}
+
+#[tokio::test]
+async fn test_ranked_data_is_not_response_cached() {
+    let summoner = "/kr/lol/summoner/v4/summoners/by-puuid/test-puuid-hide-on-bush";
+    let league = "/kr/lol/league/v4/entries/by-puuid/test-puuid-hide-on-bush";
+    let server = MockServer::start(vec![
+        (summoner, vec![MockResponse::json(SUMMONER)]),
+        (league, vec![MockResponse::json(LEAGUE_ENTRIES)]),
+    ]).await;
+    let client = server.client().with_cache(ResponseCache::new(None));
+
+    // `ProfileCache` is the only cache in front of these.
+    client.get_ranked_profile(PUUID, Platform::Kr).await.unwrap();
+    client.get_ranked_profile(PUUID, Platform::Kr).await.unwrap();
+    assert_eq!(server.requests(), vec![summoner, league, summoner, league]);
+}
+
+#[tokio::test]
+async fn test_malformed_response_is_not_cached() {
+    let server = MockServer::start(vec![
+        (ACCOUNT_PATH, vec![MockResponse::json(MALFORMED), MockResponse::json(ACCOUNT)]),
+    ]).await;
+    let client = server.client().with_cache(ResponseCache::new(None));
+
+    let result = client.get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::ParseError(_))));
+    client.get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await.unwrap();
+    assert_eq!(server.requests(), vec![ACCOUNT_PATH, ACCOUNT_PATH]);
+}
+
+#[tokio::test]
+async fn test_stale_response_served_on_server_error() {
+    let server = MockServer::start(vec![
+        (ACCOUNT_PATH, vec![MockResponse::status(503), MockResponse::json(MALFORMED)]),
+    ]).await;
+    let client = server.client().with_cache(stale_cache(ResponseCache::new(None).with_stale_fallback()).await);
+
+    let account = client.get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await.unwrap();
+    assert_eq!(account.puuid, PUUID);
+
+    // Other failures aren't covered up.
+    let result = client.get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::ParseError(_))));
+    assert_eq!(server.requests(), vec![ACCOUNT_PATH, ACCOUNT_PATH]);
+}
+
+#[tokio::test]
+async fn test_stale_response_needs_fallback_enabled() {
+    let server = MockServer::start(vec![(ACCOUNT_PATH, vec![MockResponse::status(503)])]).await;
+    let client = server.client().with_cache(stale_cache(ResponseCache::new(None)).await);
+
+    let result = client.get_account_by_riot_id("Faker", "KR1", Platform::Euw1).await;
+    assert!(matches!(result, Err(RiotApiError::ServerError)));
+}
@@ -0,0 +1,108 @@
+use super::RiotClient;
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+#[derive(Clone)]
+pub(crate) struct MockResponse {
+    status: u16,
+    headers: Vec<(&'static str, &'static str)>,
+    body: &'static str,
+}
+
+impl MockResponse {
+    pub(crate) fn json(body: &'static str) -> Self {
+        MockResponse { status: 200, headers: Vec::new(), body }
+    }
+
+    pub(crate) fn status(status: u16) -> Self {
+        MockResponse { status, headers: Vec::new(), body: "{}" }
+    }
+
+    pub(crate) fn header(mut self, name: &'static str, value: &'static str) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+}
+
+// Minimal HTTP/1.1 stand-in for the Riot API. Each path serves its queued
+// responses in order and keeps repeating the last one.
+pub(crate) struct MockServer {
+    base_url: String,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl MockServer {
+    pub(crate) async fn start(routes: Vec<(&str, Vec<MockResponse>)>) -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}/{{routing}}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let routes: HashMap<String, VecDeque<MockResponse>> = routes
+            .into_iter()
+            .map(|(path, responses)| (path.to_string(), responses.into()))
+            .collect();
+        let routes = Arc::new(Mutex::new(routes));
+
+        let log = requests.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = match listener.accept().await {
+                    Ok(conn) => conn,
+                    Err(_) => return,
+                };
+
+                let routes = routes.clone();
+                let log = log.clone();
+                tokio::spawn(async move {
+                    let mut buf = Vec::new();
+                    let mut chunk = [0u8; 1024];
+                    while !buf.windows(4).any(|w| w == b"\r\n\r\n") {
+                        match socket.read(&mut chunk).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => buf.extend_from_slice(&chunk[..n]),
+                        }
+                    }
+
+                    let request = String::from_utf8_lossy(&buf);
+                    let path = request.split_whitespace().nth(1).unwrap_or("/").to_string();
+                    log.lock().unwrap().push(path.clone());
+
+                    let response = {
+                        let mut routes = routes.lock().unwrap();
+                        match routes.get_mut(&path) {
+                            Some(queue) if queue.len() > 1 => queue.pop_front().unwrap(),
+                            Some(queue) => queue.front().cloned().unwrap(),
+                            None => MockResponse::status(404),
+                        }
+                    };
+
+                    let mut raw = format!(
+                        "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+                        response.status,
+                        response.body.len()
+                    );
+                    for (name, value) in &response.headers {
+                        raw.push_str(&format!("{}: {}\r\n", name, value));
+                    }
+                    raw.push_str("\r\n");
+                    raw.push_str(response.body);
+
+                    let _ = socket.write_all(raw.as_bytes()).await;
+                    let _ = socket.shutdown().await;
+                });
+            }
+        });
+
+        MockServer { base_url, requests }
+    }
+
+    pub(crate) fn client(&self) -> RiotClient {
+        RiotClient::new("test-key".to_string()).with_base_url(self.base_url.clone())
+    }
+
+    pub(crate) fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
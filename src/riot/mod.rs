@@ -1,6 +1,10 @@
 mod client;
+mod response_cache;
 mod types;
+pub mod static_data;
+#[cfg(test)]
+pub(crate) mod mock_server;
 
 pub use client::{RiotClient, RiotApiError};
-pub use types::{RiotAccount, Region};
-
+pub use response_cache::ResponseCache;
+pub use types::{Platform, LeagueEntry, RankedProfile, MatchDto, ChampionMastery, CurrentGameInfo, CurrentGameParticipant, Tier, queue_id_from_name, queue_name, QUEUE_ALIASES};
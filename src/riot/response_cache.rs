@@ -0,0 +1,198 @@
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use crate::cache::{SWEEP_INTERVAL, TtlCache, ttl};
+use crate::database::Database;
+
+const CAPACITY: usize = 5_000;
+// How long past its TTL a response may still stand in while Riot is
+// failing or rate limiting us.
+pub const MAX_STALE: Duration = Duration::from_secs(6 * 3600);
+
+// Endpoints not listed aren't cached here. Summoners, league entries, match
+// lists and matches have their own caches (`ProfileCache`,
+// `MatchHistoryCache` and the `match_cache` table), and `verify` and rank
+// snapshots rely on reading summoners and league entries straight from
+// Riot. An old spectator response would show a game that has already ended.
+// Only the endpoints listed here fall back to a stale response while Riot
+// is failing; every other request fails with it.
+fn endpoint_ttl(method: &str) -> Option<Duration> {
+    match method {
+        "account-v1.by-riot-id" => Some(ttl::ACCOUNT),
+        "champion-mastery-v4.top-by-puuid" | "champion-mastery-v4.by-puuid-by-champion" => Some(ttl::CHAMPION_MASTERY),
+        _ => None,
+    }
+}
+
+fn unix_secs(time: SystemTime) -> i64 {
+    time.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs() as i64)
+}
+
+#[derive(Clone, Debug)]
+pub struct CachedResponse {
+    pub body: Arc<str>,
+    fetched_at: SystemTime,
+    ttl: Duration,
+}
+
+impl CachedResponse {
+    pub fn is_fresh(&self) -> bool {
+        self.age().is_some_and(|age| age < self.ttl)
+    }
+
+    pub fn age(&self) -> Option<Duration> {
+        SystemTime::now().duration_since(self.fetched_at).ok()
+    }
+}
+
+// Raw JSON bodies of Riot responses, keyed by host and path (query
+// included). Entries are kept for `MAX_STALE` past their TTL so they can be
+// served when a refresh fails; with a database they also survive restarts.
+pub struct ResponseCache {
+    memory: Arc<TtlCache<String, CachedResponse>>,
+    db: Option<Arc<Database>>,
+    serve_stale: bool,
+}
+
+impl ResponseCache {
+    pub fn new(db: Option<Arc<Database>>) -> Self {
+        let memory = TtlCache::new("Riot response", CAPACITY, MAX_STALE)
+            .with_ttl_for(|response: &CachedResponse| response.ttl + MAX_STALE);
+
+        ResponseCache { memory: Arc::new(memory), db, serve_stale: false }
+    }
+
+    pub fn with_stale_fallback(mut self) -> Self {
+        self.serve_stale = true;
+        self
+    }
+
+    pub fn serves_stale(&self) -> bool {
+        self.serve_stale
+    }
+
+    pub fn key(routing: &str, path: &str) -> String {
+        format!("{}{}", routing, path)
+    }
+
+    pub fn spawn_sweeper(&self) {
+        self.memory.spawn_sweeper(SWEEP_INTERVAL);
+    }
+
+    // Deletes persisted responses too old to be served even as stale.
+    pub async fn prune(&self) {
+        let Some(db) = &self.db else {
+            return;
+        };
+
+        match db.prune_riot_responses(unix_secs(SystemTime::now())).await {
+            Ok(0) => {}
+            Ok(pruned) => println!("Pruned {} expired Riot responses", pruned),
+            Err(e) => eprintln!("Failed to prune Riot responses: {}", e),
+        }
+    }
+
+    // The stored response, fresh or not. Memory is checked first, then the
+    // database, whose hits are kept in memory from then on.
+    pub async fn get(&self, method: &str, key: &str) -> Option<CachedResponse> {
+        let ttl = endpoint_ttl(method)?;
+        if let Some(response) = self.memory.get(&key.to_string()) {
+            return Some(response);
+        }
+
+        let db = self.db.as_ref()?;
+        let (body, fetched_at) = match db.get_riot_response(key, unix_secs(SystemTime::now())).await {
+            Ok(row) => row?,
+            Err(e) => {
+                eprintln!("Failed to read cached Riot response {}: {}", key, e);
+                return None;
+            }
+        };
+
+        let response = CachedResponse {
+            body: body.into(),
+            fetched_at: UNIX_EPOCH + Duration::from_secs(fetched_at.max(0) as u64),
+            ttl,
+        };
+        self.memory.insert(key.to_string(), response.clone());
+        Some(response)
+    }
+
+    pub async fn insert(&self, method: &str, key: String, body: String) {
+        self.insert_at(method, key, body, SystemTime::now()).await;
+    }
+
+    pub(super) async fn insert_at(&self, method: &str, key: String, body: String, fetched_at: SystemTime) {
+        let Some(ttl) = endpoint_ttl(method) else {
+            return;
+        };
+
+        if let Some(db) = &self.db {
+            let fetched = unix_secs(fetched_at);
+            let expires = unix_secs(fetched_at + ttl + MAX_STALE);
+            if let Err(e) = db.save_riot_response(&key, &body, fetched, expires).await {
+                eprintln!("Failed to save Riot response {}: {}", key, e);
+            }
+        }
+
+        self.memory.insert(key, CachedResponse { body: body.into(), fetched_at, ttl });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ACCOUNT: &str = "account-v1.by-riot-id";
+    const KEY: &str = "europe/riot/account/v1/accounts/by-riot-id/Faker/KR1";
+
+    async fn database() -> Arc<Database> {
+        Arc::new(Database::in_memory().await.unwrap())
+    }
+
+    #[test]
+    fn test_only_uncovered_endpoints_are_cached() {
+        assert_eq!(endpoint_ttl("spectator-v5.active-game"), None);
+        assert_eq!(endpoint_ttl("summoner-v4.by-puuid"), None);
+        assert_eq!(endpoint_ttl("league-v4.entries-by-puuid"), None);
+        assert_eq!(endpoint_ttl("match-v5.match"), None);
+        assert_eq!(endpoint_ttl("account-v1.by-riot-id"), Some(ttl::ACCOUNT));
+    }
+
+    #[tokio::test]
+    async fn test_responses_survive_restart() {
+        let db = database().await;
+
+        let cache = ResponseCache::new(Some(db.clone()));
+        cache.insert(ACCOUNT, KEY.to_string(), "{}".to_string()).await;
+
+        let restarted = ResponseCache::new(Some(db));
+        let response = restarted.get(ACCOUNT, KEY).await.unwrap();
+        assert_eq!(&*response.body, "{}");
+        assert!(response.is_fresh());
+        assert!(restarted.get(ACCOUNT, "europe/other").await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_old_responses_are_kept_as_stale() {
+        let cache = ResponseCache::new(None);
+        let fetched_at = SystemTime::now() - ttl::ACCOUNT - Duration::from_secs(1);
+        cache.insert_at(ACCOUNT, KEY.to_string(), "{}".to_string(), fetched_at).await;
+
+        assert!(!cache.get(ACCOUNT, KEY).await.unwrap().is_fresh());
+    }
+
+    #[tokio::test]
+    async fn test_prune_drops_responses_past_max_stale() {
+        let db = database().await;
+        let cache = ResponseCache::new(Some(db.clone()));
+        let long_ago = SystemTime::now() - ttl::ACCOUNT - MAX_STALE;
+        cache.insert_at(ACCOUNT, "old".to_string(), "{}".to_string(), long_ago).await;
+        cache.insert(ACCOUNT, "new".to_string(), "{}".to_string()).await;
+
+        cache.prune().await;
+
+        let now = unix_secs(SystemTime::now());
+        assert!(db.get_riot_response("old", 0).await.unwrap().is_none());
+        assert!(db.get_riot_response("new", now).await.unwrap().is_some());
+    }
+}
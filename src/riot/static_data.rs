@@ -0,0 +1,467 @@
+mod source;
+
+pub use source::DataSource;
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use crate::utils::levenshtein;
+
+pub const DDRAGON_BASE_URL: &str = "https://ddragon.leagueoflegends.com";
+
+// New patches ship every two weeks; an hour keeps the delay negligible.
+pub const REFRESH_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+#[derive(Debug)]
+pub enum StaticDataError {
+    Network(String),
+    Io(String),
+    Parse(String),
+    Missing(String),
+}
+
+impl std::fmt::Display for StaticDataError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StaticDataError::Network(msg) => write!(f, "Network error: {}", msg),
+            StaticDataError::Io(msg) => write!(f, "I/O error: {}", msg),
+            StaticDataError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            StaticDataError::Missing(msg) => write!(f, "Missing data: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StaticDataError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Champion {
+    pub id: i64,
+    // Data Dragon's internal name, e.g. `MonkeyKing` for Wukong.
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item {
+    pub id: i64,
+    pub name: String,
+}
+
+// Keystones, minor runes and the trees themselves share one ID space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rune {
+    pub id: i64,
+    pub key: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummonerSpell {
+    pub id: i64,
+    // e.g. `SummonerDot` for Ignite.
+    pub key: String,
+    pub name: String,
+}
+
+pub trait Named {
+    // Used in place of the name for IDs the index doesn't know.
+    const KIND: &'static str;
+
+    fn name(&self) -> &str;
+
+    // Data Dragon's internal name, searchable alongside the display name.
+    fn key(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl Named for Champion {
+    const KIND: &'static str = "Champion";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+}
+
+impl Named for Item {
+    const KIND: &'static str = "Item";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl Named for Rune {
+    const KIND: &'static str = "Rune";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+}
+
+impl Named for SummonerSpell {
+    const KIND: &'static str = "Spell";
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn key(&self) -> Option<&str> {
+        Some(&self.key)
+    }
+}
+
+// Entries by ID, and IDs by name. Names are matched ignoring case, spaces
+// and punctuation, so `kaisa` and `lee sin` both resolve.
+#[derive(Debug)]
+pub struct NameIndex<T> {
+    by_id: HashMap<i64, T>,
+    names: Vec<(String, i64)>,
+}
+
+impl<T> Default for NameIndex<T> {
+    fn default() -> Self {
+        NameIndex { by_id: HashMap::new(), names: Vec::new() }
+    }
+}
+
+impl<T: Named> NameIndex<T> {
+    // The first entry to claim a name keeps it.
+    fn insert(&mut self, id: i64, entry: T) {
+        for alias in std::iter::once(entry.name()).chain(entry.key()) {
+            let alias = normalize(alias);
+            if !alias.is_empty() && !self.names.iter().any(|(name, _)| *name == alias) {
+                self.names.push((alias, id));
+            }
+        }
+        self.by_id.insert(id, entry);
+    }
+
+    pub fn len(&self) -> usize {
+        self.by_id.len()
+    }
+
+    pub fn get(&self, id: i64) -> Option<&T> {
+        self.by_id.get(&id)
+    }
+
+    // Falls back to the raw ID while the index is empty or behind a patch.
+    pub fn name(&self, id: i64) -> String {
+        match self.get(id) {
+            Some(entry) => entry.name().to_string(),
+            None => format!("{} {}", T::KIND, id),
+        }
+    }
+
+    // Exact matches win, then prefixes (`lee`), then substrings (`zhonya`),
+    // then near misses (`leblnc`). Ties go to the shortest name.
+    pub fn find(&self, query: &str) -> Option<&T> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return None;
+        }
+
+        // Swapped letters are two edits, so `yasou` needs a budget of two.
+        let max_typos = if query.chars().count() >= 5 { 2 } else { 1 };
+        self.names
+            .iter()
+            .filter_map(|(name, id)| {
+                let score = if *name == query {
+                    0
+                } else if name.starts_with(&query) {
+                    1
+                } else if name.contains(&query) {
+                    2
+                } else {
+                    match levenshtein(name, &query) {
+                        distance if distance <= max_typos => 2 + distance,
+                        _ => return None,
+                    }
+                };
+                Some(((score, name.len(), name.as_str()), *id))
+            })
+            .min_by(|a, b| a.0.cmp(&b.0))
+            .and_then(|(_, id)| self.by_id.get(&id))
+    }
+}
+
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+// One patch worth of Data Dragon.
+#[derive(Debug, Default)]
+pub struct StaticData {
+    pub version: Option<String>,
+    pub champions: NameIndex<Champion>,
+    pub items: NameIndex<Item>,
+    pub runes: NameIndex<Rune>,
+    pub spells: NameIndex<SummonerSpell>,
+}
+
+#[derive(Deserialize)]
+struct DataFile<T> {
+    data: HashMap<String, T>,
+}
+
+#[derive(Deserialize)]
+struct ChampionData {
+    id: String,
+    key: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct ItemData {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct RuneTreeData {
+    id: i64,
+    key: String,
+    name: String,
+    slots: Vec<RuneSlotData>,
+}
+
+#[derive(Deserialize)]
+struct RuneSlotData {
+    runes: Vec<RuneData>,
+}
+
+#[derive(Deserialize)]
+struct RuneData {
+    id: i64,
+    key: String,
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct SpellData {
+    id: String,
+    key: String,
+    name: String,
+}
+
+impl StaticData {
+    // Parses `champion.json`, `item.json`, `runesReforged.json` and
+    // `summoner.json` of one patch.
+    pub fn from_json(
+        version: &str,
+        champions: &str,
+        items: &str,
+        runes: &str,
+        spells: &str,
+    ) -> Result<Self, serde_json::Error> {
+        let mut data = StaticData { version: Some(version.to_string()), ..StaticData::default() };
+
+        let champions: DataFile<ChampionData> = serde_json::from_str(champions)?;
+        for champion in sorted_by_key(champions.data.into_values(), |c| &c.key) {
+            let Ok(id) = champion.key.parse() else { continue };
+            data.champions.insert(id, Champion { id, key: champion.id, name: champion.name });
+        }
+
+        // Items are keyed by ID. Mode-specific copies reuse the name under a
+        // larger ID, so going in ID order keeps the name on the base item.
+        let items: DataFile<ItemData> = serde_json::from_str(items)?;
+        let mut items: Vec<(i64, ItemData)> = items
+            .data
+            .into_iter()
+            .filter_map(|(id, item)| Some((id.parse().ok()?, item)))
+            .collect();
+        items.sort_by_key(|(id, _)| *id);
+        for (id, item) in items {
+            data.items.insert(id, Item { id, name: item.name });
+        }
+
+        let trees: Vec<RuneTreeData> = serde_json::from_str(runes)?;
+        for tree in trees {
+            data.runes.insert(tree.id, Rune { id: tree.id, key: tree.key, name: tree.name });
+            for rune in tree.slots.into_iter().flat_map(|slot| slot.runes) {
+                data.runes.insert(rune.id, Rune { id: rune.id, key: rune.key, name: rune.name });
+            }
+        }
+
+        let spells: DataFile<SpellData> = serde_json::from_str(spells)?;
+        for spell in sorted_by_key(spells.data.into_values(), |s| &s.key) {
+            let Ok(id) = spell.key.parse() else { continue };
+            data.spells.insert(id, SummonerSpell { id, key: spell.id, name: spell.name });
+        }
+
+        Ok(data)
+    }
+
+    pub fn version(&self) -> Option<&str> {
+        self.version.as_deref()
+    }
+}
+
+// HashMap order would make name collisions resolve differently per run.
+fn sorted_by_key<T>(values: impl Iterator<Item = T>, key: impl Fn(&T) -> &String) -> Vec<T> {
+    let mut values: Vec<T> = values.collect();
+    values.sort_by(|a, b| key(a).cmp(key(b)));
+    values
+}
+
+// The patch currently in use. Readers take a snapshot and keep it for the
+// whole command, so a refresh never changes names halfway through a reply.
+#[derive(Clone, Default)]
+pub struct StaticDataStore {
+    current: Arc<RwLock<Arc<StaticData>>>,
+}
+
+impl StaticDataStore {
+    pub fn current(&self) -> Arc<StaticData> {
+        self.current.read().expect("static data lock poisoned").clone()
+    }
+
+    pub fn replace(&self, data: StaticData) {
+        *self.current.write().expect("static data lock poisoned") = Arc::new(data);
+    }
+}
+
+pub fn spawn_refresher(store: StaticDataStore, source: DataSource) {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(REFRESH_INTERVAL);
+        let mut revision = None;
+
+        loop {
+            interval.tick().await;
+            refresh(&store, &source, &mut revision).await;
+        }
+    });
+}
+
+// Reloads only when the source reports a new revision, and keeps serving the
+// previous patch if loading the new one fails.
+async fn refresh(store: &StaticDataStore, source: &DataSource, revision: &mut Option<String>) {
+    let latest = match source.revision().await {
+        Ok(latest) => latest,
+        Err(e) => {
+            eprintln!("Failed to check {} for new static data: {}", source, e);
+            return;
+        }
+    };
+    if revision.as_deref() == Some(latest.as_str()) {
+        return;
+    }
+
+    match source.load().await {
+        Ok(data) => {
+            println!(
+                "Loaded Data Dragon {} from {}: {} champions, {} items, {} runes, {} spells",
+                data.version().unwrap_or("?"),
+                source,
+                data.champions.len(),
+                data.items.len(),
+                data.runes.len(),
+                data.spells.len(),
+            );
+            store.replace(data);
+            *revision = Some(latest);
+        }
+        Err(e) => eprintln!("Failed to load static data from {}: {}", source, e),
+    }
+}
+
+#[cfg(test)]
+pub const FIXTURE_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/ddragon");
+
+#[cfg(test)]
+pub fn fixture() -> StaticData {
+    StaticData::from_json(
+        "15.20.1",
+        include_str!("../../tests/fixtures/ddragon/15.20.1/data/en_US/champion.json"),
+        include_str!("../../tests/fixtures/ddragon/15.20.1/data/en_US/item.json"),
+        include_str!("../../tests/fixtures/ddragon/15.20.1/data/en_US/runesReforged.json"),
+        include_str!("../../tests/fixtures/ddragon/15.20.1/data/en_US/summoner.json"),
+    )
+    .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_by_id_and_name() {
+        let data = fixture();
+        assert_eq!(data.version(), Some("15.20.1"));
+
+        assert_eq!(data.champions.get(7).unwrap().name, "LeBlanc");
+        assert_eq!(data.champions.name(62), "Wukong");
+        assert_eq!(data.champions.name(999), "Champion 999");
+        assert_eq!(data.champions.find("kaisa").unwrap().id, 145);
+        assert_eq!(data.champions.find("MonkeyKing").unwrap().name, "Wukong");
+        assert_eq!(data.champions.find("nunu & willump").unwrap().id, 20);
+
+        assert_eq!(data.items.name(3157), "Zhonya's Hourglass");
+        assert_eq!(data.runes.name(8112), "Electrocute");
+        assert_eq!(data.runes.name(8100), "Domination");
+        assert_eq!(data.spells.name(14), "Ignite");
+        assert_eq!(data.spells.find("SummonerDot").unwrap().id, 14);
+    }
+
+    #[test]
+    fn test_find_is_fuzzy() {
+        let data = fixture();
+
+        // Prefix, then substring, then typos.
+        assert_eq!(data.champions.find("lee").unwrap().id, 64);
+        assert_eq!(data.items.find("zhonya").unwrap().id, 3157);
+        assert_eq!(data.items.find("deathcap").unwrap().id, 3089);
+        assert_eq!(data.champions.find("leblnc").unwrap().id, 7);
+        assert_eq!(data.champions.find("yasou").unwrap().id, 157);
+        assert_eq!(data.runes.find("conquerer").unwrap().id, 8010);
+
+        assert!(data.champions.find("Teemo").is_none());
+        assert!(data.champions.find("").is_none());
+    }
+
+    #[test]
+    fn test_duplicate_names_keep_the_lowest_id() {
+        let data = fixture();
+        assert_eq!(data.items.find("Infinity Edge").unwrap().id, 3031);
+        assert_eq!(data.items.name(223031), "Infinity Edge");
+    }
+
+    #[test]
+    fn test_empty_data_falls_back_to_ids() {
+        let data = StaticData::default();
+        assert_eq!(data.champions.len(), 0);
+        assert_eq!(data.champions.name(7), "Champion 7");
+        assert_eq!(data.spells.name(4), "Spell 4");
+    }
+
+    #[tokio::test]
+    async fn test_refresh_swaps_only_on_new_revision() {
+        let store = StaticDataStore::default();
+        let source = DataSource::Directory(FIXTURE_DIR.into());
+        let mut revision = None;
+
+        refresh(&store, &source, &mut revision).await;
+        let loaded = store.current();
+        assert_eq!(loaded.version(), Some("15.20.1"));
+        assert_eq!(revision.as_deref(), Some("15.20.1"));
+
+        refresh(&store, &source, &mut revision).await;
+        assert!(Arc::ptr_eq(&loaded, &store.current()));
+
+        // A failing source keeps the current patch.
+        let missing = DataSource::Directory(std::env::temp_dir().join("no-such-ddragon"));
+        refresh(&store, &missing, &mut revision).await;
+        assert!(Arc::ptr_eq(&loaded, &store.current()));
+    }
+}
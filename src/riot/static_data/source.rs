@@ -0,0 +1,267 @@
+use flate2::read::GzDecoder;
+use reqwest::Client;
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Component, Path, PathBuf};
+use std::time::{Duration, UNIX_EPOCH};
+use super::{StaticData, StaticDataError};
+
+const LOCALE: &str = "en_US";
+
+// In the order `StaticData::from_json` takes them.
+const FILES: [&str; 4] = ["champion.json", "item.json", "runesReforged.json", "summoner.json"];
+
+// Where Data Dragon comes from. Besides the CDN, hosts without outbound
+// access can point at an extracted `dragontail-<version>.tgz` or at the
+// archive itself; both contain `<version>/data/<locale>/*.json`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DataSource {
+    Remote(String),
+    Directory(PathBuf),
+    Archive(PathBuf),
+}
+
+impl DataSource {
+    pub fn parse(value: &str) -> Self {
+        if value.starts_with("http://") || value.starts_with("https://") {
+            return DataSource::Remote(value.trim_end_matches('/').to_string());
+        }
+
+        let path = PathBuf::from(value);
+        if value.ends_with(".tgz") || value.ends_with(".tar.gz") {
+            DataSource::Archive(path)
+        } else {
+            DataSource::Directory(path)
+        }
+    }
+
+    // Changes whenever the source has a new patch to offer: the latest
+    // version for the CDN and directories, the file's mtime for archives.
+    pub async fn revision(&self) -> Result<String, StaticDataError> {
+        match self {
+            DataSource::Remote(base_url) => latest_remote_version(&http_client()?, base_url).await,
+            DataSource::Directory(dir) => {
+                let dir = dir.clone();
+                blocking(move || latest_local_version(&dir)).await
+            }
+            DataSource::Archive(path) => {
+                let modified = std::fs::metadata(path)
+                    .and_then(|metadata| metadata.modified())
+                    .map_err(|e| StaticDataError::Io(format!("{}: {}", path.display(), e)))?;
+                let secs = modified.duration_since(UNIX_EPOCH).map_or(0, |since| since.as_secs());
+                Ok(secs.to_string())
+            }
+        }
+    }
+
+    // Loads the newest patch the source has.
+    pub async fn load(&self) -> Result<StaticData, StaticDataError> {
+        match self {
+            DataSource::Remote(base_url) => load_remote(base_url).await,
+            DataSource::Directory(dir) => {
+                let dir = dir.clone();
+                blocking(move || load_directory(&dir)).await
+            }
+            DataSource::Archive(path) => {
+                let path = path.clone();
+                blocking(move || load_archive(&path)).await
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for DataSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DataSource::Remote(base_url) => write!(f, "{}", base_url),
+            DataSource::Directory(path) | DataSource::Archive(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+fn parse(version: &str, files: &[String; 4]) -> Result<StaticData, StaticDataError> {
+    StaticData::from_json(version, &files[0], &files[1], &files[2], &files[3])
+        .map_err(|e| StaticDataError::Parse(format!("{}: {}", version, e)))
+}
+
+// Data Dragon versions are dotted numbers; anything else in a dragontail
+// (`img`, `lolpatch_*`) is not a patch.
+fn version_key(name: &str) -> Option<Vec<u32>> {
+    name.split('.').map(|part| part.parse().ok()).collect()
+}
+
+async fn blocking<T: Send + 'static>(
+    task: impl FnOnce() -> Result<T, StaticDataError> + Send + 'static,
+) -> Result<T, StaticDataError> {
+    tokio::task::spawn_blocking(task)
+        .await
+        .map_err(|e| StaticDataError::Io(e.to_string()))?
+}
+
+fn http_client() -> Result<Client, StaticDataError> {
+    Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| StaticDataError::Network(e.to_string()))
+}
+
+async fn latest_remote_version(http_client: &Client, base_url: &str) -> Result<String, StaticDataError> {
+    let body = get_text(http_client, &format!("{}/api/versions.json", base_url)).await?;
+    let versions: Vec<String> = serde_json::from_str(&body).map_err(|e| StaticDataError::Parse(e.to_string()))?;
+    versions
+        .into_iter()
+        .next()
+        .ok_or_else(|| StaticDataError::Missing(format!("{} listed no versions", base_url)))
+}
+
+async fn load_remote(base_url: &str) -> Result<StaticData, StaticDataError> {
+    let http_client = http_client()?;
+    let version = latest_remote_version(&http_client, base_url).await?;
+
+    let mut files: [String; 4] = Default::default();
+    for (file, name) in files.iter_mut().zip(FILES) {
+        let url = format!("{}/cdn/{}/data/{}/{}", base_url, version, LOCALE, name);
+        *file = get_text(&http_client, &url).await?;
+    }
+
+    parse(&version, &files)
+}
+
+async fn get_text(http_client: &Client, url: &str) -> Result<String, StaticDataError> {
+    let response = http_client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| StaticDataError::Network(e.to_string()))?;
+
+    match response.status() {
+        status if status.is_success() => response
+            .text()
+            .await
+            .map_err(|e| StaticDataError::Network(e.to_string())),
+        reqwest::StatusCode::NOT_FOUND => Err(StaticDataError::Missing(url.to_string())),
+        status => Err(StaticDataError::Network(format!("Unexpected status {} from {}", status, url))),
+    }
+}
+
+fn latest_local_version(dir: &Path) -> Result<String, StaticDataError> {
+    let entries = std::fs::read_dir(dir).map_err(|e| StaticDataError::Io(format!("{}: {}", dir.display(), e)))?;
+
+    entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| dir.join(name).join("data").join(LOCALE).join(FILES[0]).is_file())
+        .filter_map(|name| Some((version_key(&name)?, name)))
+        .max()
+        .map(|(_, name)| name)
+        .ok_or_else(|| StaticDataError::Missing(format!("no Data Dragon version in {}", dir.display())))
+}
+
+fn load_directory(dir: &Path) -> Result<StaticData, StaticDataError> {
+    let version = latest_local_version(dir)?;
+    let data_dir = dir.join(&version).join("data").join(LOCALE);
+
+    let mut files: [String; 4] = Default::default();
+    for (file, name) in files.iter_mut().zip(FILES) {
+        let path = data_dir.join(name);
+        *file = std::fs::read_to_string(&path).map_err(|e| StaticDataError::Io(format!("{}: {}", path.display(), e)))?;
+    }
+
+    parse(&version, &files)
+}
+
+// Dragontails are large, mostly images, so the archive is streamed once and
+// only the JSON we index is kept.
+fn load_archive(path: &Path) -> Result<StaticData, StaticDataError> {
+    let io_error = |e: std::io::Error| StaticDataError::Io(format!("{}: {}", path.display(), e));
+
+    let file = std::fs::File::open(path).map_err(io_error)?;
+    let mut archive = tar::Archive::new(GzDecoder::new(file));
+
+    let mut versions: HashMap<String, [Option<String>; 4]> = HashMap::new();
+    for entry in archive.entries().map_err(io_error)? {
+        let mut entry = entry.map_err(io_error)?;
+        let entry_path = entry.path().map_err(io_error)?.into_owned();
+        let parts: Vec<&str> = entry_path
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(part) => part.to_str(),
+                _ => None,
+            })
+            .collect();
+
+        let [version, "data", locale, name] = parts.as_slice() else { continue };
+        let Some(slot) = FILES.iter().position(|file| file == name) else { continue };
+        if *locale != LOCALE || version_key(version).is_none() {
+            continue;
+        }
+
+        let mut contents = String::new();
+        entry.read_to_string(&mut contents).map_err(io_error)?;
+        versions.entry(version.to_string()).or_default()[slot] = Some(contents);
+    }
+
+    let (version, files) = versions
+        .into_iter()
+        .filter_map(|(version, files)| Some((version_key(&version)?, version, files)))
+        .filter(|(_, _, files)| files.iter().all(Option::is_some))
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, version, files)| (version, files.map(Option::unwrap_or_default)))
+        .ok_or_else(|| StaticDataError::Missing(format!("no complete Data Dragon version in {}", path.display())))?;
+
+    parse(&version, &files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::FIXTURE_DIR;
+    use flate2::Compression;
+    use flate2::write::GzEncoder;
+
+    #[test]
+    fn test_parse_source() {
+        assert_eq!(
+            DataSource::parse("https://ddragon.leagueoflegends.com/"),
+            DataSource::Remote("https://ddragon.leagueoflegends.com".to_string())
+        );
+        assert_eq!(DataSource::parse("/srv/dragontail-15.20.1.tgz"), DataSource::Archive("/srv/dragontail-15.20.1.tgz".into()));
+        assert_eq!(DataSource::parse("/srv/ddragon"), DataSource::Directory("/srv/ddragon".into()));
+    }
+
+    #[test]
+    fn test_version_key_orders_numerically() {
+        assert!(version_key("15.10.1") > version_key("15.9.1"));
+        assert_eq!(version_key("img"), None);
+        assert_eq!(version_key("lolpatch_7.20"), None);
+    }
+
+    #[tokio::test]
+    async fn test_load_directory() {
+        let source = DataSource::Directory(FIXTURE_DIR.into());
+        assert_eq!(source.revision().await.unwrap(), "15.20.1");
+
+        let data = source.load().await.unwrap();
+        assert_eq!(data.version(), Some("15.20.1"));
+        assert_eq!(data.champions.name(7), "LeBlanc");
+    }
+
+    #[tokio::test]
+    async fn test_load_archive() {
+        let path = std::env::temp_dir().join(format!("dragontail-test-{}.tgz", std::process::id()));
+        {
+            let mut builder = tar::Builder::new(GzEncoder::new(std::fs::File::create(&path).unwrap(), Compression::fast()));
+            builder.append_dir_all("15.20.1", Path::new(FIXTURE_DIR).join("15.20.1")).unwrap();
+            builder.into_inner().unwrap().finish().unwrap();
+        }
+
+        let source = DataSource::Archive(path.clone());
+        let data = source.load().await;
+        let revision = source.revision().await;
+        std::fs::remove_file(&path).unwrap();
+
+        let data = data.unwrap();
+        assert_eq!(data.version(), Some("15.20.1"));
+        assert_eq!(data.spells.name(4), "Flash");
+        assert!(!revision.unwrap().is_empty());
+    }
+}
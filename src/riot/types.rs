@@ -9,7 +9,7 @@ pub struct RiotAccount {
     pub tag_line: String,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Region {
     Americas,
     Europe,
@@ -18,21 +18,577 @@ pub enum Region {
 }
 
 impl Region {
-    pub fn from_game_region(region: &str) -> Self {
-        match region.to_lowercase().as_str() {
-            "na" | "br" | "lan" | "las" => Region::Americas,
-            "euw" | "eune" | "tr" | "ru" => Region::Europe,
-            "kr" | "jp" => Region::Asia,
-            _ => Region::Americas,
+    pub fn host(&self) -> &'static str {
+        match self {
+            Region::Americas => "americas",
+            Region::Europe => "europe",
+            Region::Asia => "asia",
+            Region::Sea => "sea",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Platform {
+    Na1,
+    Euw1,
+    Eun1,
+    Kr,
+    Br1,
+    La1,
+    La2,
+    Oc1,
+    Ru,
+    Tr1,
+    Jp1,
+    Ph2,
+    Sg2,
+    Th2,
+    Tw2,
+    Vn2,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ApiFamily {
+    Account,
+    Match,
+    Summoner,
+    League,
+    Spectator,
+    ChampionMastery,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Route {
+    Platform(Platform),
+    Regional(Region),
+}
+
+impl Route {
+    pub fn host(&self) -> &'static str {
+        match self {
+            Route::Platform(platform) => platform.host(),
+            Route::Regional(region) => region.host(),
+        }
+    }
+
+    pub fn base_url(&self, template: &str) -> String {
+        template.replace("{routing}", self.host())
+    }
+}
+
+impl Platform {
+    pub const ALL: [Platform; 16] = [
+        Platform::Na1,
+        Platform::Euw1,
+        Platform::Eun1,
+        Platform::Kr,
+        Platform::Br1,
+        Platform::La1,
+        Platform::La2,
+        Platform::Oc1,
+        Platform::Ru,
+        Platform::Tr1,
+        Platform::Jp1,
+        Platform::Ph2,
+        Platform::Sg2,
+        Platform::Th2,
+        Platform::Tw2,
+        Platform::Vn2,
+    ];
+
+    pub fn from_game_region(region: &str) -> Option<Self> {
+        let region = region.to_lowercase();
+        Platform::ALL
+            .into_iter()
+            .find(|platform| platform.game_region() == region || platform.host() == region)
+    }
+
+    pub fn game_region(&self) -> &'static str {
+        match self {
+            Platform::Na1 => "na",
+            Platform::Euw1 => "euw",
+            Platform::Eun1 => "eune",
+            Platform::Kr => "kr",
+            Platform::Br1 => "br",
+            Platform::La1 => "lan",
+            Platform::La2 => "las",
+            Platform::Oc1 => "oce",
+            Platform::Ru => "ru",
+            Platform::Tr1 => "tr",
+            Platform::Jp1 => "jp",
+            Platform::Ph2 => "ph",
+            Platform::Sg2 => "sg",
+            Platform::Th2 => "th",
+            Platform::Tw2 => "tw",
+            Platform::Vn2 => "vn",
+        }
+    }
+
+    pub fn host(&self) -> &'static str {
+        match self {
+            Platform::Na1 => "na1",
+            Platform::Euw1 => "euw1",
+            Platform::Eun1 => "eun1",
+            Platform::Kr => "kr",
+            Platform::Br1 => "br1",
+            Platform::La1 => "la1",
+            Platform::La2 => "la2",
+            Platform::Oc1 => "oc1",
+            Platform::Ru => "ru",
+            Platform::Tr1 => "tr1",
+            Platform::Jp1 => "jp1",
+            Platform::Ph2 => "ph2",
+            Platform::Sg2 => "sg2",
+            Platform::Th2 => "th2",
+            Platform::Tw2 => "tw2",
+            Platform::Vn2 => "vn2",
+        }
+    }
+
+    // account-v1 has no SEA cluster; match-v5 moved OCE and the Garena
+    // shards to SEA.
+    pub fn region(&self, family: ApiFamily) -> Region {
+        match (self, family) {
+            (Platform::Na1 | Platform::Br1 | Platform::La1 | Platform::La2, _) => Region::Americas,
+            (Platform::Euw1 | Platform::Eun1 | Platform::Ru | Platform::Tr1, _) => Region::Europe,
+            (Platform::Kr | Platform::Jp1, _) => Region::Asia,
+            (Platform::Oc1, ApiFamily::Account) => Region::Americas,
+            (_, ApiFamily::Account) => Region::Asia,
+            _ => Region::Sea,
+        }
+    }
+
+    pub fn route(&self, family: ApiFamily) -> Route {
+        match family {
+            ApiFamily::Account | ApiFamily::Match => Route::Regional(self.region(family)),
+            ApiFamily::Summoner | ApiFamily::League | ApiFamily::Spectator | ApiFamily::ChampionMastery => {
+                Route::Platform(*self)
+            }
+        }
+    }
+
+    pub fn game_regions() -> Vec<&'static str> {
+        Platform::ALL.iter().map(|platform| platform.game_region()).collect()
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Summoner {
+    pub puuid: String,
+    pub profile_icon_id: i64,
+    pub revision_date: i64,
+    pub summoner_level: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeagueEntry {
+    pub queue_type: String,
+    pub tier: String,
+    pub rank: String,
+    pub league_points: i32,
+    pub wins: i32,
+    pub losses: i32,
+    pub hot_streak: bool,
+    pub veteran: bool,
+    pub fresh_blood: bool,
+    pub inactive: bool,
+}
+
+impl LeagueEntry {
+    pub const RANKED_SOLO: &'static str = "RANKED_SOLO_5x5";
+    pub const RANKED_FLEX: &'static str = "RANKED_FLEX_SR";
+
+    pub fn win_rate(&self) -> f64 {
+        let games = self.wins + self.losses;
+        if games == 0 {
+            return 0.0;
         }
+        self.wins as f64 / games as f64 * 100.0
     }
 
-    pub fn api_base_url(&self) -> &'static str {
+    pub fn ranked_tier(&self) -> Option<Tier> {
+        Tier::parse(&self.tier)
+    }
+
+    // Sorts by tier, then division, then LP. Apex tiers only report
+    // division I, so LP decides between them.
+    pub fn standing(&self) -> Option<(Tier, u8, i32)> {
+        let division = match self.rank.as_str() {
+            "I" => 3,
+            "II" => 2,
+            "III" => 1,
+            _ => 0,
+        };
+        Some((self.ranked_tier()?, division, self.league_points))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Tier {
+    Iron,
+    Bronze,
+    Silver,
+    Gold,
+    Platinum,
+    Emerald,
+    Diamond,
+    Master,
+    Grandmaster,
+    Challenger,
+}
+
+impl Tier {
+    pub const ALL: [Tier; 10] = [
+        Tier::Iron, Tier::Bronze, Tier::Silver, Tier::Gold, Tier::Platinum,
+        Tier::Emerald, Tier::Diamond, Tier::Master, Tier::Grandmaster, Tier::Challenger,
+    ];
+
+    pub const NAMES: [&'static str; 10] = [
+        "iron", "bronze", "silver", "gold", "platinum",
+        "emerald", "diamond", "master", "grandmaster", "challenger",
+    ];
+
+    // Accepts Riot's `GOLD` as well as user input like `gold`.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.to_lowercase();
+        Tier::NAMES
+            .iter()
+            .position(|name| *name == value)
+            .map(|i| Tier::ALL[i])
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Tier::Iron => "IRON",
+            Tier::Bronze => "BRONZE",
+            Tier::Silver => "SILVER",
+            Tier::Gold => "GOLD",
+            Tier::Platinum => "PLATINUM",
+            Tier::Emerald => "EMERALD",
+            Tier::Diamond => "DIAMOND",
+            Tier::Master => "MASTER",
+            Tier::Grandmaster => "GRANDMASTER",
+            Tier::Challenger => "CHALLENGER",
+        }
+    }
+
+    // Master and above have no divisions.
+    pub fn is_apex(&self) -> bool {
+        *self >= Tier::Master
+    }
+
+    pub fn title(&self) -> &'static str {
+        match self {
+            Tier::Iron => "Iron",
+            Tier::Bronze => "Bronze",
+            Tier::Silver => "Silver",
+            Tier::Gold => "Gold",
+            Tier::Platinum => "Platinum",
+            Tier::Emerald => "Emerald",
+            Tier::Diamond => "Diamond",
+            Tier::Master => "Master",
+            Tier::Grandmaster => "Grandmaster",
+            Tier::Challenger => "Challenger",
+        }
+    }
+
+    // Emblem colors as 0xRRGGBB.
+    pub fn color(&self) -> u32 {
         match self {
-            Region::Americas => "https://americas.api.riotgames.com",
-            Region::Europe => "https://europe.api.riotgames.com",
-            Region::Asia => "https://asia.api.riotgames.com",
-            Region::Sea => "https://sea.api.riotgames.com",
+            Tier::Iron => 0x6E6365,
+            Tier::Bronze => 0x8C523A,
+            Tier::Silver => 0x8098A5,
+            Tier::Gold => 0xCD8837,
+            Tier::Platinum => 0x4E9996,
+            Tier::Emerald => 0x2BA35F,
+            Tier::Diamond => 0x576BCE,
+            Tier::Master => 0x9D48E0,
+            Tier::Grandmaster => 0xCD4545,
+            Tier::Challenger => 0xF4C874,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RankedProfile {
+    pub summoner: Summoner,
+    pub solo: Option<LeagueEntry>,
+    pub flex: Option<LeagueEntry>,
+}
+
+impl RankedProfile {
+    pub fn new(summoner: Summoner, entries: Vec<LeagueEntry>) -> Self {
+        let mut solo = None;
+        let mut flex = None;
+
+        for entry in entries {
+            match entry.queue_type.as_str() {
+                LeagueEntry::RANKED_SOLO => solo = Some(entry),
+                LeagueEntry::RANKED_FLEX => flex = Some(entry),
+                _ => {}
+            }
         }
+
+        RankedProfile { summoner, solo, flex }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MatchDto {
+    pub metadata: MetadataDto,
+    pub info: InfoDto,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataDto {
+    pub match_id: String,
+    pub participants: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoDto {
+    pub game_creation: i64,
+    pub game_duration: i64,
+    #[serde(default)]
+    pub game_end_timestamp: Option<i64>,
+    pub game_mode: String,
+    pub queue_id: i32,
+    pub participants: Vec<ParticipantDto>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParticipantDto {
+    pub puuid: String,
+    #[serde(default)]
+    pub riot_id_game_name: Option<String>,
+    #[serde(default)]
+    pub riot_id_tagline: Option<String>,
+    pub champion_id: i32,
+    pub champion_name: String,
+    pub team_id: i32,
+    #[serde(default)]
+    pub team_position: String,
+    pub kills: i32,
+    pub deaths: i32,
+    pub assists: i32,
+    pub total_minions_killed: i32,
+    pub neutral_minions_killed: i32,
+    pub summoner1_id: i32,
+    pub summoner2_id: i32,
+    pub win: bool,
+}
+
+impl MatchDto {
+    pub fn participant(&self, puuid: &str) -> Option<&ParticipantDto> {
+        self.info.participants.iter().find(|p| p.puuid == puuid)
+    }
+}
+
+impl ParticipantDto {
+    pub fn creep_score(&self) -> i32 {
+        self.total_minions_killed + self.neutral_minions_killed
+    }
+
+    pub fn kda(&self) -> f64 {
+        (self.kills + self.assists) as f64 / self.deaths.max(1) as f64
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentGameInfo {
+    pub game_id: i64,
+    pub game_mode: String,
+    pub game_start_time: i64,
+    pub game_length: i64,
+    #[serde(default)]
+    pub game_queue_config_id: Option<i32>,
+    pub participants: Vec<CurrentGameParticipant>,
+}
+
+// `puuid` and `riot_id` are missing for bots.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CurrentGameParticipant {
+    #[serde(default)]
+    pub puuid: Option<String>,
+    #[serde(default)]
+    pub riot_id: Option<String>,
+    pub champion_id: i64,
+    pub team_id: i32,
+    pub spell1_id: i64,
+    pub spell2_id: i64,
+    #[serde(default)]
+    pub bot: bool,
+    #[serde(default)]
+    pub perks: Option<Perks>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Perks {
+    pub perk_ids: Vec<i64>,
+    pub perk_style: i64,
+    pub perk_sub_style: i64,
+}
+
+impl CurrentGameParticipant {
+    // The first rune listed is always the keystone.
+    pub fn keystone(&self) -> Option<i64> {
+        self.perks.as_ref()?.perk_ids.first().copied()
+    }
+}
+
+impl CurrentGameInfo {
+    pub const BLUE_TEAM: i32 = 100;
+    pub const RED_TEAM: i32 = 200;
+
+    // The start time stays 0 while players are loading in.
+    pub fn elapsed_secs(&self, now_millis: i64) -> i64 {
+        if self.game_start_time > 0 {
+            ((now_millis - self.game_start_time) / 1000).max(0)
+        } else {
+            self.game_length.max(0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChampionMastery {
+    pub champion_id: i64,
+    pub champion_level: i32,
+    pub champion_points: i64,
+    // Unix milliseconds.
+    pub last_play_time: i64,
+}
+
+pub const QUEUE_ALIASES: [&str; 7] = ["solo", "flex", "normal", "blind", "aram", "quickplay", "arena"];
+
+// Queues with a name in `queue_name`, which may also be given by ID.
+const KNOWN_QUEUE_IDS: [i32; 7] = [420, 440, 400, 430, 450, 490, 1700];
+
+pub fn queue_id_from_name(name: &str) -> Option<i32> {
+    match name.to_lowercase().as_str() {
+        "solo" | "soloq" | "ranked" => Some(420),
+        "flex" => Some(440),
+        "draft" | "normal" => Some(400),
+        "blind" => Some(430),
+        "aram" => Some(450),
+        "quickplay" | "swiftplay" => Some(490),
+        "arena" => Some(1700),
+        other => other.parse().ok().filter(|id| KNOWN_QUEUE_IDS.contains(id)),
+    }
+}
+
+pub fn queue_name(queue_id: i32) -> &'static str {
+    match queue_id {
+        420 => "Ranked Solo/Duo",
+        440 => "Ranked Flex",
+        400 => "Normal Draft",
+        430 => "Normal Blind",
+        450 => "ARAM",
+        490 => "Quickplay",
+        1700 => "Arena",
+        0 => "Custom",
+        _ => "Other",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_queue_id_from_name() {
+        assert_eq!(queue_id_from_name("ARAM"), Some(450));
+        assert_eq!(queue_id_from_name("1700"), Some(1700));
+        assert_eq!(queue_id_from_name("20"), None);
+        assert_eq!(queue_id_from_name("0"), None);
+    }
+
+    #[test]
+    fn test_tier_parse_round_trips() {
+        for tier in Tier::ALL {
+            assert_eq!(Tier::parse(tier.as_str()), Some(tier));
+            assert_eq!(Tier::parse(tier.title()), Some(tier));
+        }
+        assert_eq!(Tier::parse("unranked"), None);
+        assert!(Tier::Gold < Tier::Platinum);
+    }
+
+    #[test]
+    fn test_standing_orders_division_before_lp() {
+        let entry = |tier: &str, rank: &str, lp: i32| LeagueEntry {
+            queue_type: LeagueEntry::RANKED_SOLO.to_string(),
+            tier: tier.to_string(),
+            rank: rank.to_string(),
+            league_points: lp,
+            wins: 0,
+            losses: 0,
+            hot_streak: false,
+            veteran: false,
+            fresh_blood: false,
+            inactive: false,
+        };
+
+        assert!(entry("GOLD", "I", 0).standing() > entry("GOLD", "II", 99).standing());
+        assert!(entry("PLATINUM", "IV", 0).standing() > entry("GOLD", "I", 99).standing());
+        assert!(entry("MASTER", "I", 300).standing() > entry("MASTER", "I", 12).standing());
+        assert_eq!(entry("UNRANKED", "", 0).standing(), None);
+    }
+
+    #[test]
+    fn test_every_game_region_resolves() {
+        for platform in Platform::ALL {
+            assert_eq!(Platform::from_game_region(platform.game_region()), Some(platform));
+        }
+    }
+
+    #[test]
+    fn test_platform_ids_resolve() {
+        assert_eq!(Platform::from_game_region("EUW1"), Some(Platform::Euw1));
+        assert_eq!(Platform::from_game_region("oc1"), Some(Platform::Oc1));
+        assert_eq!(Platform::from_game_region("atlantis"), None);
+    }
+
+    #[test]
+    fn test_sea_platforms_route_matches_to_sea() {
+        for region in ["oce", "ph", "sg", "th", "tw", "vn"] {
+            let platform = Platform::from_game_region(region).unwrap();
+            assert_eq!(platform.route(ApiFamily::Match), Route::Regional(Region::Sea));
+            assert_ne!(platform.route(ApiFamily::Account), Route::Regional(Region::Sea));
+        }
+    }
+
+    #[test]
+    fn test_game_elapsed_falls_back_while_loading() {
+        let mut game: CurrentGameInfo = serde_json::from_str(
+            r#"{"gameId": 1, "gameMode": "CLASSIC", "gameStartTime": 0, "gameLength": 12, "participants": []}"#,
+        ).unwrap();
+        assert_eq!(game.game_queue_config_id, None);
+        assert_eq!(game.elapsed_secs(1_000_000), 12);
+
+        game.game_start_time = 400_000;
+        assert_eq!(game.elapsed_secs(1_000_000), 600);
+    }
+
+    #[test]
+    fn test_league_routes_to_platform() {
+        let platform = Platform::from_game_region("kr").unwrap();
+        assert_eq!(platform.route(ApiFamily::League), Route::Platform(Platform::Kr));
+        assert_eq!(platform.route(ApiFamily::Spectator), Route::Platform(Platform::Kr));
+        assert_eq!(platform.route(ApiFamily::Account), Route::Regional(Region::Asia));
+        assert_eq!(
+            platform.route(ApiFamily::League).base_url("https://{routing}.api.riotgames.com"),
+            "https://kr.api.riotgames.com"
+        );
     }
 }
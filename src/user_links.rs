@@ -0,0 +1,165 @@
+use serenity::model::id::UserId;
+use serenity::prelude::TypeMapKey;
+use std::future::Future;
+use std::sync::Arc;
+use crate::cache::{SWEEP_INTERVAL, TtlCache, ttl};
+use crate::database::Database;
+use crate::database::models::UserLink;
+
+const CAPACITY: usize = 10_000;
+
+// The only way commands read or change linked accounts. Reads go through
+// the cache, "not linked" included, and every write leaves the cache
+// holding what the database now has.
+pub struct UserLinkRepository {
+    db: Arc<Database>,
+    cache: Arc<TtlCache<UserId, Vec<UserLink>>>,
+}
+
+impl TypeMapKey for UserLinkRepository {
+    type Value = Arc<UserLinkRepository>;
+}
+
+impl UserLinkRepository {
+    pub fn new(db: Arc<Database>) -> Self {
+        let cache = TtlCache::new("User link", CAPACITY, ttl::USER_LINK).with_ttl_for(|links: &Vec<UserLink>| {
+            if links.is_empty() { ttl::NO_USER_LINK } else { ttl::USER_LINK }
+        });
+
+        UserLinkRepository { db, cache: Arc::new(cache) }
+    }
+
+    pub fn spawn_sweeper(&self) {
+        self.cache.spawn_sweeper(SWEEP_INTERVAL);
+    }
+
+    pub async fn get(&self, user_id: UserId) -> Result<Vec<UserLink>, sqlx::Error> {
+        self.cache.get_or_load(user_id, || self.db.get_user_links(user_id)).await
+    }
+
+    // The account other commands use by default.
+    pub async fn get_primary(&self, user_id: UserId) -> Result<Option<UserLink>, sqlx::Error> {
+        let links = self.get(user_id).await?;
+        Ok(links.into_iter().find(|link| link.is_primary))
+    }
+
+    pub async fn save(&self, link: &UserLink) -> Result<(), sqlx::Error> {
+        self.write_through(link.discord_user_id, || self.db.save_user_link(link)).await
+    }
+
+    pub async fn delete(&self, user_id: UserId, name: &str, tag: &str) -> Result<bool, sqlx::Error> {
+        self.write_through(user_id, || self.db.delete_user_link(user_id, name, tag)).await
+    }
+
+    pub async fn set_primary(&self, user_id: UserId, name: &str, tag: &str) -> Result<bool, sqlx::Error> {
+        self.write_through(user_id, || self.db.set_primary_link(user_id, name, tag)).await
+    }
+
+    pub async fn set_verified(&self, user_id: UserId, puuid: &str) -> Result<bool, sqlx::Error> {
+        self.write_through(user_id, || self.db.set_link_verified(user_id, puuid)).await
+    }
+
+    // Writes can move the primary flag between links, so the user's links
+    // are read back as a whole. A failed write or read-back drops the entry
+    // rather than keep something the database may not match. Runs under the
+    // user's load lock so a `get` that read before the write can't store
+    // its older rows after it.
+    async fn write_through<T, F, Fut>(&self, user_id: UserId, write: F) -> Result<T, sqlx::Error>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<T, sqlx::Error>>,
+    {
+        self.cache.locked(user_id, || async {
+            let result = write().await;
+            if result.is_err() {
+                self.cache.remove(&user_id);
+                return result;
+            }
+
+            self.reload(user_id).await;
+            result
+        }).await
+    }
+
+    async fn reload(&self, user_id: UserId) {
+        match self.db.get_user_links(user_id).await {
+            Ok(links) => self.cache.insert(user_id, links),
+            Err(e) => {
+                eprintln!("Failed to reload links of {}: {}", user_id, e);
+                self.cache.remove(&user_id);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn link(user_id: UserId, name: &str) -> UserLink {
+        UserLink {
+            discord_user_id: user_id,
+            summoner_name: name.to_string(),
+            summoner_tag: "EUW".to_string(),
+            region: "euw".to_string(),
+            riot_puuid: Some(format!("puuid-{}", name)),
+            is_primary: false,
+            verified: false,
+        }
+    }
+
+    async fn repository() -> UserLinkRepository {
+        UserLinkRepository::new(Arc::new(Database::in_memory().await.unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_not_linked_is_cached() {
+        let repo = repository().await;
+        let user = UserId::new(1);
+
+        assert!(repo.get(user).await.unwrap().is_empty());
+
+        // Written behind the repository's back, so only a reload would see it.
+        repo.db.save_user_link(&link(user, "Hidden")).await.unwrap();
+        assert!(repo.get(user).await.unwrap().is_empty());
+        assert_eq!(repo.cache.metrics().loads, 1);
+    }
+
+    #[tokio::test]
+    async fn test_writes_update_the_cache() {
+        let repo = repository().await;
+        let user = UserId::new(1);
+
+        assert!(repo.get_primary(user).await.unwrap().is_none());
+
+        repo.save(&link(user, "First")).await.unwrap();
+        repo.save(&link(user, "Second")).await.unwrap();
+        let links = repo.get(user).await.unwrap();
+        assert_eq!(links.len(), 2);
+        assert_eq!(repo.get_primary(user).await.unwrap().unwrap().summoner_name, "First");
+
+        assert!(repo.set_primary(user, "Second", "EUW").await.unwrap());
+        assert_eq!(repo.get_primary(user).await.unwrap().unwrap().summoner_name, "Second");
+
+        assert!(repo.set_verified(user, "puuid-Second").await.unwrap());
+        assert!(repo.get_primary(user).await.unwrap().unwrap().verified);
+
+        assert!(repo.delete(user, "Second", "EUW").await.unwrap());
+        assert!(!repo.delete(user, "Second", "EUW").await.unwrap());
+        let links = repo.get(user).await.unwrap();
+        assert_eq!(links.len(), 1);
+        assert!(links[0].is_primary);
+
+        // Only the first read went to the database; writes refreshed the rest.
+        assert_eq!(repo.cache.metrics().loads, 1);
+    }
+
+    #[tokio::test]
+    async fn test_users_are_cached_separately() {
+        let repo = repository().await;
+
+        repo.save(&link(UserId::new(1), "One")).await.unwrap();
+        assert!(repo.get(UserId::new(2)).await.unwrap().is_empty());
+        assert_eq!(repo.get(UserId::new(1)).await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,54 @@
+pub fn levenshtein(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b_chars.len()).collect();
+    let mut current = vec![0; b_chars.len() + 1];
+
+    for (i, a_char) in a.chars().enumerate() {
+        current[0] = i + 1;
+        for (j, b_char) in b_chars.iter().enumerate() {
+            let cost = if a_char == *b_char { 0 } else { 1 };
+            current[j + 1] = (previous[j] + cost)
+                .min(previous[j + 1] + 1)
+                .min(current[j] + 1);
+        }
+        std::mem::swap(&mut previous, &mut current);
+    }
+
+    previous[b_chars.len()]
+}
+
+// Closest candidate within `max_distance` edits, compared case-insensitively.
+pub fn closest_match<'a, I>(input: &str, candidates: I, max_distance: usize) -> Option<&'a str>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    let input = input.to_lowercase();
+    candidates
+        .into_iter()
+        .map(|candidate| (levenshtein(&input, &candidate.to_lowercase()), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("link", "link"), 0);
+        assert_eq!(levenshtein("lnik", "link"), 2);
+        assert_eq!(levenshtein("rnak", "rank"), 2);
+        assert_eq!(levenshtein("", "help"), 4);
+        assert_eq!(levenshtein("histroy", "history"), 2);
+    }
+
+    #[test]
+    fn test_closest_match() {
+        let commands = ["ping", "help", "link", "unlink", "rank"];
+        assert_eq!(closest_match("lnk", commands, 2), Some("link"));
+        assert_eq!(closest_match("RANK", commands, 2), Some("rank"));
+        assert_eq!(closest_match("leaderboard", commands, 2), None);
+    }
+}
@@ -1,3 +1,5 @@
 mod parser;
+mod fuzzy;
 
-pub use parser::parse_args;
+pub use parser::{parse_args, parse_channel_mention, parse_riot_id, parse_role_mention, parse_user_mention};
+pub use fuzzy::{closest_match, levenshtein};
@@ -1,10 +1,11 @@
+use serenity::model::id::{ChannelId, RoleId, UserId};
+
 pub fn parse_args(input: &str) -> Vec<String> {
     let mut args = Vec::new();
     let mut current_arg = String::new();
     let mut in_quotes = false;
-    let mut chars = input.chars().peekable(); 
 
-    while let Some(c) = chars.next() {
+    for c in input.chars() {
        match c {
            '"' => {
                in_quotes = !in_quotes;
@@ -28,6 +29,36 @@ pub fn parse_args(input: &str) -> Vec<String> {
     args
 }
 
+pub fn parse_user_mention(input: &str) -> Option<UserId> {
+    let id = input
+        .strip_prefix("<@")?
+        .strip_suffix('>')?
+        .trim_start_matches('!');
+
+    id.parse::<u64>().ok().filter(|id| *id != 0).map(UserId::new)
+}
+
+pub fn parse_role_mention(input: &str) -> Option<RoleId> {
+    let id = input.strip_prefix("<@&")?.strip_suffix('>')?;
+
+    id.parse::<u64>().ok().filter(|id| *id != 0).map(RoleId::new)
+}
+
+pub fn parse_channel_mention(input: &str) -> Option<ChannelId> {
+    let id = input.strip_prefix("<#")?.strip_suffix('>')?;
+
+    id.parse::<u64>().ok().filter(|id| *id != 0).map(ChannelId::new)
+}
+
+// Splits `Name#TAG`; names may contain spaces but neither part may be empty.
+pub fn parse_riot_id(input: &str) -> Option<(&str, &str)> {
+    let (name, tag) = input.split_once('#')?;
+    if name.is_empty() || tag.is_empty() || tag.contains('#') {
+        return None;
+    }
+    Some((name, tag))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,6 +78,36 @@ mod tests {
         assert_eq!(parse_args("\"hello there\" \"Chovy Faker\" CN"), vec!["hello there", "Chovy Faker", "CN"]);
     }
 
+    #[test]
+    fn test_user_mention() {
+        assert_eq!(parse_user_mention("<@80351110224678912>"), Some(UserId::new(80351110224678912)));
+        assert_eq!(parse_user_mention("<@!80351110224678912>"), Some(UserId::new(80351110224678912)));
+        assert_eq!(parse_user_mention("<@&80351110224678912>"), None);
+        assert_eq!(parse_user_mention("Faker#KR1"), None);
+    }
+
+    #[test]
+    fn test_role_mention() {
+        assert_eq!(parse_role_mention("<@&80351110224678912>"), Some(RoleId::new(80351110224678912)));
+        assert_eq!(parse_role_mention("<@80351110224678912>"), None);
+    }
+
+    #[test]
+    fn test_channel_mention() {
+        assert_eq!(parse_channel_mention("<#80351110224678912>"), Some(ChannelId::new(80351110224678912)));
+        assert_eq!(parse_channel_mention("<@80351110224678912>"), None);
+        assert_eq!(parse_channel_mention("#general"), None);
+    }
+
+    #[test]
+    fn test_riot_id() {
+        assert_eq!(parse_riot_id("Hide on bush#KR1"), Some(("Hide on bush", "KR1")));
+        assert_eq!(parse_riot_id("Faker"), None);
+        assert_eq!(parse_riot_id("#KR1"), None);
+        assert_eq!(parse_riot_id("Faker#"), None);
+        assert_eq!(parse_riot_id("a#b#c"), None);
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(parse_args(""), Vec::<String>::new());